@@ -2,7 +2,7 @@
 //
 // SPDX-License-Identifier: Apache-2.0 OR MIT
 
-use dos_date_time::Date;
+use dos_date_time::{Date, DateTime};
 use test::Bencher;
 
 #[bench]
@@ -14,3 +14,13 @@ fn equality(b: &mut Bencher) {
 fn order(b: &mut Bencher) {
     b.iter(|| Date::MAX > Date::MIN);
 }
+
+#[bench]
+fn equality_with_date_time(b: &mut Bencher) {
+    b.iter(|| Date::MIN == DateTime::MIN);
+}
+
+#[bench]
+fn order_with_date_time(b: &mut Bencher) {
+    b.iter(|| Date::MAX > DateTime::MIN);
+}
@@ -9,6 +9,11 @@ use dos_date_time::{Date, time::macros::date};
 use jiff::civil;
 use test::Bencher;
 
+#[bench]
+fn from_date_to_u16(b: &mut Bencher) {
+    b.iter(|| u16::from(Date::MIN));
+}
+
 #[bench]
 fn from_date_to_time_date(b: &mut Bencher) {
     b.iter(|| time::Date::from(Date::MIN));
@@ -26,6 +31,17 @@ fn from_date_to_jiff_civil_date(b: &mut Bencher) {
     b.iter(|| civil::Date::from(Date::MIN));
 }
 
+#[cfg(feature = "jiff")]
+#[bench]
+fn from_date_to_tuple(b: &mut Bencher) {
+    b.iter(|| <(i16, i8, i8)>::from(Date::MIN));
+}
+
+#[bench]
+fn try_from_u16_to_date(b: &mut Bencher) {
+    b.iter(|| Date::try_from(0b0000_0000_0010_0001).unwrap());
+}
+
 #[bench]
 fn try_from_time_date_to_date(b: &mut Bencher) {
     let date = date!(1980-01-01);
@@ -45,3 +61,8 @@ fn try_from_jiff_civil_date_to_date(b: &mut Bencher) {
     let date = civil::date(1980, 1, 1);
     b.iter(|| Date::try_from(date).unwrap());
 }
+
+#[bench]
+fn is_date_representable(b: &mut Bencher) {
+    b.iter(|| dos_date_time::is_date_representable(date!(1980-01-01)));
+}
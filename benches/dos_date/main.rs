@@ -8,8 +8,13 @@ extern crate test;
 
 mod cmp;
 mod convert;
+mod ops;
+mod range;
 
-use dos_date_time::{Date, time::macros::date};
+use dos_date_time::{
+    Date,
+    time::{Month, macros::date},
+};
 use test::Bencher;
 
 #[bench]
@@ -17,31 +22,111 @@ fn new(b: &mut Bencher) {
     b.iter(|| Date::new(0b0000_0000_0010_0001).unwrap());
 }
 
+#[bench]
+fn new_with_invalid_date(b: &mut Bencher) {
+    b.iter(|| Date::new(0b0000_0000_0101_1110));
+}
+
+#[bench]
+fn checked_from_raw(b: &mut Bencher) {
+    b.iter(|| Date::checked_from_raw(0b0000_0000_0010_0001).unwrap());
+}
+
+#[bench]
+fn checked_new(b: &mut Bencher) {
+    b.iter(|| Date::checked_new(0b0000_0000_0010_0001).unwrap());
+}
+
+#[bench]
+fn from_i64(b: &mut Bencher) {
+    b.iter(|| Date::from_i64(0b1111_1111_1001_1111).unwrap());
+}
+
 #[bench]
 fn new_unchecked(b: &mut Bencher) {
     b.iter(|| unsafe { Date::new_unchecked(0b0000_0000_0010_0001) });
 }
 
+#[bench]
+fn wrapping_add_raw(b: &mut Bencher) {
+    b.iter(|| unsafe { Date::MIN.wrapping_add_raw(1) });
+}
+
 #[bench]
 fn from_date(b: &mut Bencher) {
     b.iter(|| Date::from_date(date!(1980-01-01)).unwrap());
 }
 
+#[bench]
+fn from_year_month_day(b: &mut Bencher) {
+    b.iter(|| Date::from_year_month_day(1980, Month::January, 1).unwrap());
+}
+
+#[bench]
+fn from_ymd_i32(b: &mut Bencher) {
+    b.iter(|| Date::from_ymd_i32(2107, 12, 31).unwrap());
+}
+
+#[bench]
+fn from_ordinal_date(b: &mut Bencher) {
+    b.iter(|| Date::from_ordinal_date(1980, 1).unwrap());
+}
+
+#[bench]
+fn from_julian_day(b: &mut Bencher) {
+    b.iter(|| Date::from_julian_day(2_444_240).unwrap());
+}
+
+#[bench]
+fn from_days_since_epoch(b: &mut Bencher) {
+    b.iter(|| Date::from_days_since_epoch(46750).unwrap());
+}
+
+#[bench]
+fn all_valid(b: &mut Bencher) {
+    b.iter(|| Date::all_valid().count());
+}
+
 #[bench]
 fn is_valid(b: &mut Bencher) {
     b.iter(|| Date::MIN.is_valid());
 }
 
+#[bench]
+fn in_range(b: &mut Bencher) {
+    b.iter(|| Date::MIN.in_range(Date::MIN..=Date::MAX));
+}
+
+#[bench]
+fn clamp(b: &mut Bencher) {
+    b.iter(|| Date::MIN.clamp(Date::MIN, Date::MAX));
+}
+
 #[bench]
 fn to_raw(b: &mut Bencher) {
     b.iter(|| Date::MIN.to_raw());
 }
 
+#[bench]
+fn to_i64(b: &mut Bencher) {
+    b.iter(|| Date::MAX.to_i64());
+}
+
 #[bench]
 fn year(b: &mut Bencher) {
     b.iter(|| Date::MIN.year());
 }
 
+#[bench]
+fn to_julian_day(b: &mut Bencher) {
+    b.iter(|| Date::MIN.to_julian_day());
+}
+
+#[bench]
+fn to_days_since_epoch(b: &mut Bencher) {
+    b.iter(|| Date::MAX.to_days_since_epoch());
+}
+
 #[bench]
 fn month(b: &mut Bencher) {
     b.iter(|| Date::MIN.month());
@@ -52,6 +137,111 @@ fn day(b: &mut Bencher) {
     b.iter(|| Date::MIN.day());
 }
 
+#[bench]
+fn month0(b: &mut Bencher) {
+    b.iter(|| Date::MIN.month0());
+}
+
+#[bench]
+fn day0(b: &mut Bencher) {
+    b.iter(|| Date::MIN.day0());
+}
+
+#[bench]
+fn ordinal(b: &mut Bencher) {
+    b.iter(|| Date::MIN.ordinal());
+}
+
+#[bench]
+fn weekday(b: &mut Bencher) {
+    b.iter(|| Date::MIN.weekday());
+}
+
+#[bench]
+fn weekday_ordinal(b: &mut Bencher) {
+    b.iter(|| Date::MAX.weekday_ordinal());
+}
+
+#[bench]
+fn is_weekend(b: &mut Bencher) {
+    b.iter(|| Date::MAX.is_weekend());
+}
+
+#[bench]
+fn with_year(b: &mut Bencher) {
+    b.iter(|| Date::MIN.with_year(2107).unwrap());
+}
+
+#[bench]
+fn first_of_month(b: &mut Bencher) {
+    b.iter(|| Date::MAX.first_of_month());
+}
+
+#[bench]
+fn last_of_month(b: &mut Bencher) {
+    b.iter(|| Date::MIN.last_of_month());
+}
+
+#[bench]
+fn next_day(b: &mut Bencher) {
+    b.iter(|| Date::MIN.next_day().unwrap());
+}
+
+#[bench]
+fn next_day_with_time(b: &mut Bencher) {
+    b.iter(|| Date::from_date(date!(1980-01-01).next_day().unwrap()).unwrap());
+}
+
+#[bench]
+fn previous_day(b: &mut Bencher) {
+    b.iter(|| Date::MAX.previous_day().unwrap());
+}
+
+#[bench]
+fn previous_day_with_time(b: &mut Bencher) {
+    b.iter(|| Date::from_date(date!(2107-12-31).previous_day().unwrap()).unwrap());
+}
+
+#[bench]
+fn checked_add_days(b: &mut Bencher) {
+    b.iter(|| Date::MIN.checked_add_days(46750).unwrap());
+}
+
+#[bench]
+fn checked_sub_days(b: &mut Bencher) {
+    b.iter(|| Date::MAX.checked_sub_days(46750).unwrap());
+}
+
+#[bench]
+fn checked_add_months(b: &mut Bencher) {
+    b.iter(|| Date::MIN.checked_add_months(1535).unwrap());
+}
+
+#[bench]
+fn checked_sub_months(b: &mut Bencher) {
+    b.iter(|| Date::MAX.checked_sub_months(1535).unwrap());
+}
+
+#[bench]
+fn months_since(b: &mut Bencher) {
+    b.iter(|| Date::MAX.months_since(Date::MIN));
+}
+
+#[bench]
+fn iter_months(b: &mut Bencher) {
+    b.iter(|| Date::MIN.iter_months(u32::MAX).count());
+}
+
+#[bench]
+fn iter_years(b: &mut Bencher) {
+    b.iter(|| Date::MIN.iter_years(u32::MAX).count());
+}
+
+#[bench]
+fn days_in_month_of(b: &mut Bencher) {
+    b.iter(|| Date::days_in_month_of(2000, 2));
+}
+
 #[bench]
 fn default(b: &mut Bencher) {
     b.iter(Date::default);
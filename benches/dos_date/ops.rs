@@ -0,0 +1,26 @@
+// SPDX-FileCopyrightText: 2025 Shun Sakai
+//
+// SPDX-License-Identifier: Apache-2.0 OR MIT
+
+use dos_date_time::{Date, Days, Months};
+use test::Bencher;
+
+#[bench]
+fn add_days(b: &mut Bencher) {
+    b.iter(|| Date::MIN + Days(46750));
+}
+
+#[bench]
+fn sub_days(b: &mut Bencher) {
+    b.iter(|| Date::MAX - Days(46750));
+}
+
+#[bench]
+fn add_months(b: &mut Bencher) {
+    b.iter(|| Date::MIN + Months(1535));
+}
+
+#[bench]
+fn sub_months(b: &mut Bencher) {
+    b.iter(|| Date::MAX - Months(1535));
+}
@@ -0,0 +1,34 @@
+// SPDX-FileCopyrightText: 2025 Shun Sakai
+//
+// SPDX-License-Identifier: Apache-2.0 OR MIT
+
+use dos_date_time::{Date, DateRange};
+use test::Bencher;
+
+#[bench]
+fn new(b: &mut Bencher) {
+    b.iter(|| DateRange::new(Date::MIN, Date::MAX));
+}
+
+#[bench]
+fn contains(b: &mut Bencher) {
+    let range = DateRange::new(Date::MIN, Date::MAX);
+    b.iter(|| range.contains(Date::MAX));
+}
+
+#[bench]
+fn len(b: &mut Bencher) {
+    let range = DateRange::new(Date::MIN, Date::MAX);
+    b.iter(|| range.len());
+}
+
+#[bench]
+fn is_empty(b: &mut Bencher) {
+    let range = DateRange::new(Date::MIN, Date::MAX);
+    b.iter(|| range.is_empty());
+}
+
+#[bench]
+fn from_range_inclusive_to_date_range(b: &mut Bencher) {
+    b.iter(|| DateRange::from(Date::MIN..=Date::MAX));
+}
@@ -2,7 +2,7 @@
 //
 // SPDX-License-Identifier: Apache-2.0 OR MIT
 
-use dos_date_time::DateTime;
+use dos_date_time::{Date, DateTime};
 use test::Bencher;
 
 #[bench]
@@ -14,3 +14,13 @@ fn equality(b: &mut Bencher) {
 fn order(b: &mut Bencher) {
     b.iter(|| DateTime::MAX > DateTime::MIN);
 }
+
+#[bench]
+fn equality_with_date(b: &mut Bencher) {
+    b.iter(|| DateTime::MIN == Date::MIN);
+}
+
+#[bench]
+fn order_with_date(b: &mut Bencher) {
+    b.iter(|| DateTime::MAX > Date::MIN);
+}
@@ -5,13 +5,18 @@
 #[cfg(feature = "chrono")]
 use chrono::NaiveDateTime;
 use dos_date_time::{
-    DateTime,
+    Date, DateTime, Time,
     time::{PrimitiveDateTime, macros::datetime},
 };
 #[cfg(feature = "jiff")]
 use jiff::civil;
 use test::Bencher;
 
+#[bench]
+fn from_date_time_to_bytes(b: &mut Bencher) {
+    b.iter(|| <[u8; 4]>::from(DateTime::MIN));
+}
+
 #[bench]
 fn try_from_date_time_to_primitive_date_time(b: &mut Bencher) {
     b.iter(|| PrimitiveDateTime::from(DateTime::MIN));
@@ -29,6 +34,11 @@ fn try_from_date_time_to_jiff_civil_date_time(b: &mut Bencher) {
     b.iter(|| civil::DateTime::from(DateTime::MIN));
 }
 
+#[bench]
+fn from_date_and_time_to_date_time(b: &mut Bencher) {
+    b.iter(|| DateTime::from((Date::MIN, Time::MIN)));
+}
+
 #[bench]
 fn try_from_primitive_date_time_to_date_time(b: &mut Bencher) {
     let dt = datetime!(1980-01-01 00:00:00);
@@ -48,3 +58,13 @@ fn try_from_jiff_civil_date_time_to_date_time(b: &mut Bencher) {
     let dt = civil::date(1980, 1, 1).at(0, 0, 0, 0);
     b.iter(|| DateTime::try_from(dt).unwrap());
 }
+
+#[bench]
+fn try_from_bytes_to_date_time(b: &mut Bencher) {
+    b.iter(|| DateTime::try_from([0x00, 0x00, 0x21, 0x00]).unwrap());
+}
+
+#[bench]
+fn is_representable(b: &mut Bencher) {
+    b.iter(|| dos_date_time::is_representable(datetime!(1980-01-01 00:00:00)));
+}
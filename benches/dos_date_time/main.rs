@@ -8,8 +8,14 @@ extern crate test;
 
 mod cmp;
 mod convert;
+mod ops;
 
-use dos_date_time::{Date, DateTime, Time, time::macros::date};
+use core::time::Duration;
+
+use dos_date_time::{
+    Date, DateTime, DosWords, Time, ValidationPolicy,
+    time::macros::{date, datetime},
+};
 use test::Bencher;
 
 #[bench]
@@ -17,16 +23,254 @@ fn new(b: &mut Bencher) {
     b.iter(|| DateTime::new(Date::MIN, Time::MIN));
 }
 
+#[bench]
+fn new_with_policy(b: &mut Bencher) {
+    b.iter(|| {
+        DateTime::new_with_policy(0b0000_0000_0010_0000, u16::MIN, ValidationPolicy::Lenient)
+            .unwrap()
+    });
+}
+
+#[bench]
+fn try_from_parts(b: &mut Bencher) {
+    b.iter(|| DateTime::try_from_parts(2107, 12, 31, 23, 59, 58).unwrap());
+}
+
 #[bench]
 fn from_date_time(b: &mut Bencher) {
     b.iter(|| DateTime::from_date_time(date!(1980-01-01), time::Time::MIDNIGHT).unwrap());
 }
 
+#[bench]
+fn from_date_time_exact(b: &mut Bencher) {
+    b.iter(|| DateTime::from_date_time_exact(date!(1980-01-01), time::Time::MIDNIGHT).unwrap());
+}
+
+#[bench]
+fn try_from_utc(b: &mut Bencher) {
+    b.iter(|| DateTime::try_from_utc(datetime!(1980-01-01 00:00:00 UTC)).unwrap());
+}
+
+#[bench]
+fn saturating_from_primitive(b: &mut Bencher) {
+    b.iter(|| DateTime::saturating_from_primitive(datetime!(1900-01-01 00:00:00)));
+}
+
+#[bench]
+fn from_unix_time_f64(b: &mut Bencher) {
+    b.iter(|| DateTime::from_unix_time_f64(315_532_800.0).unwrap());
+}
+
+#[cfg(feature = "std")]
+#[bench]
+fn from_system_time(b: &mut Bencher) {
+    let st = std::time::SystemTime::UNIX_EPOCH + Duration::from_secs(315_532_800);
+    b.iter(|| DateTime::from_system_time(st).unwrap());
+}
+
+#[cfg(feature = "jiff")]
+#[bench]
+fn from_dos_epoch_span(b: &mut Bencher) {
+    use dos_date_time::jiff::ToSpan;
+
+    b.iter(|| DateTime::from_dos_epoch_span(27.years().hours(12)).unwrap());
+}
+
+#[bench]
+fn from_ascii(b: &mut Bencher) {
+    b.iter(|| DateTime::from_ascii(b"1980-01-01 00:00:00").unwrap());
+}
+
+#[bench]
+fn from_raw_pair_str(b: &mut Bencher) {
+    b.iter(|| DateTime::from_raw_pair_str("0xFF9F,0xBF7D").unwrap());
+}
+
+#[bench]
+fn from_base32_key(b: &mut Bencher) {
+    b.iter(|| DateTime::from_base32_key("3ZSZFVX").unwrap());
+}
+
+#[bench]
+fn from_words(b: &mut Bencher) {
+    b.iter(|| {
+        DateTime::from_words(DosWords {
+            date: 0b1111_1111_1001_1111,
+            time: 0b1011_1111_0111_1101,
+        })
+        .unwrap()
+    });
+}
+
+#[bench]
+fn from_zip_u32(b: &mut Bencher) {
+    b.iter(|| DateTime::from_zip_u32(0xFF9F_BF7D).unwrap());
+}
+
+#[bench]
+fn from_index(b: &mut Bencher) {
+    b.iter(|| DateTime::from_index(DateTime::INDEX_COUNT - 1).unwrap());
+}
+
+#[bench]
+fn read_le(b: &mut Bencher) {
+    b.iter(|| DateTime::read_le(&mut [0x00, 0x00, 0x21, 0x00].as_slice()).unwrap());
+}
+
+#[cfg(feature = "std")]
+#[bench]
+fn read_all_zip(b: &mut Bencher) {
+    let bytes = [0x00, 0x00, 0x21, 0x00].repeat(16);
+    b.iter(|| DateTime::read_all_zip(&mut bytes.as_slice(), 16).unwrap());
+}
+
+#[bench]
+fn write_le(b: &mut Bencher) {
+    b.iter(|| DateTime::MIN.write_le(&mut [u8::MIN; 4]).unwrap());
+}
+
+#[bench]
+fn to_zip_bytes(b: &mut Bencher) {
+    b.iter(|| DateTime::MIN.to_zip_bytes());
+}
+
+#[bench]
+fn to_words(b: &mut Bencher) {
+    b.iter(|| DateTime::MAX.to_words());
+}
+
+#[bench]
+fn to_sort_key(b: &mut Bencher) {
+    b.iter(|| DateTime::MAX.to_sort_key());
+}
+
+#[bench]
+fn to_index(b: &mut Bencher) {
+    b.iter(|| DateTime::MAX.to_index());
+}
+
+#[bench]
+fn to_primitive(b: &mut Bencher) {
+    b.iter(|| DateTime::MAX.to_primitive());
+}
+
+#[bench]
+fn step_by(b: &mut Bencher) {
+    b.iter(|| DateTime::step_by(DateTime::MIN, Duration::from_secs(3600)).nth(24));
+}
+
 #[bench]
 fn is_valid(b: &mut Bencher) {
     b.iter(|| DateTime::MIN.is_valid());
 }
 
+#[bench]
+fn normalized(b: &mut Bencher) {
+    b.iter(|| DateTime::MIN.normalized().unwrap());
+}
+
+#[bench]
+fn invalid_reason(b: &mut Bencher) {
+    b.iter(|| DateTime::MIN.invalid_reason());
+}
+
+#[bench]
+fn clamp(b: &mut Bencher) {
+    b.iter(|| DateTime::MIN.clamp(DateTime::MIN, DateTime::MAX));
+}
+
+#[bench]
+fn clamp_year(b: &mut Bencher) {
+    b.iter(|| DateTime::MAX.clamp_year(1990, 1999));
+}
+
+#[bench]
+fn envelope(b: &mut Bencher) {
+    b.iter(|| DateTime::envelope([DateTime::MAX, DateTime::MIN]).unwrap());
+}
+
+#[bench]
+fn bisect(b: &mut Bencher) {
+    let sorted = [DateTime::MIN, DateTime::MAX];
+    b.iter(|| DateTime::bisect(&sorted, DateTime::MAX).unwrap());
+}
+
+#[bench]
+fn duration_until(b: &mut Bencher) {
+    let dt = datetime!(2107-12-31 23:59:58);
+    b.iter(|| DateTime::MIN.duration_until(dt));
+}
+
+#[bench]
+fn age_from(b: &mut Bencher) {
+    b.iter(|| DateTime::MIN.age_from(DateTime::MAX));
+}
+
+#[bench]
+fn approx_eq(b: &mut Bencher) {
+    b.iter(|| DateTime::MIN.approx_eq(DateTime::MAX, Duration::from_secs(2)));
+}
+
+#[bench]
+fn checked_add(b: &mut Bencher) {
+    b.iter(|| DateTime::MIN.checked_add(time::Duration::HOUR).unwrap());
+}
+
+#[bench]
+fn checked_sub(b: &mut Bencher) {
+    b.iter(|| DateTime::MAX.checked_sub(time::Duration::HOUR).unwrap());
+}
+
+#[bench]
+fn saturating_add(b: &mut Bencher) {
+    b.iter(|| DateTime::MAX.saturating_add(time::Duration::HOUR));
+}
+
+#[bench]
+fn saturating_sub(b: &mut Bencher) {
+    b.iter(|| DateTime::MIN.saturating_sub(time::Duration::HOUR));
+}
+
+#[bench]
+fn checked_next(b: &mut Bencher) {
+    b.iter(|| DateTime::MIN.checked_next().unwrap());
+}
+
+#[bench]
+fn checked_prev(b: &mut Bencher) {
+    b.iter(|| DateTime::MAX.checked_prev().unwrap());
+}
+
+#[bench]
+fn saturating_next(b: &mut Bencher) {
+    b.iter(|| DateTime::MAX.saturating_next());
+}
+
+#[bench]
+fn saturating_prev(b: &mut Bencher) {
+    b.iter(|| DateTime::MIN.saturating_prev());
+}
+
+#[bench]
+fn checked_add_days(b: &mut Bencher) {
+    b.iter(|| DateTime::MIN.checked_add_days(46750).unwrap());
+}
+
+#[bench]
+fn checked_sub_days(b: &mut Bencher) {
+    b.iter(|| DateTime::MAX.checked_sub_days(46750).unwrap());
+}
+
+#[bench]
+fn checked_add_months(b: &mut Bencher) {
+    b.iter(|| DateTime::MIN.checked_add_months(1535).unwrap());
+}
+
+#[bench]
+fn checked_sub_months(b: &mut Bencher) {
+    b.iter(|| DateTime::MAX.checked_sub_months(1535).unwrap());
+}
+
 #[bench]
 fn date(b: &mut Bencher) {
     b.iter(|| DateTime::MIN.date());
@@ -37,6 +281,68 @@ fn time(b: &mut Bencher) {
     b.iter(|| DateTime::MIN.time());
 }
 
+#[bench]
+fn as_tuple(b: &mut Bencher) {
+    b.iter(|| DateTime::MIN.as_tuple());
+}
+
+#[bench]
+fn with_date(b: &mut Bencher) {
+    b.iter(|| DateTime::MIN.with_date(Date::MAX));
+}
+
+#[bench]
+fn with_time(b: &mut Bencher) {
+    b.iter(|| DateTime::MIN.with_time(Time::MAX));
+}
+
+#[bench]
+fn with_time_from_secs(b: &mut Bencher) {
+    b.iter(|| DateTime::MIN.with_time_from_secs(86399).unwrap());
+}
+
+#[bench]
+fn with_date_from_ordinal(b: &mut Bencher) {
+    b.iter(|| DateTime::MIN.with_date_from_ordinal(2107, 365).unwrap());
+}
+
+#[bench]
+fn start_of_day(b: &mut Bencher) {
+    b.iter(|| DateTime::MAX.start_of_day());
+}
+
+#[bench]
+fn end_of_day(b: &mut Bencher) {
+    b.iter(|| DateTime::MIN.end_of_day());
+}
+
+#[bench]
+fn with_date_of(b: &mut Bencher) {
+    b.iter(|| DateTime::MIN.with_date_of(DateTime::MAX));
+}
+
+#[bench]
+fn with_time_of(b: &mut Bencher) {
+    b.iter(|| DateTime::MIN.with_time_of(DateTime::MAX));
+}
+
+#[bench]
+fn set_date(b: &mut Bencher) {
+    let mut dt = DateTime::MIN;
+    b.iter(|| dt.set_date(Date::MAX));
+}
+
+#[bench]
+fn set_time(b: &mut Bencher) {
+    let mut dt = DateTime::MIN;
+    b.iter(|| dt.set_time(Time::MAX));
+}
+
+#[bench]
+fn checked_set_year(b: &mut Bencher) {
+    b.iter(|| DateTime::MAX.checked_set_year(1980).unwrap());
+}
+
 #[bench]
 fn year(b: &mut Bencher) {
     b.iter(|| DateTime::MIN.year());
@@ -52,6 +358,16 @@ fn day(b: &mut Bencher) {
     b.iter(|| DateTime::MIN.day());
 }
 
+#[bench]
+fn month0(b: &mut Bencher) {
+    b.iter(|| DateTime::MIN.month0());
+}
+
+#[bench]
+fn day0(b: &mut Bencher) {
+    b.iter(|| DateTime::MIN.day0());
+}
+
 #[bench]
 fn hour(b: &mut Bencher) {
     b.iter(|| DateTime::MIN.hour());
@@ -67,6 +383,43 @@ fn second(b: &mut Bencher) {
     b.iter(|| DateTime::MIN.second());
 }
 
+#[bench]
+fn is_business_day(b: &mut Bencher) {
+    b.iter(|| DateTime::MIN.is_business_day());
+}
+
+#[bench]
+fn fields(b: &mut Bencher) {
+    b.iter(|| DateTime::MIN.fields());
+}
+
+#[bench]
+fn breakdown(b: &mut Bencher) {
+    b.iter(|| DateTime::MAX.breakdown());
+}
+
+#[bench]
+fn eq_to_the_minute(b: &mut Bencher) {
+    b.iter(|| DateTime::MIN.eq_to_the_minute(DateTime::MAX));
+}
+
+#[bench]
+fn with_utc_offset_byte(b: &mut Bencher) {
+    b.iter(|| DateTime::MIN.with_utc_offset_byte(0x80));
+}
+
+#[bench]
+fn exfat_utc_offset(b: &mut Bencher) {
+    let dt = DateTime::MIN.with_utc_offset_byte(0xF0);
+    b.iter(|| dt.utc_offset().unwrap());
+}
+
+#[bench]
+fn exfat_to_offset_date_time(b: &mut Bencher) {
+    let dt = DateTime::MIN.with_utc_offset_byte(0xF0);
+    b.iter(|| dt.to_offset_date_time().unwrap());
+}
+
 #[bench]
 fn default(b: &mut Bencher) {
     b.iter(DateTime::default);
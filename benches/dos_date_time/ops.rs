@@ -0,0 +1,46 @@
+// SPDX-FileCopyrightText: 2025 Shun Sakai
+//
+// SPDX-License-Identifier: Apache-2.0 OR MIT
+
+use dos_date_time::{DateTime, Days, Months, time::Duration};
+use test::Bencher;
+
+#[bench]
+fn add(b: &mut Bencher) {
+    b.iter(|| DateTime::MIN + Duration::HOUR);
+}
+
+#[bench]
+fn sub_duration(b: &mut Bencher) {
+    b.iter(|| DateTime::MAX - Duration::HOUR);
+}
+
+#[bench]
+fn add_days(b: &mut Bencher) {
+    b.iter(|| DateTime::MIN + Days(46750));
+}
+
+#[bench]
+fn sub_days(b: &mut Bencher) {
+    b.iter(|| DateTime::MAX - Days(46750));
+}
+
+#[bench]
+fn add_months(b: &mut Bencher) {
+    b.iter(|| DateTime::MIN + Months(1535));
+}
+
+#[bench]
+fn sub_months(b: &mut Bencher) {
+    b.iter(|| DateTime::MAX - Months(1535));
+}
+
+#[bench]
+fn sub(b: &mut Bencher) {
+    b.iter(|| DateTime::MAX - DateTime::MIN);
+}
+
+#[bench]
+fn between(b: &mut Bencher) {
+    b.iter(|| dos_date_time::between(DateTime::MIN, DateTime::MAX));
+}
@@ -9,6 +9,11 @@ use dos_date_time::Time;
 use jiff::civil;
 use test::Bencher;
 
+#[bench]
+fn from_time_to_u16(b: &mut Bencher) {
+    b.iter(|| u16::from(Time::MIN));
+}
+
 #[bench]
 fn from_time_to_time_time(b: &mut Bencher) {
     b.iter(|| time::Time::from(Time::MIN));
@@ -26,6 +31,17 @@ fn from_time_to_jiff_civil_time(b: &mut Bencher) {
     b.iter(|| civil::Time::from(Time::MIN));
 }
 
+#[cfg(feature = "jiff")]
+#[bench]
+fn from_time_to_tuple(b: &mut Bencher) {
+    b.iter(|| <(i8, i8, i8)>::from(Time::MIN));
+}
+
+#[bench]
+fn try_from_u16_to_time(b: &mut Bencher) {
+    b.iter(|| Time::try_from(u16::MIN).unwrap());
+}
+
 #[bench]
 fn from_time_time_to_time(b: &mut Bencher) {
     b.iter(|| Time::from(time::Time::MIDNIGHT));
@@ -17,21 +17,81 @@ fn new(b: &mut Bencher) {
     b.iter(|| Time::new(u16::MIN).unwrap());
 }
 
+#[bench]
+fn new_with_invalid_time(b: &mut Bencher) {
+    b.iter(|| Time::new(0b0000_0000_0001_1110));
+}
+
+#[bench]
+fn checked_from_raw(b: &mut Bencher) {
+    b.iter(|| Time::checked_from_raw(u16::MIN).unwrap());
+}
+
+#[bench]
+fn checked_new(b: &mut Bencher) {
+    b.iter(|| Time::checked_new(u16::MIN).unwrap());
+}
+
+#[bench]
+fn from_i64(b: &mut Bencher) {
+    b.iter(|| Time::from_i64(0b1011_1111_0111_1101).unwrap());
+}
+
+#[bench]
+fn wrapping_add_raw(b: &mut Bencher) {
+    b.iter(|| unsafe { Time::MIN.wrapping_add_raw(1) });
+}
+
 #[bench]
 fn from_time(b: &mut Bencher) {
     b.iter(|| Time::from_time(time::Time::MIDNIGHT));
 }
 
+#[bench]
+fn from_time_exact(b: &mut Bencher) {
+    b.iter(|| Time::from_time_exact(time::Time::MIDNIGHT).unwrap());
+}
+
+#[bench]
+fn from_second_of_day(b: &mut Bencher) {
+    b.iter(|| Time::from_second_of_day(86399).unwrap());
+}
+
+#[bench]
+fn from_hms_nano(b: &mut Bencher) {
+    b.iter(|| Time::from_hms_nano(23, 59, 59, 999_999_999).unwrap());
+}
+
+#[bench]
+fn all_valid(b: &mut Bencher) {
+    b.iter(|| Time::all_valid().count());
+}
+
 #[bench]
 fn is_valid(b: &mut Bencher) {
     b.iter(|| Time::MIN.is_valid());
 }
 
+#[bench]
+fn clamp(b: &mut Bencher) {
+    b.iter(|| Time::MIN.clamp(Time::MIN, Time::MAX));
+}
+
+#[bench]
+fn between(b: &mut Bencher) {
+    b.iter(|| Time::MIN.between(Time::MAX, Time::MIN));
+}
+
 #[bench]
 fn to_raw(b: &mut Bencher) {
     b.iter(|| Time::MIN.to_raw());
 }
 
+#[bench]
+fn to_i64(b: &mut Bencher) {
+    b.iter(|| Time::MAX.to_i64());
+}
+
 #[bench]
 fn hour(b: &mut Bencher) {
     b.iter(|| Time::MIN.hour());
@@ -47,6 +107,26 @@ fn second(b: &mut Bencher) {
     b.iter(|| Time::MIN.second());
 }
 
+#[bench]
+fn second_of_day(b: &mut Bencher) {
+    b.iter(|| Time::MAX.second_of_day());
+}
+
+#[bench]
+fn duration_until(b: &mut Bencher) {
+    b.iter(|| Time::MIN.duration_until(Time::MAX).unwrap());
+}
+
+#[bench]
+fn wrapping_duration_until(b: &mut Bencher) {
+    b.iter(|| Time::MAX.wrapping_duration_until(Time::MIN));
+}
+
+#[bench]
+fn round_to_minute(b: &mut Bencher) {
+    b.iter(|| Time::MAX.round_to_minute());
+}
+
 #[bench]
 fn default(b: &mut Bencher) {
     b.iter(Time::default);
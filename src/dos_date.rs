@@ -10,10 +10,17 @@ mod cmp;
 mod consts;
 mod convert;
 mod fmt;
+mod ops;
+mod range;
 
-use time::Month;
+use core::ops::RangeBounds;
 
-use crate::error::{DateRangeError, DateRangeErrorKind};
+use time::{Month, Weekday};
+
+pub use self::{convert::is_date_representable, range::DateRange};
+use crate::error::{
+    DateError, DateErrorKind, DateRangeError, DateRangeErrorKind, InvalidDateField,
+};
 
 /// `Date` is a type that represents the [MS-DOS date].
 ///
@@ -30,7 +37,6 @@ use crate::error::{DateRangeError, DateRangeErrorKind};
 pub struct Date(u16);
 
 impl Date {
-    #[allow(clippy::missing_panics_doc)]
     /// Creates a new `Date` with the given MS-DOS date.
     ///
     /// Returns [`None`] if the given MS-DOS date is not a valid MS-DOS date.
@@ -48,18 +54,98 @@ impl Date {
     /// ```
     #[must_use]
     pub fn new(date: u16) -> Option<Self> {
+        Self::checked_from_raw(date).ok()
+    }
+
+    #[allow(clippy::missing_panics_doc)]
+    /// Creates a new `Date` with the given MS-DOS date.
+    ///
+    /// Unlike [`Date::new`], this reports which field is invalid.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`Err`] if the given MS-DOS date is not a valid MS-DOS date.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use dos_date_time::{Date, error::InvalidDateField};
+    /// #
+    /// assert_eq!(Date::checked_from_raw(0b0000_0000_0010_0001), Ok(Date::MIN));
+    ///
+    /// // The Day field is 30, which is after the last day of February.
+    /// assert_eq!(
+    ///     Date::checked_from_raw(0b0000_0010_0101_1110),
+    ///     Err(InvalidDateField::DayTooLarge { day: 30, max: 28 })
+    /// );
+    /// ```
+    pub fn checked_from_raw(date: u16) -> Result<Self, InvalidDateField> {
         let (year, month, day) = (
-            (1980 + (date >> 9)).into(),
-            u8::try_from((date >> 5) & 0x0F)
-                .expect("month should be in the range of `u8`")
-                .try_into()
-                .ok()?,
-            (date & 0x1F)
-                .try_into()
-                .expect("day should be in the range of `u8`"),
+            1980 + (date >> 9),
+            u8::try_from((date >> 5) & 0x0F).expect("month should be in the range of `u8`"),
+            u8::try_from(date & 0x1F).expect("day should be in the range of `u8`"),
         );
-        let date = time::Date::from_calendar_date(year, month, day).ok()?;
-        Self::from_date(date).ok()
+        match month {
+            0 => return Err(InvalidDateField::MonthZero),
+            13.. => return Err(InvalidDateField::MonthTooLarge(month)),
+            _ => {}
+        }
+        let max = Self::days_in_month_of(year, month);
+        match day {
+            0 => Err(InvalidDateField::DayZero),
+            day if day > max => Err(InvalidDateField::DayTooLarge { day, max }),
+            // SAFETY: `date` is a valid as the MS-DOS date.
+            _ => Ok(unsafe { Self::new_unchecked(date) }),
+        }
+    }
+
+    /// Creates a new `Date` with the given MS-DOS date.
+    ///
+    /// This is an alias for [`Date::checked_from_raw`].
+    ///
+    /// # Errors
+    ///
+    /// Returns [`Err`] if the given MS-DOS date is not a valid MS-DOS date.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use dos_date_time::{Date, error::InvalidDateField};
+    /// #
+    /// assert_eq!(Date::checked_new(0b0000_0000_0010_0001), Ok(Date::MIN));
+    ///
+    /// // The Month field is 0.
+    /// assert_eq!(
+    ///     Date::checked_new(0b0000_0000_0000_0001),
+    ///     Err(InvalidDateField::MonthZero)
+    /// );
+    /// ```
+    pub fn checked_new(date: u16) -> Result<Self, InvalidDateField> {
+        Self::checked_from_raw(date)
+    }
+
+    /// Creates a new `Date` with the given MS-DOS date as an [`i64`] value.
+    ///
+    /// This is intended for interoperability with databases which store the
+    /// MS-DOS date as a signed integer. Returns [`None`] if `date` is out of
+    /// range for [`u16`], or if it is not a valid MS-DOS date.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use dos_date_time::Date;
+    /// #
+    /// assert_eq!(Date::from_i64(33), Some(Date::MIN));
+    /// assert_eq!(Date::from_i64(65_439), Some(Date::MAX));
+    ///
+    /// assert_eq!(Date::from_i64(-1), None);
+    /// assert_eq!(Date::from_i64(65536), None);
+    /// // The Day field is 0.
+    /// assert_eq!(Date::from_i64(0b0000_0000_0010_0000), None);
+    /// ```
+    #[must_use]
+    pub fn from_i64(date: i64) -> Option<Self> {
+        u16::try_from(date).ok().and_then(Self::new)
     }
 
     /// Creates a new `Date` with the given MS-DOS date.
@@ -72,6 +158,53 @@ impl Date {
         Self(date)
     }
 
+    /// Adds `delta` to the MS-DOS date of this `Date`, wrapping around at the
+    /// boundary of [`u16`].
+    ///
+    /// This operates on the raw MS-DOS date, not on the calendar, so it is
+    /// intended for low-level manipulation such as fuzzing. The result may
+    /// not be a valid MS-DOS date.
+    ///
+    /// # Safety
+    ///
+    /// The returned `Date` may not be a valid MS-DOS date. Before it is used
+    /// for anything other than [`Date::is_valid`] and [`Date::to_raw`], it must
+    /// be checked with [`Date::is_valid`].
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use dos_date_time::{Date, time::macros::date};
+    /// #
+    /// let date = unsafe { Date::MIN.wrapping_add_raw(1) };
+    /// assert!(date.is_valid());
+    /// assert_eq!(date, Date::from_date(date!(1980-01-02)).unwrap());
+    ///
+    /// let date = unsafe { Date::MAX.wrapping_add_raw(u16::MAX) };
+    /// assert_eq!(date.to_raw(), Date::MAX.to_raw() - 1);
+    /// ```
+    #[must_use]
+    pub const unsafe fn wrapping_add_raw(self, delta: u16) -> Self {
+        Self(self.to_raw().wrapping_add(delta))
+    }
+
+    #[doc(hidden)]
+    #[must_use]
+    pub const fn __from_calendar_date(year: u16, month: u16, day: u16) -> Self {
+        assert!(
+            1980 <= year && year <= 2107,
+            "year should be in the range of the MS-DOS date"
+        );
+        assert!(1 <= month && month <= 12, "month should be in `1..=12`");
+        assert!(
+            1 <= day && day <= Self::days_in_month(year, month),
+            "day should be a valid day of the month"
+        );
+        let date = ((year - 1980) << 9) | (month << 5) | day;
+        // SAFETY: `date` is a valid as the MS-DOS date.
+        unsafe { Self::new_unchecked(date) }
+    }
+
     #[allow(clippy::missing_panics_doc)]
     /// Creates a new `Date` with the given [`time::Date`].
     ///
@@ -110,11 +243,267 @@ impl Date {
         }
     }
 
+    /// Creates a new `Date` with the given year, month, and day.
+    ///
+    /// Unlike [`Date::from_date`], this does not require constructing a
+    /// [`time::Date`] first.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`Err`] if `day` is not a valid day of `month` in `year`, or if
+    /// the date is out of range for the MS-DOS date.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use dos_date_time::{Date, time::Month};
+    /// #
+    /// assert_eq!(
+    ///     Date::from_year_month_day(1980, Month::January, 1),
+    ///     Ok(Date::MIN)
+    /// );
+    /// assert_eq!(
+    ///     Date::from_year_month_day(2107, Month::December, 31),
+    ///     Ok(Date::MAX)
+    /// );
+    ///
+    /// // `2001` is not a leap year.
+    /// assert!(Date::from_year_month_day(2001, Month::February, 29).is_err());
+    /// // After `2107-12-31`.
+    /// assert!(Date::from_year_month_day(2108, Month::January, 1).is_err());
+    /// ```
+    pub fn from_year_month_day(year: u16, month: Month, day: u8) -> Result<Self, DateError> {
+        let year = match year {
+            ..=1979 => return Err(DateErrorKind::Range(DateRangeErrorKind::Negative).into()),
+            2108.. => return Err(DateErrorKind::Range(DateRangeErrorKind::Overflow).into()),
+            year => year - 1980,
+        };
+        if !(1..=Self::days_in_month_of(1980 + year, u8::from(month))).contains(&day) {
+            return Err(DateErrorKind::InvalidDay.into());
+        }
+        let date = (year << 9) | (u16::from(u8::from(month)) << 5) | u16::from(day);
+        // SAFETY: `date` is a valid as the MS-DOS date.
+        let date = unsafe { Self::new_unchecked(date) };
+        Ok(date)
+    }
+
+    #[allow(clippy::missing_panics_doc)]
+    /// Creates a new `Date` with the given year, month, and day, with the same
+    /// parameter types as [`chrono::NaiveDate::from_ymd_opt`].
+    ///
+    /// This eases migrating code which uses [`chrono`]. `month` and `day` are
+    /// 1-based.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`Err`] if `month` is not in the range `1..=12`, if `day` is not
+    /// a valid day of `month` in `year`, or if the date is out of range for
+    /// the MS-DOS date.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use dos_date_time::Date;
+    /// #
+    /// assert_eq!(Date::from_ymd_i32(1980, 1, 1), Ok(Date::MIN));
+    /// assert_eq!(Date::from_ymd_i32(2107, 12, 31), Ok(Date::MAX));
+    ///
+    /// // Before `1980-01-01`.
+    /// assert!(Date::from_ymd_i32(1979, 12, 31).is_err());
+    /// // The month is 13.
+    /// assert!(Date::from_ymd_i32(2018, 13, 17).is_err());
+    /// ```
+    ///
+    /// [`chrono::NaiveDate::from_ymd_opt`]: https://docs.rs/chrono/latest/chrono/naive/struct.NaiveDate.html#method.from_ymd_opt
+    /// [`chrono`]: https://docs.rs/chrono
+    pub fn from_ymd_i32(year: i32, month: u32, day: u32) -> Result<Self, DateError> {
+        let year = match year {
+            ..=1979 => return Err(DateErrorKind::Range(DateRangeErrorKind::Negative).into()),
+            2108.. => return Err(DateErrorKind::Range(DateRangeErrorKind::Overflow).into()),
+            year => u16::try_from(year).expect("year should be in the range of `u16`"),
+        };
+        let month = u8::try_from(month)
+            .ok()
+            .and_then(|month| Month::try_from(month).ok())
+            .ok_or(DateErrorKind::InvalidMonth)?;
+        let day = u8::try_from(day).map_err(|_| DateErrorKind::InvalidDay)?;
+        Self::from_year_month_day(year, month, day)
+    }
+
+    /// Creates a new `Date` with the given year and ordinal day.
+    ///
+    /// The ordinal day is the day of the year, in the range `1..=365`, or
+    /// `1..=366` in a leap year.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`Err`] if `ordinal` is not a valid ordinal day in `year`, or if
+    /// the date is out of range for the MS-DOS date.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use dos_date_time::Date;
+    /// #
+    /// assert_eq!(Date::from_ordinal_date(1980, 1), Ok(Date::MIN));
+    /// assert_eq!(Date::from_ordinal_date(2107, 365), Ok(Date::MAX));
+    ///
+    /// // `2107` is not a leap year.
+    /// assert!(Date::from_ordinal_date(2107, 366).is_err());
+    /// // Before `1980-01-01`.
+    /// assert!(Date::from_ordinal_date(1979, 365).is_err());
+    /// ```
+    pub fn from_ordinal_date(year: u16, ordinal: u16) -> Result<Self, DateError> {
+        match year {
+            ..=1979 => Err(DateErrorKind::Range(DateRangeErrorKind::Negative).into()),
+            2108.. => Err(DateErrorKind::Range(DateRangeErrorKind::Overflow).into()),
+            year => {
+                let date = time::Date::from_ordinal_date(year.into(), ordinal)
+                    .map_err(|_| DateErrorKind::InvalidOrdinal)?;
+                let date = Self::from_date(date)?;
+                Ok(date)
+            }
+        }
+    }
+
+    /// Creates a new `Date` with the given [Julian day number].
+    ///
+    /// # Errors
+    ///
+    /// Returns [`Err`] if `jdn` is out of range for the MS-DOS date.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use dos_date_time::Date;
+    /// #
+    /// assert_eq!(Date::from_julian_day(2_444_240), Ok(Date::MIN));
+    /// assert_eq!(Date::from_julian_day(2_490_990), Ok(Date::MAX));
+    ///
+    /// // Before `1980-01-01`.
+    /// assert!(Date::from_julian_day(2_444_239).is_err());
+    /// // After `2107-12-31`.
+    /// assert!(Date::from_julian_day(2_490_991).is_err());
+    /// ```
+    ///
+    /// [Julian day number]: https://en.wikipedia.org/wiki/Julian_day
+    pub fn from_julian_day(jdn: i64) -> Result<Self, DateRangeError> {
+        i32::try_from(jdn)
+            .ok()
+            .and_then(|jdn| time::Date::from_julian_day(jdn).ok())
+            .map_or_else(
+                || {
+                    if jdn.is_negative() {
+                        Err(DateRangeErrorKind::Negative.into())
+                    } else {
+                        Err(DateRangeErrorKind::Overflow.into())
+                    }
+                },
+                Self::from_date,
+            )
+    }
+
+    /// Creates a new `Date` with the given number of days since the MS-DOS
+    /// epoch, 1980-01-01.
+    ///
+    /// This is the inverse of [`Date::to_days_since_epoch`].
+    ///
+    /// # Errors
+    ///
+    /// Returns [`Err`] if `days` is out of range for the MS-DOS date.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use dos_date_time::Date;
+    /// #
+    /// assert_eq!(Date::from_days_since_epoch(0), Ok(Date::MIN));
+    /// assert_eq!(Date::from_days_since_epoch(46750), Ok(Date::MAX));
+    ///
+    /// // After `2107-12-31`.
+    /// assert!(Date::from_days_since_epoch(46751).is_err());
+    /// ```
+    pub fn from_days_since_epoch(days: u16) -> Result<Self, DateRangeError> {
+        Self::from_julian_day(Self::MIN.to_julian_day() + i64::from(days))
+    }
+
+    /// Returns an iterator over all valid MS-DOS dates in ascending order.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use dos_date_time::Date;
+    /// #
+    /// let mut dates = Date::all_valid();
+    /// assert_eq!(dates.next(), Some(Date::MIN));
+    /// assert_eq!(dates.last(), Some(Date::MAX));
+    ///
+    /// assert_eq!(Date::all_valid().count(), 46751);
+    /// ```
+    pub fn all_valid() -> impl Iterator<Item = Self> {
+        (u16::MIN..=u16::MAX).filter_map(Self::new)
+    }
+
     /// Returns [`true`] if `self` is a valid MS-DOS date, and [`false`]
     /// otherwise.
     #[must_use]
     pub fn is_valid(self) -> bool {
-        Self::new(self.to_raw()).is_some()
+        Self::checked_from_raw(self.to_raw()).is_ok()
+    }
+
+    /// Returns [`true`] if `self` is contained in `range`, and [`false`]
+    /// otherwise.
+    ///
+    /// This accepts any range of `Date`, including open-ended ranges such as
+    /// `start..` and `..=end`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use dos_date_time::{Date, time::macros::date};
+    /// #
+    /// let start = Date::from_date(date!(2000-01-01)).unwrap();
+    /// let end = Date::from_date(date!(2000-12-31)).unwrap();
+    ///
+    /// assert!(start.in_range(start..=end));
+    /// assert!(!end.in_range(start..end));
+    /// assert!(Date::MIN.in_range(..start));
+    /// assert!(Date::MAX.in_range(end..));
+    /// ```
+    #[must_use]
+    pub fn in_range<R: RangeBounds<Self>>(self, range: R) -> bool {
+        range.contains(&self)
+    }
+
+    /// Restricts this `Date` to the range `min..=max`.
+    ///
+    /// This is the same as [`Ord::clamp`]. `Date` has a total order, which is
+    /// the chronological order, so any two values can be compared.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `min > max`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use dos_date_time::{Date, time::macros::date};
+    /// #
+    /// let (min, max) = (
+    ///     Date::from_date(date!(2002-11-26)).unwrap(),
+    ///     Date::from_date(date!(2107-01-01)).unwrap(),
+    /// );
+    /// assert_eq!(Date::MIN.clamp(min, max), min);
+    /// assert_eq!(
+    ///     Date::from_date(date!(2018-11-17)).unwrap().clamp(min, max),
+    ///     Date::from_date(date!(2018-11-17)).unwrap()
+    /// );
+    /// assert_eq!(Date::MAX.clamp(min, max), max);
+    /// ```
+    #[must_use]
+    pub fn clamp(self, min: Self, max: Self) -> Self {
+        assert!(min <= max, "`min` should be less than or equal to `max`");
+        Ord::clamp(self, min, max)
     }
 
     /// Returns the MS-DOS date of this `Date` as the underlying [`u16`] value.
@@ -127,11 +516,30 @@ impl Date {
     /// assert_eq!(Date::MIN.to_raw(), 0b0000_0000_0010_0001);
     /// assert_eq!(Date::MAX.to_raw(), 0b1111_1111_1001_1111);
     /// ```
+    #[inline]
     #[must_use]
     pub const fn to_raw(self) -> u16 {
         self.0
     }
 
+    /// Returns the MS-DOS date of this `Date` as an [`i64`] value.
+    ///
+    /// This is the underlying [`u16`] value widened to [`i64`], and is the
+    /// inverse of [`Date::from_i64`].
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use dos_date_time::Date;
+    /// #
+    /// assert_eq!(Date::MIN.to_i64(), 33);
+    /// assert_eq!(Date::MAX.to_i64(), 65_439);
+    /// ```
+    #[must_use]
+    pub const fn to_i64(self) -> i64 {
+        self.to_raw() as i64
+    }
+
     /// Gets the year of this `Date`.
     ///
     /// # Examples
@@ -142,11 +550,51 @@ impl Date {
     /// assert_eq!(Date::MIN.year(), 1980);
     /// assert_eq!(Date::MAX.year(), 2107);
     /// ```
+    #[inline]
     #[must_use]
     pub const fn year(self) -> u16 {
         1980 + (self.to_raw() >> 9)
     }
 
+    /// Gets the [Julian day number] of this `Date`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use dos_date_time::Date;
+    /// #
+    /// assert_eq!(Date::MIN.to_julian_day(), 2_444_240);
+    /// assert_eq!(Date::MAX.to_julian_day(), 2_490_990);
+    /// ```
+    ///
+    /// [Julian day number]: https://en.wikipedia.org/wiki/Julian_day
+    #[must_use]
+    pub fn to_julian_day(self) -> i64 {
+        time::Date::from(self).to_julian_day().into()
+    }
+
+    #[allow(clippy::missing_panics_doc)]
+    /// Gets the number of days since the MS-DOS epoch, 1980-01-01, of this
+    /// `Date`.
+    ///
+    /// This is a compact index of the date, which is in the range
+    /// `0..=46750`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use dos_date_time::Date;
+    /// #
+    /// assert_eq!(Date::MIN.to_days_since_epoch(), 0);
+    /// assert_eq!(Date::MAX.to_days_since_epoch(), 46750);
+    /// ```
+    #[must_use]
+    pub fn to_days_since_epoch(self) -> u16 {
+        (self.to_julian_day() - Self::MIN.to_julian_day())
+            .try_into()
+            .expect("number of days should be in the range of `u16`")
+    }
+
     #[allow(clippy::missing_panics_doc)]
     /// Gets the month of this `Date`.
     ///
@@ -158,6 +606,7 @@ impl Date {
     /// assert_eq!(Date::MIN.month(), Month::January);
     /// assert_eq!(Date::MAX.month(), Month::December);
     /// ```
+    #[inline]
     #[must_use]
     pub fn month(self) -> Month {
         u8::try_from((self.to_raw() >> 5) & 0x0F)
@@ -177,239 +626,2101 @@ impl Date {
     /// assert_eq!(Date::MIN.day(), 1);
     /// assert_eq!(Date::MAX.day(), 31);
     /// ```
+    #[inline]
     #[must_use]
     pub fn day(self) -> u8 {
         (self.to_raw() & 0x1F)
             .try_into()
             .expect("day should be in the range of `u8`")
     }
-}
 
-impl Default for Date {
-    /// Returns the default value of "1980-01-01".
+    /// Gets the zero-based month of this `Date`.
     ///
-    /// Equivalent to [`Date::MIN`] except that it is not callable in const
-    /// contexts.
+    /// The returned value is in the range `0..=11`, which is useful for
+    /// indexing into an array of 12 elements.
     ///
     /// # Examples
     ///
     /// ```
     /// # use dos_date_time::Date;
     /// #
-    /// assert_eq!(Date::default(), Date::MIN);
+    /// assert_eq!(Date::MIN.month0(), 0);
+    /// assert_eq!(Date::MAX.month0(), 11);
     /// ```
-    fn default() -> Self {
-        Self::MIN
+    #[allow(clippy::cast_possible_truncation)]
+    #[must_use]
+    pub const fn month0(self) -> u8 {
+        // The Month field is 4 bits, so this never truncates.
+        ((self.to_raw() >> 5) & 0x0F) as u8 - 1
     }
-}
-
-#[cfg(test)]
-mod tests {
-    use core::mem;
-    #[cfg(feature = "std")]
-    use std::{
-        collections::hash_map::DefaultHasher,
-        hash::{Hash, Hasher},
-    };
-
-    use time::macros::date;
-
-    use super::*;
 
-    #[test]
-    fn size_of() {
-        assert_eq!(mem::size_of::<Date>(), mem::size_of::<u16>());
+    /// Gets the zero-based day of the month of this `Date`.
+    ///
+    /// The returned value is in the range `0..=30`, which is useful for
+    /// indexing into an array of 31 elements.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use dos_date_time::Date;
+    /// #
+    /// assert_eq!(Date::MIN.day0(), 0);
+    /// assert_eq!(Date::MAX.day0(), 30);
+    /// ```
+    #[allow(clippy::cast_possible_truncation)]
+    #[must_use]
+    pub const fn day0(self) -> u8 {
+        // The Day field is 5 bits, so this never truncates.
+        (self.to_raw() & 0x1F) as u8 - 1
     }
 
-    #[test]
+    /// Gets the ordinal day of this `Date`.
+    ///
+    /// The ordinal day is the day of the year, in the range `1..=365`, or
+    /// `1..=366` in a leap year.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use dos_date_time::Date;
+    /// #
+    /// assert_eq!(Date::MIN.ordinal(), 1);
+    /// assert_eq!(Date::MAX.ordinal(), 365);
+    /// ```
+    #[must_use]
+    pub fn ordinal(self) -> u16 {
+        time::Date::from(self).ordinal()
+    }
+
+    /// Gets the weekday of this `Date`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use dos_date_time::{Date, time::Weekday};
+    /// #
+    /// assert_eq!(Date::MIN.weekday(), Weekday::Tuesday);
+    /// assert_eq!(Date::MAX.weekday(), Weekday::Saturday);
+    /// ```
+    #[must_use]
+    pub fn weekday(self) -> Weekday {
+        time::Date::from(self).weekday()
+    }
+
+    /// Gets which occurrence of its weekday within the month this `Date` is.
+    ///
+    /// The returned value is in the range `1..=5`. For example, this returns
+    /// `3` for the third Saturday of a month.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use dos_date_time::{Date, time::macros::date};
+    /// #
+    /// assert_eq!(Date::MIN.weekday_ordinal(), 1);
+    /// assert_eq!(
+    ///     Date::from_date(date!(2018-11-17))
+    ///         .unwrap()
+    ///         .weekday_ordinal(),
+    ///     3
+    /// );
+    /// assert_eq!(Date::MAX.weekday_ordinal(), 5);
+    /// ```
+    #[must_use]
+    pub const fn weekday_ordinal(self) -> u8 {
+        self.day0() / 7 + 1
+    }
+
+    /// Returns [`true`] if this `Date` is a Saturday or a Sunday.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use dos_date_time::Date;
+    /// #
+    /// assert!(Date::MAX.is_weekend());
+    ///
+    /// // `1980-01-01` is a Tuesday.
+    /// assert!(!Date::MIN.is_weekend());
+    /// ```
+    #[must_use]
+    pub fn is_weekend(self) -> bool {
+        matches!(self.weekday(), Weekday::Saturday | Weekday::Sunday)
+    }
+
+    /// Returns a `Date` with the year of this `Date` replaced by `year`,
+    /// keeping the month and the day.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`Err`] if the day of this `Date` is not a valid day of the
+    /// month in `year`, such as February 29 in a non-leap year, or if `year`
+    /// is out of range for the MS-DOS date.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use dos_date_time::{Date, time::macros::date};
+    /// #
+    /// let date = Date::from_date(date!(2000-02-29)).unwrap();
+    /// assert_eq!(
+    ///     date.with_year(2004),
+    ///     Ok(Date::from_date(date!(2004-02-29)).unwrap())
+    /// );
+    ///
+    /// // `2001` is not a leap year.
+    /// assert!(date.with_year(2001).is_err());
+    /// // After `2107-12-31`.
+    /// assert!(date.with_year(2108).is_err());
+    /// ```
+    pub fn with_year(self, year: u16) -> Result<Self, DateError> {
+        Self::from_year_month_day(year, self.month(), self.day())
+    }
+
+    /// Returns the first day of the month of this `Date`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use dos_date_time::{Date, time::macros::date};
+    /// #
+    /// assert_eq!(
+    ///     Date::from_date(date!(2000-02-15)).unwrap().first_of_month(),
+    ///     Date::from_date(date!(2000-02-01)).unwrap()
+    /// );
+    /// assert_eq!(Date::MIN.first_of_month(), Date::MIN);
+    /// ```
+    #[must_use]
+    pub const fn first_of_month(self) -> Self {
+        let date = (self.to_raw() & !0x1F) | 1;
+        // SAFETY: `date` is a valid as the MS-DOS date.
+        unsafe { Self::new_unchecked(date) }
+    }
+
+    /// Returns the last day of the month of this `Date`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use dos_date_time::{Date, time::macros::date};
+    /// #
+    /// assert_eq!(
+    ///     Date::from_date(date!(2000-02-15)).unwrap().last_of_month(),
+    ///     Date::from_date(date!(2000-02-29)).unwrap()
+    /// );
+    /// assert_eq!(Date::MAX.last_of_month(), Date::MAX);
+    /// ```
+    #[must_use]
+    pub const fn last_of_month(self) -> Self {
+        let date = self.to_raw();
+        let last = Self::days_in_month(self.year(), (date >> 5) & 0x0F);
+        let date = (date & !0x1F) | last;
+        // SAFETY: `date` is a valid as the MS-DOS date.
+        unsafe { Self::new_unchecked(date) }
+    }
+
+    /// Returns the next day, or [`None`] if this `Date` is [`Date::MAX`].
+    ///
+    /// This does not use the [`time`] crate, so it is callable in const
+    /// contexts.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use dos_date_time::{Date, time::macros::date};
+    /// #
+    /// assert_eq!(
+    ///     Date::MIN.next_day(),
+    ///     Some(Date::from_date(date!(1980-01-02)).unwrap())
+    /// );
+    /// assert_eq!(
+    ///     Date::from_date(date!(2000-02-28)).unwrap().next_day(),
+    ///     Some(Date::from_date(date!(2000-02-29)).unwrap())
+    /// );
+    /// assert_eq!(
+    ///     Date::from_date(date!(2001-12-31)).unwrap().next_day(),
+    ///     Some(Date::from_date(date!(2002-01-01)).unwrap())
+    /// );
+    /// assert_eq!(Date::MAX.next_day(), None);
+    /// ```
+    #[must_use]
+    pub const fn next_day(self) -> Option<Self> {
+        let date = self.to_raw();
+        let (year, month, day) = (self.year(), (date >> 5) & 0x0F, date & 0x1F);
+        let date = if day < Self::days_in_month(year, month) {
+            date + 1
+        } else if month < 12 {
+            (date & 0xFE00) | ((month + 1) << 5) | 1
+        } else if year < 2107 {
+            ((year + 1 - 1980) << 9) | (1 << 5) | 1
+        } else {
+            return None;
+        };
+        // SAFETY: `date` is a valid as the MS-DOS date.
+        Some(unsafe { Self::new_unchecked(date) })
+    }
+
+    /// Returns the previous day, or [`None`] if this `Date` is [`Date::MIN`].
+    ///
+    /// This does not use the [`time`] crate, so it is callable in const
+    /// contexts.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use dos_date_time::{Date, time::macros::date};
+    /// #
+    /// assert_eq!(Date::MIN.previous_day(), None);
+    /// assert_eq!(
+    ///     Date::from_date(date!(2000-03-01)).unwrap().previous_day(),
+    ///     Some(Date::from_date(date!(2000-02-29)).unwrap())
+    /// );
+    /// assert_eq!(
+    ///     Date::from_date(date!(2002-01-01)).unwrap().previous_day(),
+    ///     Some(Date::from_date(date!(2001-12-31)).unwrap())
+    /// );
+    /// assert_eq!(
+    ///     Date::MAX.previous_day(),
+    ///     Some(Date::from_date(date!(2107-12-30)).unwrap())
+    /// );
+    /// ```
+    #[must_use]
+    pub const fn previous_day(self) -> Option<Self> {
+        let date = self.to_raw();
+        let (year, month, day) = (self.year(), (date >> 5) & 0x0F, date & 0x1F);
+        let date = if day > 1 {
+            date - 1
+        } else if month > 1 {
+            (date & 0xFE00) | ((month - 1) << 5) | Self::days_in_month(year, month - 1)
+        } else if year > 1980 {
+            ((year - 1 - 1980) << 9) | (12 << 5) | 31
+        } else {
+            return None;
+        };
+        // SAFETY: `date` is a valid as the MS-DOS date.
+        Some(unsafe { Self::new_unchecked(date) })
+    }
+
+    /// Computes `self + days`, returning [`None`] if the result is out of range
+    /// for the MS-DOS date.
+    ///
+    /// `days` may be negative, in which case this moves backward.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use dos_date_time::{Date, time::macros::date};
+    /// #
+    /// assert_eq!(
+    ///     Date::MIN.checked_add_days(31),
+    ///     Some(Date::from_date(date!(1980-02-01)).unwrap())
+    /// );
+    /// assert_eq!(Date::MIN.checked_add_days(46750), Some(Date::MAX));
+    ///
+    /// assert_eq!(Date::MAX.checked_add_days(1), None);
+    /// assert_eq!(Date::MIN.checked_add_days(-1), None);
+    /// ```
+    #[must_use]
+    pub fn checked_add_days(self, days: i32) -> Option<Self> {
+        Self::from_julian_day(self.to_julian_day() + i64::from(days)).ok()
+    }
+
+    /// Computes `self - days`, returning [`None`] if the result is out of range
+    /// for the MS-DOS date.
+    ///
+    /// `days` may be negative, in which case this moves forward.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use dos_date_time::{Date, time::macros::date};
+    /// #
+    /// assert_eq!(
+    ///     Date::MAX.checked_sub_days(31),
+    ///     Some(Date::from_date(date!(2107-11-30)).unwrap())
+    /// );
+    /// assert_eq!(Date::MAX.checked_sub_days(46750), Some(Date::MIN));
+    ///
+    /// assert_eq!(Date::MIN.checked_sub_days(1), None);
+    /// assert_eq!(Date::MAX.checked_sub_days(-1), None);
+    /// ```
+    #[must_use]
+    pub fn checked_sub_days(self, days: i32) -> Option<Self> {
+        Self::from_julian_day(self.to_julian_day() - i64::from(days)).ok()
+    }
+
+    /// Computes `self + months`, returning [`None`] if the result is out of
+    /// range for the MS-DOS date.
+    ///
+    /// `months` may be negative, in which case this moves backward. The day of
+    /// this `Date` is kept, but is clamped to the last day of the month if the
+    /// resulting month is shorter.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use dos_date_time::{Date, time::macros::date};
+    /// #
+    /// assert_eq!(
+    ///     Date::from_date(date!(2000-01-31))
+    ///         .unwrap()
+    ///         .checked_add_months(1),
+    ///     Some(Date::from_date(date!(2000-02-29)).unwrap())
+    /// );
+    /// assert_eq!(
+    ///     Date::MIN.checked_add_months(1535),
+    ///     Some(Date::from_date(date!(2107-12-01)).unwrap())
+    /// );
+    ///
+    /// assert_eq!(Date::MAX.checked_add_months(1), None);
+    /// assert_eq!(Date::MIN.checked_add_months(-1), None);
+    /// ```
+    #[must_use]
+    pub fn checked_add_months(self, months: i32) -> Option<Self> {
+        let start = i32::from(self.year() - 1980) * 12 + i32::from(self.month0());
+        let months = u16::try_from(start.checked_add(months)?)
+            .ok()
+            .filter(|&m| m < 128 * 12)?;
+        let (year, month) = (1980 + months / 12, months % 12 + 1);
+        let day = u16::from(self.day()).min(Self::days_in_month(year, month));
+        Some(Self::__from_calendar_date(year, month, day))
+    }
+
+    /// Computes `self - months`, returning [`None`] if the result is out of
+    /// range for the MS-DOS date.
+    ///
+    /// `months` may be negative, in which case this moves forward. The day of
+    /// this `Date` is kept, but is clamped to the last day of the month if the
+    /// resulting month is shorter.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use dos_date_time::{Date, time::macros::date};
+    /// #
+    /// assert_eq!(
+    ///     Date::from_date(date!(2000-03-31))
+    ///         .unwrap()
+    ///         .checked_sub_months(1),
+    ///     Some(Date::from_date(date!(2000-02-29)).unwrap())
+    /// );
+    /// assert_eq!(
+    ///     Date::MAX.checked_sub_months(1535),
+    ///     Some(Date::from_date(date!(1980-01-31)).unwrap())
+    /// );
+    ///
+    /// assert_eq!(Date::MIN.checked_sub_months(1), None);
+    /// assert_eq!(Date::MAX.checked_sub_months(-1), None);
+    /// ```
+    #[must_use]
+    pub fn checked_sub_months(self, months: i32) -> Option<Self> {
+        self.checked_add_months(months.checked_neg()?)
+    }
+
+    /// Returns the number of whole months from `other` to `self`, ignoring the
+    /// day of the month.
+    ///
+    /// The returned value is negative if `self` is before `other`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use dos_date_time::{Date, time::macros::date};
+    /// #
+    /// let (a, b) = (
+    ///     Date::from_date(date!(2019-01-01)).unwrap(),
+    ///     Date::from_date(date!(2018-11-30)).unwrap(),
+    /// );
+    /// assert_eq!(a.months_since(b), 2);
+    /// assert_eq!(b.months_since(a), -2);
+    ///
+    /// assert_eq!(Date::MAX.months_since(Date::MIN), 1535);
+    /// ```
+    #[must_use]
+    pub const fn months_since(self, other: Self) -> i32 {
+        let years = self.year() as i32 - other.year() as i32;
+        let months = self.month0() as i32 - other.month0() as i32;
+        (years * 12) + months
+    }
+
+    /// Returns an iterator over at most `count` dates, starting at this `Date`
+    /// and advancing by one month each step.
+    ///
+    /// Each date keeps the day of this `Date`, clamped to the last day of the
+    /// month if the month is shorter. The iterator ends early if the next date
+    /// is out of range for the MS-DOS date.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use dos_date_time::{Date, time::macros::date};
+    /// #
+    /// let mut dates = Date::from_date(date!(2000-01-31)).unwrap().iter_months(3);
+    /// assert_eq!(
+    ///     dates.next(),
+    ///     Some(Date::from_date(date!(2000-01-31)).unwrap())
+    /// );
+    /// assert_eq!(
+    ///     dates.next(),
+    ///     Some(Date::from_date(date!(2000-02-29)).unwrap())
+    /// );
+    /// assert_eq!(
+    ///     dates.next(),
+    ///     Some(Date::from_date(date!(2000-03-31)).unwrap())
+    /// );
+    /// assert_eq!(dates.next(), None);
+    ///
+    /// assert_eq!(Date::MAX.iter_months(2).count(), 1);
+    /// ```
+    pub fn iter_months(self, count: u32) -> impl Iterator<Item = Self> {
+        (0..count).map_while(move |i| self.checked_add_months(i32::try_from(i).ok()?))
+    }
+
+    /// Returns an iterator over at most `count` dates, starting at this `Date`
+    /// and advancing by one year each step.
+    ///
+    /// Each date keeps the month and the day of this `Date`, except that
+    /// February 29 is clamped to February 28 in a non-leap year. The iterator
+    /// ends early if the next date is out of range for the MS-DOS date.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use dos_date_time::{Date, time::macros::date};
+    /// #
+    /// let mut dates = Date::from_date(date!(2000-02-29)).unwrap().iter_years(2);
+    /// assert_eq!(
+    ///     dates.next(),
+    ///     Some(Date::from_date(date!(2000-02-29)).unwrap())
+    /// );
+    /// assert_eq!(
+    ///     dates.next(),
+    ///     Some(Date::from_date(date!(2001-02-28)).unwrap())
+    /// );
+    /// assert_eq!(dates.next(), None);
+    ///
+    /// assert_eq!(Date::MAX.iter_years(2).count(), 1);
+    /// ```
+    pub fn iter_years(self, count: u32) -> impl Iterator<Item = Self> {
+        let (start, month) = (self.year(), u16::from(u8::from(self.month())));
+        let day = u16::from(self.day());
+        (0..count).map_while(move |i| {
+            let year = u16::try_from(i)
+                .ok()
+                .and_then(|i| start.checked_add(i))
+                .filter(|&y| y <= 2107)?;
+            let day = day.min(Self::days_in_month(year, month));
+            Some(Self::__from_calendar_date(year, month, day))
+        })
+    }
+
+    /// Returns the number of days in the given month of the given year.
+    ///
+    /// `month` is in the range `1..=12`. Unlike [`time::util::days_in_month`],
+    /// this is callable in const contexts.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `month` is not in the range `1..=12`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use dos_date_time::Date;
+    /// #
+    /// assert_eq!(Date::days_in_month_of(2000, 2), 29);
+    /// assert_eq!(Date::days_in_month_of(2001, 2), 28);
+    /// assert_eq!(Date::days_in_month_of(2100, 2), 28);
+    /// assert_eq!(Date::days_in_month_of(2107, 12), 31);
+    /// ```
+    #[must_use]
+    pub const fn days_in_month_of(year: u16, month: u8) -> u8 {
+        assert!(1 <= month && month <= 12, "month should be in `1..=12`");
+        let is_leap_year =
+            year.is_multiple_of(4) && (!year.is_multiple_of(100) || year.is_multiple_of(400));
+        if month == 2 && is_leap_year {
+            29
+        } else {
+            Self::DAYS_IN_MONTH[(month - 1) as usize]
+        }
+    }
+
+    /// Returns the number of days in the given month of the given year.
+    #[allow(clippy::cast_possible_truncation)]
+    pub(crate) const fn days_in_month(year: u16, month: u16) -> u16 {
+        // The Month field is 4 bits, so this never truncates.
+        Self::days_in_month_of(year, month as u8) as u16
+    }
+}
+
+impl Default for Date {
+    /// Returns the default value of "1980-01-01".
+    ///
+    /// Equivalent to [`Date::MIN`] except that it is not callable in const
+    /// contexts.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use dos_date_time::Date;
+    /// #
+    /// assert_eq!(Date::default(), Date::MIN);
+    /// ```
+    fn default() -> Self {
+        Self::MIN
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use core::mem;
+    #[cfg(feature = "std")]
+    use std::{
+        collections::hash_map::DefaultHasher,
+        hash::{Hash, Hasher},
+    };
+
+    use time::macros::date;
+
+    use super::*;
+
+    #[test]
+    fn size_of() {
+        assert_eq!(mem::size_of::<Date>(), mem::size_of::<u16>());
+    }
+
+    #[test]
     fn align_of() {
         assert_eq!(mem::align_of::<Date>(), mem::align_of::<u16>());
     }
 
     #[test]
-    fn clone() {
-        assert_eq!(Date::MIN.clone(), Date::MIN);
+    fn clone() {
+        assert_eq!(Date::MIN.clone(), Date::MIN);
+    }
+
+    #[test]
+    fn copy() {
+        let a = Date::MIN;
+        let b = a;
+        assert_eq!(a, b);
+    }
+
+    #[cfg(feature = "std")]
+    #[test]
+    fn hash() {
+        assert_ne!(
+            {
+                let mut hasher = DefaultHasher::new();
+                Date::MIN.hash(&mut hasher);
+                hasher.finish()
+            },
+            {
+                let mut hasher = DefaultHasher::new();
+                Date::MAX.hash(&mut hasher);
+                hasher.finish()
+            }
+        );
+    }
+
+    #[test]
+    fn new() {
+        assert_eq!(Date::new(0b0000_0000_0010_0001).unwrap(), Date::MIN);
+        assert_eq!(Date::new(0b1111_1111_1001_1111).unwrap(), Date::MAX);
+    }
+
+    #[test]
+    fn new_with_invalid_date() {
+        // The Day field is 0.
+        assert!(Date::new(0b0000_0000_0010_0000).is_none());
+        // The Day field is 30, which is after the last day of February.
+        assert!(Date::new(0b0000_0000_0101_1110).is_none());
+        // The Month field is 0.
+        assert!(Date::new(0b0000_0000_0000_0001).is_none());
+        // The Month field is 13.
+        assert!(Date::new(0b0000_0001_1010_0001).is_none());
+    }
+
+    #[test]
+    fn new_agrees_with_time_based_validation() {
+        fn is_valid_with_time(date: u16) -> bool {
+            let (year, month, day) = (
+                (1980 + (date >> 9)).into(),
+                u8::try_from((date >> 5) & 0x0F).unwrap(),
+                u8::try_from(date & 0x1F).unwrap(),
+            );
+            Month::try_from(month)
+                .is_ok_and(|month| time::Date::from_calendar_date(year, month, day).is_ok())
+        }
+
+        for date in u16::MIN..=u16::MAX {
+            assert_eq!(Date::new(date).is_some(), is_valid_with_time(date));
+        }
+    }
+
+    #[test]
+    fn checked_from_raw() {
+        assert_eq!(
+            Date::checked_from_raw(0b0000_0000_0010_0001).unwrap(),
+            Date::MIN
+        );
+        // <https://devblogs.microsoft.com/oldnewthing/20030905-02/?p=42653>.
+        assert_eq!(
+            Date::checked_from_raw(0b0010_1101_0111_1010).unwrap(),
+            Date::new(0b0010_1101_0111_1010).unwrap()
+        );
+        // February 29 in 1980, which is a leap year.
+        assert_eq!(
+            Date::checked_from_raw(0b0000_0000_0101_1101).unwrap(),
+            Date::from_date(date!(1980-02-29)).unwrap()
+        );
+        assert_eq!(
+            Date::checked_from_raw(0b1111_1111_1001_1111).unwrap(),
+            Date::MAX
+        );
+    }
+
+    #[test]
+    fn checked_from_raw_with_invalid_date() {
+        // The Month field is 0.
+        assert_eq!(
+            Date::checked_from_raw(0b0000_0000_0000_0001).unwrap_err(),
+            InvalidDateField::MonthZero
+        );
+        // The Month field is 13.
+        assert_eq!(
+            Date::checked_from_raw(0b0000_0001_1010_0001).unwrap_err(),
+            InvalidDateField::MonthTooLarge(13)
+        );
+        // The Month field is 15.
+        assert_eq!(
+            Date::checked_from_raw(0b0000_0001_1110_0001).unwrap_err(),
+            InvalidDateField::MonthTooLarge(15)
+        );
+        // The Day field is 0.
+        assert_eq!(
+            Date::checked_from_raw(0b0000_0000_0010_0000).unwrap_err(),
+            InvalidDateField::DayZero
+        );
+        // The Day field is 30, which is after the last day of February in 1980.
+        assert_eq!(
+            Date::checked_from_raw(0b0000_0000_0101_1110).unwrap_err(),
+            InvalidDateField::DayTooLarge { day: 30, max: 29 }
+        );
+        // The Day field is 30, which is after the last day of February in 1981.
+        assert_eq!(
+            Date::checked_from_raw(0b0000_0010_0101_1110).unwrap_err(),
+            InvalidDateField::DayTooLarge { day: 30, max: 28 }
+        );
+        // The Day field is 29, which is after the last day of February in 2100.
+        assert_eq!(
+            Date::checked_from_raw(0b1111_0000_0101_1101).unwrap_err(),
+            InvalidDateField::DayTooLarge { day: 29, max: 28 }
+        );
+        // The Day field is 31, which is after the last day of April.
+        assert_eq!(
+            Date::checked_from_raw(0b0000_0000_1001_1111).unwrap_err(),
+            InvalidDateField::DayTooLarge { day: 31, max: 30 }
+        );
+    }
+
+    #[test]
+    fn checked_new() {
+        assert_eq!(Date::checked_new(0b0000_0000_0010_0001).unwrap(), Date::MIN);
+        // <https://devblogs.microsoft.com/oldnewthing/20030905-02/?p=42653>.
+        assert_eq!(
+            Date::checked_new(0b0010_1101_0111_1010).unwrap(),
+            Date::new(0b0010_1101_0111_1010).unwrap()
+        );
+        assert_eq!(Date::checked_new(0b1111_1111_1001_1111).unwrap(), Date::MAX);
+    }
+
+    #[test]
+    fn checked_new_with_month_zero() {
+        assert_eq!(
+            Date::checked_new(0b0000_0000_0000_0001).unwrap_err(),
+            InvalidDateField::MonthZero
+        );
+    }
+
+    #[test]
+    fn checked_new_with_month_too_large() {
+        assert_eq!(
+            Date::checked_new(0b0000_0001_1010_0001).unwrap_err(),
+            InvalidDateField::MonthTooLarge(13)
+        );
+    }
+
+    #[test]
+    fn checked_new_with_day_zero() {
+        assert_eq!(
+            Date::checked_new(0b0000_0000_0010_0000).unwrap_err(),
+            InvalidDateField::DayZero
+        );
+    }
+
+    #[test]
+    fn checked_new_with_day_too_large() {
+        assert_eq!(
+            Date::checked_new(0b0000_0010_0101_1110).unwrap_err(),
+            InvalidDateField::DayTooLarge { day: 30, max: 28 }
+        );
+    }
+
+    #[test]
+    fn from_i64() {
+        assert_eq!(Date::from_i64(0b0000_0000_0010_0001).unwrap(), Date::MIN);
+        // <https://github.com/zip-rs/zip/blob/v0.6.4/src/types.rs#L553-L569>.
+        assert_eq!(
+            Date::from_i64(0b0100_1101_0111_0001).unwrap(),
+            Date::new(0b0100_1101_0111_0001).unwrap()
+        );
+        assert_eq!(Date::from_i64(0b1111_1111_1001_1111).unwrap(), Date::MAX);
+    }
+
+    #[test]
+    fn from_i64_with_out_of_range() {
+        assert!(Date::from_i64(-1).is_none());
+        assert!(Date::from_i64(i64::from(u16::MAX) + 1).is_none());
+        assert!(Date::from_i64(i64::MIN).is_none());
+        assert!(Date::from_i64(i64::MAX).is_none());
+    }
+
+    #[test]
+    fn from_i64_with_invalid_date() {
+        // The Day field is 0.
+        assert!(Date::from_i64(0b0000_0000_0010_0000).is_none());
+    }
+
+    #[test]
+    fn from_i64_agrees_with_new() {
+        for raw in u16::MIN..=u16::MAX {
+            assert_eq!(Date::from_i64(raw.into()), Date::new(raw));
+        }
+    }
+
+    #[test]
+    fn new_unchecked() {
+        assert_eq!(
+            unsafe { Date::new_unchecked(0b0000_0000_0010_0001) },
+            Date::MIN
+        );
+        assert_eq!(
+            unsafe { Date::new_unchecked(0b1111_1111_1001_1111) },
+            Date::MAX
+        );
+    }
+
+    #[test]
+    const fn new_unchecked_is_const_fn() {
+        const _: Date = unsafe { Date::new_unchecked(0b0000_0000_0010_0001) };
+    }
+
+    #[test]
+    fn wrapping_add_raw() {
+        assert_eq!(unsafe { Date::MIN.wrapping_add_raw(0) }, Date::MIN);
+        assert_eq!(
+            unsafe { Date::MIN.wrapping_add_raw(1) }.to_raw(),
+            0b0000_0000_0010_0010
+        );
+        assert_eq!(
+            unsafe { Date::MAX.wrapping_add_raw(u16::MAX) }.to_raw(),
+            0b1111_1111_1001_1111 - 1
+        );
+        assert_eq!(
+            unsafe { Date::MAX.wrapping_add_raw(0x61) }.to_raw(),
+            u16::MIN
+        );
+    }
+
+    #[test]
+    fn wrapping_add_raw_with_invalid_date() {
+        // The Day field is 0.
+        let date = unsafe { Date::MIN.wrapping_add_raw(u16::MAX) };
+        assert!(!date.is_valid());
+        assert_eq!(date.to_raw(), 0b0000_0000_0010_0000);
+        // The Month field is 0.
+        assert!(!unsafe { Date::MAX.wrapping_add_raw(0x61) }.is_valid());
+    }
+
+    #[test]
+    const fn wrapping_add_raw_is_const_fn() {
+        const _: Date = unsafe { Date::MIN.wrapping_add_raw(1) };
+    }
+
+    #[test]
+    fn from_date_before_dos_date_epoch() {
+        assert_eq!(
+            Date::from_date(date!(1979-12-31)).unwrap_err(),
+            DateRangeErrorKind::Negative.into()
+        );
+        assert_eq!(
+            Date::from_date(date!(1979-12-31)).unwrap_err(),
+            DateRangeErrorKind::Negative.into()
+        );
+    }
+
+    #[test]
+    fn from_date() {
+        assert_eq!(Date::from_date(date!(1980-01-01)).unwrap(), Date::MIN);
+        assert_eq!(Date::from_date(date!(1980-01-01)).unwrap(), Date::MIN);
+        // <https://devblogs.microsoft.com/oldnewthing/20030905-02/?p=42653>.
+        assert_eq!(
+            Date::from_date(date!(2002-11-26)).unwrap(),
+            Date::new(0b0010_1101_0111_1010).unwrap()
+        );
+        // <https://github.com/zip-rs/zip/blob/v0.6.4/src/types.rs#L553-L569>.
+        assert_eq!(
+            Date::from_date(date!(2018-11-17)).unwrap(),
+            Date::new(0b0100_1101_0111_0001).unwrap()
+        );
+        assert_eq!(Date::from_date(date!(2107-12-31)).unwrap(), Date::MAX);
+        assert_eq!(Date::from_date(date!(2107-12-31)).unwrap(), Date::MAX);
+    }
+
+    #[test]
+    fn from_date_with_too_big_date() {
+        assert_eq!(
+            Date::from_date(date!(2108-01-01)).unwrap_err(),
+            DateRangeErrorKind::Overflow.into()
+        );
+    }
+
+    #[test]
+    fn from_year_month_day_before_dos_date_epoch() {
+        assert_eq!(
+            Date::from_year_month_day(1979, Month::December, 31).unwrap_err(),
+            DateErrorKind::Range(DateRangeErrorKind::Negative).into()
+        );
+        assert_eq!(
+            Date::from_year_month_day(u16::MIN, Month::January, 1).unwrap_err(),
+            DateErrorKind::Range(DateRangeErrorKind::Negative).into()
+        );
+    }
+
+    #[test]
+    fn from_year_month_day() {
+        assert_eq!(
+            Date::from_year_month_day(1980, Month::January, 1).unwrap(),
+            Date::MIN
+        );
+        // <https://devblogs.microsoft.com/oldnewthing/20030905-02/?p=42653>.
+        assert_eq!(
+            Date::from_year_month_day(2002, Month::November, 26).unwrap(),
+            Date::new(0b0010_1101_0111_1010).unwrap()
+        );
+        // <https://github.com/zip-rs/zip/blob/v0.6.4/src/types.rs#L553-L569>.
+        assert_eq!(
+            Date::from_year_month_day(2018, Month::November, 17).unwrap(),
+            Date::new(0b0100_1101_0111_0001).unwrap()
+        );
+        assert_eq!(
+            Date::from_year_month_day(2107, Month::December, 31).unwrap(),
+            Date::MAX
+        );
+    }
+
+    #[test]
+    fn from_year_month_day_with_leap_day() {
+        assert_eq!(
+            Date::from_year_month_day(2000, Month::February, 29).unwrap(),
+            Date::from_date(date!(2000-02-29)).unwrap()
+        );
+        assert_eq!(
+            Date::from_year_month_day(2001, Month::February, 29).unwrap_err(),
+            DateErrorKind::InvalidDay.into()
+        );
+        // `2100` is not a leap year.
+        assert_eq!(
+            Date::from_year_month_day(2100, Month::February, 29).unwrap_err(),
+            DateErrorKind::InvalidDay.into()
+        );
+    }
+
+    #[test]
+    fn from_year_month_day_with_invalid_day() {
+        assert_eq!(
+            Date::from_year_month_day(1980, Month::January, 0).unwrap_err(),
+            DateErrorKind::InvalidDay.into()
+        );
+        assert_eq!(
+            Date::from_year_month_day(1980, Month::April, 31).unwrap_err(),
+            DateErrorKind::InvalidDay.into()
+        );
+        assert_eq!(
+            Date::from_year_month_day(1980, Month::December, 32).unwrap_err(),
+            DateErrorKind::InvalidDay.into()
+        );
+    }
+
+    #[test]
+    fn from_year_month_day_with_too_big_date() {
+        assert_eq!(
+            Date::from_year_month_day(2108, Month::January, 1).unwrap_err(),
+            DateErrorKind::Range(DateRangeErrorKind::Overflow).into()
+        );
+        assert_eq!(
+            Date::from_year_month_day(u16::MAX, Month::December, 31).unwrap_err(),
+            DateErrorKind::Range(DateRangeErrorKind::Overflow).into()
+        );
+    }
+
+    #[test]
+    fn from_year_month_day_roundtrip() {
+        for date in Date::all_valid() {
+            assert_eq!(
+                Date::from_year_month_day(date.year(), date.month(), date.day()).unwrap(),
+                date
+            );
+        }
+    }
+
+    #[test]
+    fn from_ymd_i32() {
+        assert_eq!(Date::from_ymd_i32(1980, 1, 1).unwrap(), Date::MIN);
+        // <https://github.com/zip-rs/zip/blob/v0.6.4/src/types.rs#L553-L569>.
+        assert_eq!(
+            Date::from_ymd_i32(2018, 11, 17).unwrap(),
+            Date::new(0b0100_1101_0111_0001).unwrap()
+        );
+        assert_eq!(
+            Date::from_ymd_i32(2000, 2, 29).unwrap(),
+            Date::from_date(date!(2000-02-29)).unwrap()
+        );
+        assert_eq!(Date::from_ymd_i32(2107, 12, 31).unwrap(), Date::MAX);
+    }
+
+    #[test]
+    fn from_ymd_i32_with_out_of_range_year() {
+        assert_eq!(
+            Date::from_ymd_i32(1979, 12, 31).unwrap_err(),
+            DateErrorKind::Range(DateRangeErrorKind::Negative).into()
+        );
+        assert_eq!(
+            Date::from_ymd_i32(i32::MIN, 1, 1).unwrap_err(),
+            DateErrorKind::Range(DateRangeErrorKind::Negative).into()
+        );
+        assert_eq!(
+            Date::from_ymd_i32(2108, 1, 1).unwrap_err(),
+            DateErrorKind::Range(DateRangeErrorKind::Overflow).into()
+        );
+        assert_eq!(
+            Date::from_ymd_i32(i32::MAX, 12, 31).unwrap_err(),
+            DateErrorKind::Range(DateRangeErrorKind::Overflow).into()
+        );
+    }
+
+    #[test]
+    fn from_ymd_i32_with_invalid_month() {
+        assert_eq!(
+            Date::from_ymd_i32(2018, 0, 17).unwrap_err(),
+            DateErrorKind::InvalidMonth.into()
+        );
+        assert_eq!(
+            Date::from_ymd_i32(2018, 13, 17).unwrap_err(),
+            DateErrorKind::InvalidMonth.into()
+        );
+        assert_eq!(
+            Date::from_ymd_i32(2018, u32::MAX, 17).unwrap_err(),
+            DateErrorKind::InvalidMonth.into()
+        );
+    }
+
+    #[test]
+    fn from_ymd_i32_with_invalid_day() {
+        assert_eq!(
+            Date::from_ymd_i32(2018, 11, 0).unwrap_err(),
+            DateErrorKind::InvalidDay.into()
+        );
+        assert_eq!(
+            Date::from_ymd_i32(2001, 2, 29).unwrap_err(),
+            DateErrorKind::InvalidDay.into()
+        );
+        assert_eq!(
+            Date::from_ymd_i32(2018, 11, u32::MAX).unwrap_err(),
+            DateErrorKind::InvalidDay.into()
+        );
+    }
+
+    #[cfg(feature = "chrono")]
+    #[test]
+    fn from_ymd_i32_agrees_with_chrono() {
+        use chrono::NaiveDate;
+
+        for (year, month, day) in [
+            (1979, 12, 31),
+            (1980, 1, 1),
+            (2000, 2, 29),
+            (2001, 2, 29),
+            (2018, 11, 17),
+            (2107, 12, 31),
+            (2108, 1, 1),
+        ] {
+            assert_eq!(
+                Date::from_ymd_i32(year, month, day).ok(),
+                NaiveDate::from_ymd_opt(year, month, day)
+                    .and_then(|date| Date::try_from(date).ok())
+            );
+        }
+    }
+
+    #[test]
+    fn from_ordinal_date_before_dos_date_epoch() {
+        assert_eq!(
+            Date::from_ordinal_date(1979, 365).unwrap_err(),
+            DateErrorKind::Range(DateRangeErrorKind::Negative).into()
+        );
+    }
+
+    #[test]
+    fn from_ordinal_date() {
+        assert_eq!(Date::from_ordinal_date(1980, 1).unwrap(), Date::MIN);
+        // <https://devblogs.microsoft.com/oldnewthing/20030905-02/?p=42653>.
+        assert_eq!(
+            Date::from_ordinal_date(2002, 330).unwrap(),
+            Date::new(0b0010_1101_0111_1010).unwrap()
+        );
+        // <https://github.com/zip-rs/zip/blob/v0.6.4/src/types.rs#L553-L569>.
+        assert_eq!(
+            Date::from_ordinal_date(2018, 321).unwrap(),
+            Date::new(0b0100_1101_0111_0001).unwrap()
+        );
+        assert_eq!(
+            Date::from_ordinal_date(1980, 366).unwrap(),
+            Date::from_date(date!(1980-12-31)).unwrap()
+        );
+        assert_eq!(
+            Date::from_ordinal_date(1981, 365).unwrap(),
+            Date::from_date(date!(1981-12-31)).unwrap()
+        );
+        assert_eq!(Date::from_ordinal_date(2107, 365).unwrap(), Date::MAX);
+    }
+
+    #[test]
+    fn from_ordinal_date_with_invalid_ordinal() {
+        assert_eq!(
+            Date::from_ordinal_date(1980, 0).unwrap_err(),
+            DateErrorKind::InvalidOrdinal.into()
+        );
+        assert_eq!(
+            Date::from_ordinal_date(1980, 367).unwrap_err(),
+            DateErrorKind::InvalidOrdinal.into()
+        );
+        assert_eq!(
+            Date::from_ordinal_date(1981, 366).unwrap_err(),
+            DateErrorKind::InvalidOrdinal.into()
+        );
+        // `2100` is not a leap year.
+        assert_eq!(
+            Date::from_ordinal_date(2100, 366).unwrap_err(),
+            DateErrorKind::InvalidOrdinal.into()
+        );
+    }
+
+    #[test]
+    fn from_ordinal_date_with_too_big_date() {
+        assert_eq!(
+            Date::from_ordinal_date(2108, 1).unwrap_err(),
+            DateErrorKind::Range(DateRangeErrorKind::Overflow).into()
+        );
+    }
+
+    #[test]
+    fn from_ordinal_date_roundtrip() {
+        for date in Date::all_valid() {
+            assert_eq!(
+                Date::from_ordinal_date(date.year(), date.ordinal()).unwrap(),
+                date
+            );
+        }
+    }
+
+    #[test]
+    fn from_julian_day_before_dos_date_epoch() {
+        assert_eq!(
+            Date::from_julian_day(2_444_239).unwrap_err(),
+            DateRangeErrorKind::Negative.into()
+        );
+        assert_eq!(
+            Date::from_julian_day(i64::from(i32::MIN)).unwrap_err(),
+            DateRangeErrorKind::Negative.into()
+        );
+        assert_eq!(
+            Date::from_julian_day(i64::MIN).unwrap_err(),
+            DateRangeErrorKind::Negative.into()
+        );
+    }
+
+    #[test]
+    fn from_julian_day() {
+        assert_eq!(Date::from_julian_day(2_444_240).unwrap(), Date::MIN);
+        assert_eq!(
+            Date::from_julian_day(2_451_545).unwrap(),
+            Date::from_date(date!(2000-01-01)).unwrap()
+        );
+        // <https://devblogs.microsoft.com/oldnewthing/20030905-02/?p=42653>.
+        assert_eq!(
+            Date::from_julian_day(2_452_605).unwrap(),
+            Date::new(0b0010_1101_0111_1010).unwrap()
+        );
+        // <https://github.com/zip-rs/zip/blob/v0.6.4/src/types.rs#L553-L569>.
+        assert_eq!(
+            Date::from_julian_day(2_458_440).unwrap(),
+            Date::new(0b0100_1101_0111_0001).unwrap()
+        );
+        assert_eq!(Date::from_julian_day(2_490_990).unwrap(), Date::MAX);
+    }
+
+    #[test]
+    fn from_julian_day_with_too_big_date() {
+        assert_eq!(
+            Date::from_julian_day(2_490_991).unwrap_err(),
+            DateRangeErrorKind::Overflow.into()
+        );
+        assert_eq!(
+            Date::from_julian_day(i64::from(i32::MAX)).unwrap_err(),
+            DateRangeErrorKind::Overflow.into()
+        );
+        assert_eq!(
+            Date::from_julian_day(i64::MAX).unwrap_err(),
+            DateRangeErrorKind::Overflow.into()
+        );
+    }
+
+    #[test]
+    fn from_days_since_epoch() {
+        assert_eq!(Date::from_days_since_epoch(0).unwrap(), Date::MIN);
+        // <https://devblogs.microsoft.com/oldnewthing/20030905-02/?p=42653>.
+        assert_eq!(
+            Date::from_days_since_epoch(8365).unwrap(),
+            Date::new(0b0010_1101_0111_1010).unwrap()
+        );
+        // <https://github.com/zip-rs/zip/blob/v0.6.4/src/types.rs#L553-L569>.
+        assert_eq!(
+            Date::from_days_since_epoch(14200).unwrap(),
+            Date::new(0b0100_1101_0111_0001).unwrap()
+        );
+        assert_eq!(Date::from_days_since_epoch(46750).unwrap(), Date::MAX);
+    }
+
+    #[test]
+    fn from_days_since_epoch_with_too_big_date() {
+        assert_eq!(
+            Date::from_days_since_epoch(46751).unwrap_err(),
+            DateRangeErrorKind::Overflow.into()
+        );
+        assert_eq!(
+            Date::from_days_since_epoch(u16::MAX).unwrap_err(),
+            DateRangeErrorKind::Overflow.into()
+        );
+    }
+
+    #[test]
+    fn all_valid() {
+        assert_eq!(Date::all_valid().count(), 46751);
+        assert_eq!(Date::all_valid().next().unwrap(), Date::MIN);
+        assert_eq!(Date::all_valid().last().unwrap(), Date::MAX);
+        assert!(Date::all_valid().all(Date::is_valid));
+    }
+
+    #[test]
+    fn all_valid_is_strictly_increasing() {
+        assert!(
+            Date::all_valid()
+                .zip(Date::all_valid().skip(1))
+                .all(|(a, b)| a < b)
+        );
+        // Tests that no valid date is skipped.
+        assert!(
+            Date::all_valid()
+                .zip(Date::all_valid().skip(1))
+                .all(|(a, b)| time::Date::from(a).next_day() == Some(b.into()))
+        );
+    }
+
+    #[test]
+    fn is_valid() {
+        assert!(Date::MIN.is_valid());
+        // <https://devblogs.microsoft.com/oldnewthing/20030905-02/?p=42653>.
+        assert!(Date::new(0b0010_1101_0111_1010).unwrap().is_valid());
+        // <https://github.com/zip-rs/zip/blob/v0.6.4/src/types.rs#L553-L569>.
+        assert!(Date::new(0b0100_1101_0111_0001).unwrap().is_valid());
+        assert!(Date::MAX.is_valid());
+    }
+
+    #[test]
+    fn is_valid_with_invalid_date() {
+        // The Day field is 0.
+        assert!(!unsafe { Date::new_unchecked(0b0000_0000_0010_0000) }.is_valid());
+        // The Day field is 30, which is after the last day of February.
+        assert!(!unsafe { Date::new_unchecked(0b0000_0000_0101_1110) }.is_valid());
+        // The Month field is 0.
+        assert!(!unsafe { Date::new_unchecked(0b0000_0000_0000_0001) }.is_valid());
+        // The Month field is 13.
+        assert!(!unsafe { Date::new_unchecked(0b0000_0001_1010_0001) }.is_valid());
+    }
+
+    #[test]
+    fn in_range() {
+        let start = Date::from_date(date!(2000-01-01)).unwrap();
+        let end = Date::from_date(date!(2000-12-31)).unwrap();
+        let before_start = Date::from_date(date!(1999-12-31)).unwrap();
+        let after_end = Date::from_date(date!(2001-01-01)).unwrap();
+
+        assert!(!before_start.in_range(start..=end));
+        assert!(start.in_range(start..=end));
+        assert!(end.in_range(start..=end));
+        assert!(!after_end.in_range(start..=end));
+
+        assert!(start.in_range(start..end));
+        assert!(!end.in_range(start..end));
+
+        assert!(!before_start.in_range(start..));
+        assert!(start.in_range(start..));
+        assert!(Date::MAX.in_range(start..));
+
+        assert!(Date::MIN.in_range(..end));
+        assert!(!end.in_range(..end));
+        assert!(end.in_range(..=end));
+        assert!(!after_end.in_range(..=end));
+
+        assert!(Date::MIN.in_range(..));
+        assert!(Date::MAX.in_range(..));
+    }
+
+    #[test]
+    fn clamp() {
+        let (min, max) = (
+            Date::from_date(date!(2002-11-26)).unwrap(),
+            Date::from_date(date!(2107-01-01)).unwrap(),
+        );
+        assert_eq!(Date::MIN.clamp(min, max), min);
+        assert_eq!(min.clamp(min, max), min);
+        assert_eq!(
+            Date::from_date(date!(2018-11-17)).unwrap().clamp(min, max),
+            Date::from_date(date!(2018-11-17)).unwrap()
+        );
+        assert_eq!(max.clamp(min, max), max);
+        assert_eq!(Date::MAX.clamp(min, max), max);
+        assert_eq!(Date::MIN.clamp(Date::MIN, Date::MAX), Date::MIN);
+        assert_eq!(Date::MAX.clamp(Date::MIN, Date::MAX), Date::MAX);
+    }
+
+    #[test]
+    #[should_panic(expected = "`min` should be less than or equal to `max`")]
+    fn clamp_with_min_greater_than_max() {
+        let _ = Date::MIN.clamp(Date::MAX, Date::MIN);
+    }
+
+    #[test]
+    fn to_raw() {
+        assert_eq!(Date::MIN.to_raw(), 0b0000_0000_0010_0001);
+        // <https://devblogs.microsoft.com/oldnewthing/20030905-02/?p=42653>.
+        assert_eq!(
+            Date::new(0b0010_1101_0111_1010).unwrap().to_raw(),
+            0b0010_1101_0111_1010
+        );
+        // <https://github.com/zip-rs/zip/blob/v0.6.4/src/types.rs#L553-L569>.
+        assert_eq!(
+            Date::new(0b0100_1101_0111_0001).unwrap().to_raw(),
+            0b0100_1101_0111_0001
+        );
+        assert_eq!(Date::MAX.to_raw(), 0b1111_1111_1001_1111);
+    }
+
+    #[test]
+    const fn to_raw_is_const_fn() {
+        const _: u16 = Date::MIN.to_raw();
+    }
+
+    #[test]
+    fn to_i64() {
+        assert_eq!(Date::MIN.to_i64(), 0b0000_0000_0010_0001);
+        // <https://github.com/zip-rs/zip/blob/v0.6.4/src/types.rs#L553-L569>.
+        assert_eq!(
+            Date::new(0b0100_1101_0111_0001).unwrap().to_i64(),
+            0b0100_1101_0111_0001
+        );
+        assert_eq!(Date::MAX.to_i64(), 0b1111_1111_1001_1111);
+    }
+
+    #[test]
+    fn to_i64_round_trip() {
+        for raw in u16::MIN..=u16::MAX {
+            if let Some(value) = Date::new(raw) {
+                assert_eq!(Date::from_i64(value.to_i64()), Some(value));
+            }
+        }
+    }
+
+    #[test]
+    const fn to_i64_is_const_fn() {
+        const _: i64 = Date::MIN.to_i64();
+    }
+
+    #[test]
+    fn year() {
+        assert_eq!(Date::MIN.year(), 1980);
+        // <https://devblogs.microsoft.com/oldnewthing/20030905-02/?p=42653>.
+        assert_eq!(Date::new(0b0010_1101_0111_1010).unwrap().year(), 2002);
+        // <https://github.com/zip-rs/zip/blob/v0.6.4/src/types.rs#L553-L569>.
+        assert_eq!(Date::new(0b0100_1101_0111_0001).unwrap().year(), 2018);
+        assert_eq!(Date::MAX.year(), 2107);
+    }
+
+    #[test]
+    const fn year_is_const_fn() {
+        const _: u16 = Date::MIN.year();
+    }
+
+    #[test]
+    fn to_julian_day() {
+        assert_eq!(Date::MIN.to_julian_day(), 2_444_240);
+        assert_eq!(
+            Date::from_date(date!(2000-01-01)).unwrap().to_julian_day(),
+            2_451_545
+        );
+        // <https://devblogs.microsoft.com/oldnewthing/20030905-02/?p=42653>.
+        assert_eq!(
+            Date::new(0b0010_1101_0111_1010).unwrap().to_julian_day(),
+            2_452_605
+        );
+        // <https://github.com/zip-rs/zip/blob/v0.6.4/src/types.rs#L553-L569>.
+        assert_eq!(
+            Date::new(0b0100_1101_0111_0001).unwrap().to_julian_day(),
+            2_458_440
+        );
+        assert_eq!(Date::MAX.to_julian_day(), 2_490_990);
+    }
+
+    #[test]
+    fn to_days_since_epoch() {
+        assert_eq!(Date::MIN.to_days_since_epoch(), 0);
+        // <https://devblogs.microsoft.com/oldnewthing/20030905-02/?p=42653>.
+        assert_eq!(
+            Date::new(0b0010_1101_0111_1010)
+                .unwrap()
+                .to_days_since_epoch(),
+            8365
+        );
+        // <https://github.com/zip-rs/zip/blob/v0.6.4/src/types.rs#L553-L569>.
+        assert_eq!(
+            Date::new(0b0100_1101_0111_0001)
+                .unwrap()
+                .to_days_since_epoch(),
+            14200
+        );
+        assert_eq!(Date::MAX.to_days_since_epoch(), 46750);
+    }
+
+    #[test]
+    fn days_since_epoch_roundtrip() {
+        for date in Date::all_valid() {
+            assert_eq!(
+                Date::from_days_since_epoch(date.to_days_since_epoch()),
+                Ok(date)
+            );
+        }
+    }
+
+    #[test]
+    fn month() {
+        assert_eq!(Date::MIN.month(), Month::January);
+        // <https://devblogs.microsoft.com/oldnewthing/20030905-02/?p=42653>.
+        assert_eq!(
+            Date::new(0b0010_1101_0111_1010).unwrap().month(),
+            Month::November
+        );
+        // <https://github.com/zip-rs/zip/blob/v0.6.4/src/types.rs#L553-L569>.
+        assert_eq!(
+            Date::new(0b0100_1101_0111_0001).unwrap().month(),
+            Month::November
+        );
+        assert_eq!(Date::MAX.month(), Month::December);
+    }
+
+    #[test]
+    fn day() {
+        assert_eq!(Date::MIN.day(), 1);
+        // <https://devblogs.microsoft.com/oldnewthing/20030905-02/?p=42653>.
+        assert_eq!(Date::new(0b0010_1101_0111_1010).unwrap().day(), 26);
+        // <https://github.com/zip-rs/zip/blob/v0.6.4/src/types.rs#L553-L569>.
+        assert_eq!(Date::new(0b0100_1101_0111_0001).unwrap().day(), 17);
+        assert_eq!(Date::MAX.day(), 31);
+    }
+
+    #[test]
+    fn month0() {
+        assert_eq!(Date::MIN.month0(), 0);
+        // <https://devblogs.microsoft.com/oldnewthing/20030905-02/?p=42653>.
+        assert_eq!(Date::new(0b0010_1101_0111_1010).unwrap().month0(), 10);
+        // <https://github.com/zip-rs/zip/blob/v0.6.4/src/types.rs#L553-L569>.
+        assert_eq!(Date::new(0b0100_1101_0111_0001).unwrap().month0(), 10);
+        assert_eq!(Date::MAX.month0(), 11);
+    }
+
+    #[test]
+    const fn month0_is_const_fn() {
+        const _: u8 = Date::MIN.month0();
+    }
+
+    #[test]
+    fn month0_agrees_with_month() {
+        for date in Date::all_valid() {
+            assert_eq!(date.month0() + 1, u8::from(date.month()));
+        }
+    }
+
+    #[test]
+    fn day0() {
+        assert_eq!(Date::MIN.day0(), 0);
+        // <https://devblogs.microsoft.com/oldnewthing/20030905-02/?p=42653>.
+        assert_eq!(Date::new(0b0010_1101_0111_1010).unwrap().day0(), 25);
+        // <https://github.com/zip-rs/zip/blob/v0.6.4/src/types.rs#L553-L569>.
+        assert_eq!(Date::new(0b0100_1101_0111_0001).unwrap().day0(), 16);
+        assert_eq!(Date::MAX.day0(), 30);
+    }
+
+    #[test]
+    const fn day0_is_const_fn() {
+        const _: u8 = Date::MIN.day0();
+    }
+
+    #[test]
+    fn day0_agrees_with_day() {
+        for date in Date::all_valid() {
+            assert_eq!(date.day0() + 1, date.day());
+        }
+    }
+
+    #[test]
+    fn ordinal() {
+        assert_eq!(Date::MIN.ordinal(), 1);
+        // <https://devblogs.microsoft.com/oldnewthing/20030905-02/?p=42653>.
+        assert_eq!(Date::new(0b0010_1101_0111_1010).unwrap().ordinal(), 330);
+        // <https://github.com/zip-rs/zip/blob/v0.6.4/src/types.rs#L553-L569>.
+        assert_eq!(Date::new(0b0100_1101_0111_0001).unwrap().ordinal(), 321);
+        assert_eq!(Date::from_date(date!(1980-12-31)).unwrap().ordinal(), 366);
+        assert_eq!(Date::MAX.ordinal(), 365);
+    }
+
+    #[test]
+    fn weekday() {
+        assert_eq!(Date::MIN.weekday(), Weekday::Tuesday);
+        // <https://devblogs.microsoft.com/oldnewthing/20030905-02/?p=42653>.
+        assert_eq!(
+            Date::from_date(date!(2002-11-26)).unwrap().weekday(),
+            Weekday::Tuesday
+        );
+        // <https://github.com/zip-rs/zip/blob/v0.6.4/src/types.rs#L553-L569>.
+        assert_eq!(
+            Date::from_date(date!(2018-11-17)).unwrap().weekday(),
+            Weekday::Saturday
+        );
+        assert_eq!(Date::MAX.weekday(), Weekday::Saturday);
+    }
+
+    #[test]
+    fn weekday_agrees_with_time() {
+        for date in Date::all_valid() {
+            assert_eq!(date.weekday(), time::Date::from(date).weekday());
+        }
+    }
+
+    #[test]
+    fn weekday_ordinal() {
+        assert_eq!(Date::MIN.weekday_ordinal(), 1);
+        assert_eq!(
+            Date::from_date(date!(1980-01-07))
+                .unwrap()
+                .weekday_ordinal(),
+            1
+        );
+        assert_eq!(
+            Date::from_date(date!(1980-01-08))
+                .unwrap()
+                .weekday_ordinal(),
+            2
+        );
+        // <https://devblogs.microsoft.com/oldnewthing/20030905-02/?p=42653>.
+        assert_eq!(
+            Date::from_date(date!(2002-11-26))
+                .unwrap()
+                .weekday_ordinal(),
+            4
+        );
+        // <https://github.com/zip-rs/zip/blob/v0.6.4/src/types.rs#L553-L569>.
+        assert_eq!(
+            Date::from_date(date!(2018-11-17))
+                .unwrap()
+                .weekday_ordinal(),
+            3
+        );
+        assert_eq!(
+            Date::from_date(date!(2000-02-29))
+                .unwrap()
+                .weekday_ordinal(),
+            5
+        );
+        assert_eq!(Date::MAX.weekday_ordinal(), 5);
+    }
+
+    #[test]
+    fn weekday_ordinal_counts_same_weekday() {
+        for date in Date::all_valid() {
+            let count = (1..=date.day())
+                .filter(|&day| {
+                    Date::from_year_month_day(date.year(), date.month(), day)
+                        .unwrap()
+                        .weekday()
+                        == date.weekday()
+                })
+                .count();
+            assert_eq!(usize::from(date.weekday_ordinal()), count);
+        }
     }
 
     #[test]
-    fn copy() {
-        let a = Date::MIN;
-        let b = a;
-        assert_eq!(a, b);
+    const fn weekday_ordinal_is_const_fn() {
+        const _: u8 = Date::MIN.weekday_ordinal();
     }
 
-    #[cfg(feature = "std")]
     #[test]
-    fn hash() {
-        assert_ne!(
-            {
-                let mut hasher = DefaultHasher::new();
-                Date::MIN.hash(&mut hasher);
-                hasher.finish()
-            },
-            {
-                let mut hasher = DefaultHasher::new();
-                Date::MAX.hash(&mut hasher);
-                hasher.finish()
-            }
-        );
+    fn is_weekend() {
+        assert!(!Date::MIN.is_weekend());
+        // <https://devblogs.microsoft.com/oldnewthing/20030905-02/?p=42653>.
+        assert!(!Date::from_date(date!(2002-11-26)).unwrap().is_weekend());
+        // <https://github.com/zip-rs/zip/blob/v0.6.4/src/types.rs#L553-L569>.
+        assert!(Date::from_date(date!(2018-11-17)).unwrap().is_weekend());
+        assert!(Date::from_date(date!(2018-11-18)).unwrap().is_weekend());
+        assert!(!Date::from_date(date!(2018-11-19)).unwrap().is_weekend());
+        assert!(!Date::from_date(date!(2018-11-21)).unwrap().is_weekend());
+        assert!(Date::MAX.is_weekend());
     }
 
     #[test]
-    fn new() {
-        assert_eq!(Date::new(0b0000_0000_0010_0001).unwrap(), Date::MIN);
-        assert_eq!(Date::new(0b1111_1111_1001_1111).unwrap(), Date::MAX);
+    fn is_weekend_agrees_with_weekday() {
+        for date in Date::all_valid() {
+            assert_eq!(
+                date.is_weekend(),
+                date.weekday().number_days_from_monday() >= 5
+            );
+        }
     }
 
     #[test]
-    fn new_with_invalid_date() {
-        // The Day field is 0.
-        assert!(Date::new(0b0000_0000_0010_0000).is_none());
-        // The Day field is 30, which is after the last day of February.
-        assert!(Date::new(0b0000_0000_0101_1110).is_none());
-        // The Month field is 0.
-        assert!(Date::new(0b0000_0000_0000_0001).is_none());
-        // The Month field is 13.
-        assert!(Date::new(0b0000_0001_1010_0001).is_none());
+    fn with_year() {
+        assert_eq!(Date::MIN.with_year(1980).unwrap(), Date::MIN);
+        assert_eq!(
+            Date::MIN.with_year(2107).unwrap(),
+            Date::from_date(date!(2107-01-01)).unwrap()
+        );
+        assert_eq!(
+            Date::MAX.with_year(1980).unwrap(),
+            Date::from_date(date!(1980-12-31)).unwrap()
+        );
+        // <https://github.com/zip-rs/zip/blob/v0.6.4/src/types.rs#L553-L569>.
+        assert_eq!(
+            Date::new(0b0100_1101_0111_0001)
+                .unwrap()
+                .with_year(2019)
+                .unwrap(),
+            Date::from_date(date!(2019-11-17)).unwrap()
+        );
     }
 
     #[test]
-    fn new_unchecked() {
+    fn with_year_with_leap_day() {
+        let date = Date::from_date(date!(2000-02-29)).unwrap();
         assert_eq!(
-            unsafe { Date::new_unchecked(0b0000_0000_0010_0001) },
-            Date::MIN
+            date.with_year(2004).unwrap(),
+            Date::from_date(date!(2004-02-29)).unwrap()
         );
         assert_eq!(
-            unsafe { Date::new_unchecked(0b1111_1111_1001_1111) },
-            Date::MAX
+            date.with_year(2001).unwrap_err(),
+            DateErrorKind::InvalidDay.into()
+        );
+        // `2100` is not a leap year.
+        assert_eq!(
+            date.with_year(2100).unwrap_err(),
+            DateErrorKind::InvalidDay.into()
         );
     }
 
     #[test]
-    const fn new_unchecked_is_const_fn() {
-        const _: Date = unsafe { Date::new_unchecked(0b0000_0000_0010_0001) };
+    fn with_year_with_out_of_range_year() {
+        assert_eq!(
+            Date::MIN.with_year(1979).unwrap_err(),
+            DateErrorKind::Range(DateRangeErrorKind::Negative).into()
+        );
+        assert_eq!(
+            Date::MAX.with_year(2108).unwrap_err(),
+            DateErrorKind::Range(DateRangeErrorKind::Overflow).into()
+        );
     }
 
     #[test]
-    fn from_date_before_dos_date_epoch() {
+    fn first_of_month() {
+        assert_eq!(Date::MIN.first_of_month(), Date::MIN);
+        // <https://devblogs.microsoft.com/oldnewthing/20030905-02/?p=42653>.
         assert_eq!(
-            Date::from_date(date!(1979-12-31)).unwrap_err(),
-            DateRangeErrorKind::Negative.into()
+            Date::new(0b0010_1101_0111_1010).unwrap().first_of_month(),
+            Date::from_date(date!(2002-11-01)).unwrap()
         );
         assert_eq!(
-            Date::from_date(date!(1979-12-31)).unwrap_err(),
-            DateRangeErrorKind::Negative.into()
+            Date::from_date(date!(2000-02-29)).unwrap().first_of_month(),
+            Date::from_date(date!(2000-02-01)).unwrap()
+        );
+        assert_eq!(
+            Date::MAX.first_of_month(),
+            Date::from_date(date!(2107-12-01)).unwrap()
         );
     }
 
     #[test]
-    fn from_date() {
-        assert_eq!(Date::from_date(date!(1980-01-01)).unwrap(), Date::MIN);
-        assert_eq!(Date::from_date(date!(1980-01-01)).unwrap(), Date::MIN);
-        // <https://devblogs.microsoft.com/oldnewthing/20030905-02/?p=42653>.
+    const fn first_of_month_is_const_fn() {
+        const _: Date = Date::MAX.first_of_month();
+    }
+
+    #[test]
+    fn last_of_month() {
         assert_eq!(
-            Date::from_date(date!(2002-11-26)).unwrap(),
-            Date::new(0b0010_1101_0111_1010).unwrap()
+            Date::MIN.last_of_month(),
+            Date::from_date(date!(1980-01-31)).unwrap()
+        );
+        assert_eq!(
+            Date::from_date(date!(2000-02-01)).unwrap().last_of_month(),
+            Date::from_date(date!(2000-02-29)).unwrap()
+        );
+        assert_eq!(
+            Date::from_date(date!(2001-02-01)).unwrap().last_of_month(),
+            Date::from_date(date!(2001-02-28)).unwrap()
+        );
+        // `2100` is not a leap year.
+        assert_eq!(
+            Date::from_date(date!(2100-02-01)).unwrap().last_of_month(),
+            Date::from_date(date!(2100-02-28)).unwrap()
         );
         // <https://github.com/zip-rs/zip/blob/v0.6.4/src/types.rs#L553-L569>.
         assert_eq!(
-            Date::from_date(date!(2018-11-17)).unwrap(),
-            Date::new(0b0100_1101_0111_0001).unwrap()
+            Date::new(0b0100_1101_0111_0001).unwrap().last_of_month(),
+            Date::from_date(date!(2018-11-30)).unwrap()
         );
-        assert_eq!(Date::from_date(date!(2107-12-31)).unwrap(), Date::MAX);
-        assert_eq!(Date::from_date(date!(2107-12-31)).unwrap(), Date::MAX);
+        assert_eq!(Date::MAX.last_of_month(), Date::MAX);
     }
 
     #[test]
-    fn from_date_with_too_big_date() {
+    const fn last_of_month_is_const_fn() {
+        const _: Date = Date::MIN.last_of_month();
+    }
+
+    #[test]
+    fn last_of_month_agrees_with_time() {
+        for date in Date::all_valid() {
+            let date_time = time::Date::from(date);
+            let last = date_time
+                .replace_day(date_time.month().length(date_time.year()))
+                .unwrap();
+            assert_eq!(date.last_of_month(), Date::from_date(last).unwrap());
+            assert_eq!(date.first_of_month().last_of_month(), date.last_of_month());
+        }
+    }
+
+    #[test]
+    fn next_day() {
         assert_eq!(
-            Date::from_date(date!(2108-01-01)).unwrap_err(),
-            DateRangeErrorKind::Overflow.into()
+            Date::MIN.next_day().unwrap(),
+            Date::from_date(date!(1980-01-02)).unwrap()
+        );
+        assert_eq!(
+            Date::from_date(date!(1980-01-31))
+                .unwrap()
+                .next_day()
+                .unwrap(),
+            Date::from_date(date!(1980-02-01)).unwrap()
+        );
+        assert_eq!(
+            Date::from_date(date!(2000-02-28))
+                .unwrap()
+                .next_day()
+                .unwrap(),
+            Date::from_date(date!(2000-02-29)).unwrap()
+        );
+        assert_eq!(
+            Date::from_date(date!(2100-02-28))
+                .unwrap()
+                .next_day()
+                .unwrap(),
+            Date::from_date(date!(2100-03-01)).unwrap()
+        );
+        assert_eq!(
+            Date::from_date(date!(2001-12-31))
+                .unwrap()
+                .next_day()
+                .unwrap(),
+            Date::from_date(date!(2002-01-01)).unwrap()
         );
+        assert!(Date::MAX.next_day().is_none());
     }
 
     #[test]
-    fn is_valid() {
-        assert!(Date::MIN.is_valid());
-        // <https://devblogs.microsoft.com/oldnewthing/20030905-02/?p=42653>.
-        assert!(Date::new(0b0010_1101_0111_1010).unwrap().is_valid());
-        // <https://github.com/zip-rs/zip/blob/v0.6.4/src/types.rs#L553-L569>.
-        assert!(Date::new(0b0100_1101_0111_0001).unwrap().is_valid());
-        assert!(Date::MAX.is_valid());
+    const fn next_day_is_const_fn() {
+        const _: Option<Date> = Date::MIN.next_day();
     }
 
     #[test]
-    fn is_valid_with_invalid_date() {
-        // The Day field is 0.
-        assert!(!unsafe { Date::new_unchecked(0b0000_0000_0010_0000) }.is_valid());
-        // The Day field is 30, which is after the last day of February.
-        assert!(!unsafe { Date::new_unchecked(0b0000_0000_0101_1110) }.is_valid());
-        // The Month field is 0.
-        assert!(!unsafe { Date::new_unchecked(0b0000_0000_0000_0001) }.is_valid());
-        // The Month field is 13.
-        assert!(!unsafe { Date::new_unchecked(0b0000_0001_1010_0001) }.is_valid());
+    fn next_day_agrees_with_time() {
+        for date in Date::all_valid() {
+            assert_eq!(
+                date.next_day(),
+                time::Date::from(date)
+                    .next_day()
+                    .and_then(|d| Date::from_date(d).ok())
+            );
+        }
     }
 
     #[test]
-    fn to_raw() {
-        assert_eq!(Date::MIN.to_raw(), 0b0000_0000_0010_0001);
-        // <https://devblogs.microsoft.com/oldnewthing/20030905-02/?p=42653>.
+    fn previous_day() {
+        assert!(Date::MIN.previous_day().is_none());
         assert_eq!(
-            Date::new(0b0010_1101_0111_1010).unwrap().to_raw(),
-            0b0010_1101_0111_1010
+            Date::from_date(date!(1980-02-01))
+                .unwrap()
+                .previous_day()
+                .unwrap(),
+            Date::from_date(date!(1980-01-31)).unwrap()
+        );
+        assert_eq!(
+            Date::from_date(date!(2000-03-01))
+                .unwrap()
+                .previous_day()
+                .unwrap(),
+            Date::from_date(date!(2000-02-29)).unwrap()
+        );
+        assert_eq!(
+            Date::from_date(date!(2100-03-01))
+                .unwrap()
+                .previous_day()
+                .unwrap(),
+            Date::from_date(date!(2100-02-28)).unwrap()
+        );
+        assert_eq!(
+            Date::from_date(date!(2002-01-01))
+                .unwrap()
+                .previous_day()
+                .unwrap(),
+            Date::from_date(date!(2001-12-31)).unwrap()
+        );
+        assert_eq!(
+            Date::MAX.previous_day().unwrap(),
+            Date::from_date(date!(2107-12-30)).unwrap()
+        );
+    }
+
+    #[test]
+    const fn previous_day_is_const_fn() {
+        const _: Option<Date> = Date::MAX.previous_day();
+    }
+
+    #[test]
+    fn previous_day_agrees_with_time() {
+        for date in Date::all_valid() {
+            assert_eq!(
+                date.previous_day(),
+                time::Date::from(date)
+                    .previous_day()
+                    .and_then(|d| Date::from_date(d).ok())
+            );
+        }
+    }
+
+    #[test]
+    fn checked_add_days() {
+        assert_eq!(Date::MIN.checked_add_days(0).unwrap(), Date::MIN);
+        assert_eq!(
+            Date::MIN.checked_add_days(1).unwrap(),
+            Date::from_date(date!(1980-01-02)).unwrap()
         );
         // <https://github.com/zip-rs/zip/blob/v0.6.4/src/types.rs#L553-L569>.
         assert_eq!(
-            Date::new(0b0100_1101_0111_0001).unwrap().to_raw(),
-            0b0100_1101_0111_0001
+            Date::from_date(date!(2018-11-17))
+                .unwrap()
+                .checked_add_days(45)
+                .unwrap(),
+            Date::from_date(date!(2019-01-01)).unwrap()
         );
-        assert_eq!(Date::MAX.to_raw(), 0b1111_1111_1001_1111);
+        assert_eq!(
+            Date::from_date(date!(2018-11-17))
+                .unwrap()
+                .checked_add_days(-45)
+                .unwrap(),
+            Date::from_date(date!(2018-10-03)).unwrap()
+        );
+        assert_eq!(Date::MIN.checked_add_days(46750).unwrap(), Date::MAX);
+        assert_eq!(Date::MAX.checked_add_days(-46750).unwrap(), Date::MIN);
     }
 
     #[test]
-    const fn to_raw_is_const_fn() {
-        const _: u16 = Date::MIN.to_raw();
+    fn checked_add_days_with_out_of_range() {
+        assert!(Date::MAX.checked_add_days(1).is_none());
+        assert!(Date::MIN.checked_add_days(-1).is_none());
+        assert!(Date::MIN.checked_add_days(i32::MAX).is_none());
+        assert!(Date::MAX.checked_add_days(i32::MIN).is_none());
     }
 
     #[test]
-    fn year() {
-        assert_eq!(Date::MIN.year(), 1980);
-        // <https://devblogs.microsoft.com/oldnewthing/20030905-02/?p=42653>.
-        assert_eq!(Date::new(0b0010_1101_0111_1010).unwrap().year(), 2002);
+    fn checked_sub_days() {
+        assert_eq!(Date::MAX.checked_sub_days(0).unwrap(), Date::MAX);
+        assert_eq!(
+            Date::MAX.checked_sub_days(1).unwrap(),
+            Date::from_date(date!(2107-12-30)).unwrap()
+        );
         // <https://github.com/zip-rs/zip/blob/v0.6.4/src/types.rs#L553-L569>.
-        assert_eq!(Date::new(0b0100_1101_0111_0001).unwrap().year(), 2018);
-        assert_eq!(Date::MAX.year(), 2107);
+        assert_eq!(
+            Date::from_date(date!(2018-11-17))
+                .unwrap()
+                .checked_sub_days(-45)
+                .unwrap(),
+            Date::from_date(date!(2019-01-01)).unwrap()
+        );
+        assert_eq!(Date::MAX.checked_sub_days(46750).unwrap(), Date::MIN);
     }
 
     #[test]
-    const fn year_is_const_fn() {
-        const _: u16 = Date::MIN.year();
+    fn checked_sub_days_with_out_of_range() {
+        assert!(Date::MIN.checked_sub_days(1).is_none());
+        assert!(Date::MAX.checked_sub_days(-1).is_none());
+        assert!(Date::MAX.checked_sub_days(i32::MAX).is_none());
+        assert!(Date::MIN.checked_sub_days(i32::MIN).is_none());
     }
 
     #[test]
-    fn month() {
-        assert_eq!(Date::MIN.month(), Month::January);
-        // <https://devblogs.microsoft.com/oldnewthing/20030905-02/?p=42653>.
+    fn checked_add_days_agrees_with_next_day() {
+        for date in Date::all_valid() {
+            assert_eq!(date.checked_add_days(1), date.next_day());
+            assert_eq!(date.checked_sub_days(1), date.previous_day());
+        }
+    }
+
+    #[test]
+    fn checked_add_months() {
+        assert_eq!(Date::MIN.checked_add_months(0).unwrap(), Date::MIN);
         assert_eq!(
-            Date::new(0b0010_1101_0111_1010).unwrap().month(),
-            Month::November
+            Date::MIN.checked_add_months(1).unwrap(),
+            Date::from_date(date!(1980-02-01)).unwrap()
         );
         // <https://github.com/zip-rs/zip/blob/v0.6.4/src/types.rs#L553-L569>.
         assert_eq!(
-            Date::new(0b0100_1101_0111_0001).unwrap().month(),
-            Month::November
+            Date::new(0b0100_1101_0111_0001)
+                .unwrap()
+                .checked_add_months(14)
+                .unwrap(),
+            Date::from_date(date!(2020-01-17)).unwrap()
+        );
+        assert_eq!(
+            Date::from_date(date!(2000-01-31))
+                .unwrap()
+                .checked_add_months(1)
+                .unwrap(),
+            Date::from_date(date!(2000-02-29)).unwrap()
+        );
+        assert_eq!(
+            Date::from_date(date!(2001-01-31))
+                .unwrap()
+                .checked_add_months(1)
+                .unwrap(),
+            Date::from_date(date!(2001-02-28)).unwrap()
+        );
+        assert_eq!(
+            Date::MAX.checked_add_months(-1535).unwrap(),
+            Date::from_date(date!(1980-01-31)).unwrap()
         );
-        assert_eq!(Date::MAX.month(), Month::December);
     }
 
     #[test]
-    fn day() {
-        assert_eq!(Date::MIN.day(), 1);
-        // <https://devblogs.microsoft.com/oldnewthing/20030905-02/?p=42653>.
-        assert_eq!(Date::new(0b0010_1101_0111_1010).unwrap().day(), 26);
+    fn checked_add_months_with_out_of_range() {
+        assert!(Date::MAX.checked_add_months(1).is_none());
+        assert!(Date::MIN.checked_add_months(-1).is_none());
+        assert!(Date::MIN.checked_add_months(i32::MAX).is_none());
+        assert!(Date::MAX.checked_add_months(i32::MIN).is_none());
+    }
+
+    #[test]
+    fn checked_sub_months() {
+        assert_eq!(Date::MAX.checked_sub_months(0).unwrap(), Date::MAX);
+        assert_eq!(
+            Date::MAX.checked_sub_months(1).unwrap(),
+            Date::from_date(date!(2107-11-30)).unwrap()
+        );
+        assert_eq!(
+            Date::from_date(date!(2000-03-31))
+                .unwrap()
+                .checked_sub_months(1)
+                .unwrap(),
+            Date::from_date(date!(2000-02-29)).unwrap()
+        );
+        assert_eq!(
+            Date::MIN.checked_sub_months(-1535).unwrap(),
+            Date::from_date(date!(2107-12-01)).unwrap()
+        );
+    }
+
+    #[test]
+    fn checked_sub_months_with_out_of_range() {
+        assert!(Date::MIN.checked_sub_months(1).is_none());
+        assert!(Date::MAX.checked_sub_months(-1).is_none());
+        assert!(Date::MAX.checked_sub_months(i32::MAX).is_none());
+        assert!(Date::MIN.checked_sub_months(i32::MIN).is_none());
+    }
+
+    #[test]
+    fn months_since() {
+        assert_eq!(Date::MIN.months_since(Date::MIN), 0);
+        assert_eq!(
+            Date::from_date(date!(2019-01-01))
+                .unwrap()
+                .months_since(Date::from_date(date!(2018-11-30)).unwrap()),
+            2
+        );
+        assert_eq!(
+            Date::from_date(date!(2018-11-30))
+                .unwrap()
+                .months_since(Date::from_date(date!(2019-01-01)).unwrap()),
+            -2
+        );
+        // The day of the month is ignored.
+        assert_eq!(
+            Date::from_date(date!(2018-11-01))
+                .unwrap()
+                .months_since(Date::from_date(date!(2018-10-31)).unwrap()),
+            1
+        );
+        assert_eq!(
+            Date::from_date(date!(2018-11-30))
+                .unwrap()
+                .months_since(Date::from_date(date!(2018-11-01)).unwrap()),
+            0
+        );
         // <https://github.com/zip-rs/zip/blob/v0.6.4/src/types.rs#L553-L569>.
-        assert_eq!(Date::new(0b0100_1101_0111_0001).unwrap().day(), 17);
-        assert_eq!(Date::MAX.day(), 31);
+        assert_eq!(
+            Date::new(0b0100_1101_0111_0001)
+                .unwrap()
+                .months_since(Date::new(0b0010_1101_0111_1010).unwrap()),
+            192
+        );
+        assert_eq!(Date::MAX.months_since(Date::MIN), 1535);
+        assert_eq!(Date::MIN.months_since(Date::MAX), -1535);
+    }
+
+    #[test]
+    const fn months_since_is_const_fn() {
+        const _: i32 = Date::MAX.months_since(Date::MIN);
+    }
+
+    #[test]
+    fn months_since_agrees_with_iter_months() {
+        for (i, date) in Date::MIN.iter_months(u32::MAX).enumerate() {
+            assert_eq!(date.months_since(Date::MIN), i32::try_from(i).unwrap());
+        }
+    }
+
+    #[test]
+    fn iter_months() {
+        let dates = Date::from_date(date!(1980-01-31)).unwrap().iter_months(14);
+        assert!(
+            dates.eq([
+                date!(1980-01-31),
+                date!(1980-02-29),
+                date!(1980-03-31),
+                date!(1980-04-30),
+                date!(1980-05-31),
+                date!(1980-06-30),
+                date!(1980-07-31),
+                date!(1980-08-31),
+                date!(1980-09-30),
+                date!(1980-10-31),
+                date!(1980-11-30),
+                date!(1980-12-31),
+                date!(1981-01-31),
+                date!(1981-02-28),
+            ]
+            .map(|d| Date::from_date(d).unwrap()))
+        );
+    }
+
+    #[test]
+    fn iter_months_with_out_of_range() {
+        assert_eq!(Date::MIN.iter_months(0).count(), 0);
+        assert_eq!(Date::MIN.iter_months(u32::MAX).count(), 128 * 12);
+        assert!(Date::MAX.iter_months(u32::MAX).eq([Date::MAX]));
+    }
+
+    #[test]
+    fn iter_years() {
+        let dates = Date::from_date(date!(2096-02-29)).unwrap().iter_years(5);
+        assert!(
+            dates.eq([
+                date!(2096-02-29),
+                date!(2097-02-28),
+                date!(2098-02-28),
+                date!(2099-02-28),
+                date!(2100-02-28),
+            ]
+            .map(|d| Date::from_date(d).unwrap()))
+        );
+    }
+
+    #[test]
+    fn iter_years_with_out_of_range() {
+        assert_eq!(Date::MIN.iter_years(0).count(), 0);
+        assert_eq!(Date::MIN.iter_years(u32::MAX).count(), 128);
+        assert_eq!(Date::MAX.iter_years(u32::MAX).last(), Some(Date::MAX));
+    }
+
+    #[test]
+    fn days_in_month_of() {
+        assert_eq!(Date::days_in_month_of(2001, 1), 31);
+        assert_eq!(Date::days_in_month_of(2001, 2), 28);
+        assert_eq!(Date::days_in_month_of(2001, 4), 30);
+        assert_eq!(Date::days_in_month_of(2001, 12), 31);
+        // Leap years.
+        assert_eq!(Date::days_in_month_of(1980, 2), 29);
+        assert_eq!(Date::days_in_month_of(2000, 2), 29);
+        assert_eq!(Date::days_in_month_of(2104, 2), 29);
+        // `2100` is not a leap year.
+        assert_eq!(Date::days_in_month_of(2100, 2), 28);
+    }
+
+    #[test]
+    fn days_in_month_of_agrees_with_time() {
+        for year in 1980..=2107 {
+            for month in 1..=12 {
+                assert_eq!(
+                    Date::days_in_month_of(year, month),
+                    time::util::days_in_month(Month::try_from(month).unwrap(), i32::from(year))
+                );
+            }
+        }
+    }
+
+    #[test]
+    #[should_panic(expected = "month should be in `1..=12`")]
+    fn days_in_month_of_with_zero_month() {
+        let _ = Date::days_in_month_of(2000, 0);
+    }
+
+    #[test]
+    #[should_panic(expected = "month should be in `1..=12`")]
+    fn days_in_month_of_with_too_big_month() {
+        let _ = Date::days_in_month_of(2000, 13);
+    }
+
+    #[test]
+    const fn days_in_month_of_is_const_fn() {
+        const FEBRUARY_2000: u8 = Date::days_in_month_of(2000, 2);
+        const FEBRUARY_2001: u8 = Date::days_in_month_of(2001, 2);
+        const { assert!(FEBRUARY_2000 == 29) };
+        const { assert!(FEBRUARY_2001 == 28) };
     }
 
     #[test]
@@ -4,13 +4,54 @@
 
 //! Utilities for comparing and ordering values.
 
+use core::cmp::Ordering;
+
+use super::Date;
+use crate::{DateTime, Time};
+
+impl PartialEq<DateTime> for Date {
+    /// Returns [`true`] if `other` is midnight of this `Date`.
+    ///
+    /// This `Date` is treated as the `DateTime` at "00:00:00" on that date.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use dos_date_time::{Date, DateTime, Time};
+    /// #
+    /// assert_eq!(Date::MIN, DateTime::MIN);
+    /// assert_ne!(Date::MIN, DateTime::new(Date::MIN, Time::MAX));
+    /// ```
+    fn eq(&self, other: &DateTime) -> bool {
+        DateTime::new(*self, Time::MIN) == *other
+    }
+}
+
+impl PartialOrd<DateTime> for Date {
+    /// Compares this `Date` with `other`.
+    ///
+    /// This `Date` is treated as the `DateTime` at "00:00:00" on that date, so
+    /// `date <= dt` holds if `dt` is on or after midnight of `date`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use dos_date_time::{Date, DateTime, Time};
+    /// #
+    /// assert!(Date::MIN <= DateTime::MIN);
+    /// assert!(Date::MIN < DateTime::new(Date::MIN, Time::MAX));
+    /// assert!(Date::MAX > DateTime::MIN);
+    /// ```
+    fn partial_cmp(&self, other: &DateTime) -> Option<Ordering> {
+        Some(DateTime::new(*self, Time::MIN).cmp(other))
+    }
+}
+
 #[cfg(test)]
 mod tests {
-    use core::cmp::Ordering;
-
-    use time::macros::date;
+    use time::macros::{date, datetime};
 
-    use super::super::Date;
+    use super::*;
 
     #[test]
     fn equality() {
@@ -42,4 +83,45 @@ mod tests {
         assert!(date < Date::from_date(date!(2018-11-18)).unwrap());
         assert!(date > Date::from_date(date!(2018-11-16)).unwrap());
     }
+
+    #[test]
+    fn equality_with_date_time() {
+        assert_eq!(Date::MIN, DateTime::MIN);
+        // <https://github.com/zip-rs/zip/blob/v0.6.4/src/types.rs#L553-L569>.
+        let date = Date::from_date(date!(2018-11-17)).unwrap();
+        assert_eq!(
+            date,
+            DateTime::try_from(datetime!(2018-11-17 00:00:00)).unwrap()
+        );
+        assert_ne!(
+            date,
+            DateTime::try_from(datetime!(2018-11-17 00:00:02)).unwrap()
+        );
+        assert_ne!(
+            date,
+            DateTime::try_from(datetime!(2018-11-16 00:00:00)).unwrap()
+        );
+        assert_ne!(Date::MAX, DateTime::MAX);
+    }
+
+    #[test]
+    fn order_with_date_time() {
+        // <https://github.com/zip-rs/zip/blob/v0.6.4/src/types.rs#L553-L569>.
+        let date = Date::from_date(date!(2018-11-17)).unwrap();
+
+        assert_eq!(
+            date.partial_cmp(&DateTime::try_from(datetime!(2018-11-17 00:00:00)).unwrap()),
+            Some(Ordering::Equal)
+        );
+
+        // Tests the boundaries on the same day.
+        assert!(date <= DateTime::try_from(datetime!(2018-11-17 00:00:00)).unwrap());
+        assert!(date >= DateTime::try_from(datetime!(2018-11-17 00:00:00)).unwrap());
+        assert!(date < DateTime::try_from(datetime!(2018-11-17 00:00:02)).unwrap());
+        assert!(date < DateTime::try_from(datetime!(2018-11-17 23:59:58)).unwrap());
+
+        // Tests the boundaries on the adjacent days.
+        assert!(date > DateTime::try_from(datetime!(2018-11-16 23:59:58)).unwrap());
+        assert!(date < DateTime::try_from(datetime!(2018-11-18 00:00:00)).unwrap());
+    }
 }
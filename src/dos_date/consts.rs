@@ -4,6 +4,9 @@
 
 //! Constants for [`Date`].
 
+#[cfg(feature = "num-traits")]
+use num_traits::Bounded;
+
 use super::Date;
 
 impl Date {
@@ -34,11 +37,62 @@ impl Date {
     /// ```
     // SAFETY: the given MS-DOS date is valid as the largest MS-DOS date.
     pub const MAX: Self = unsafe { Self::new_unchecked(0b1111_1111_1001_1111) };
+
+    /// The number of days in each month of a non-leap year.
+    ///
+    /// The index is the zero-based month, so February is at index `1`. See
+    /// [`Date::days_in_month_of`] for leap years.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use dos_date_time::Date;
+    /// #
+    /// assert_eq!(Date::DAYS_IN_MONTH[0], 31);
+    /// assert_eq!(Date::DAYS_IN_MONTH[1], 28);
+    /// assert_eq!(
+    ///     Date::DAYS_IN_MONTH
+    ///         .iter()
+    ///         .map(|&d| u16::from(d))
+    ///         .sum::<u16>(),
+    ///     365
+    /// );
+    /// ```
+    pub const DAYS_IN_MONTH: [u8; 12] = [31, 28, 31, 30, 31, 30, 31, 31, 30, 31, 30, 31];
+}
+
+#[cfg(feature = "num-traits")]
+impl Bounded for Date {
+    /// Returns [`Date::MIN`].
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use dos_date_time::{Date, num_traits::Bounded};
+    /// #
+    /// assert_eq!(<Date as Bounded>::min_value(), Date::MIN);
+    /// ```
+    fn min_value() -> Self {
+        Self::MIN
+    }
+
+    /// Returns [`Date::MAX`].
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use dos_date_time::{Date, num_traits::Bounded};
+    /// #
+    /// assert_eq!(<Date as Bounded>::max_value(), Date::MAX);
+    /// ```
+    fn max_value() -> Self {
+        Self::MAX
+    }
 }
 
 #[cfg(test)]
 mod tests {
-    use time::macros::date;
+    use time::{Month, macros::date};
 
     use super::*;
 
@@ -51,4 +105,21 @@ mod tests {
     fn max() {
         assert_eq!(Date::MAX, Date::from_date(date!(2107-12-31)).unwrap());
     }
+
+    #[test]
+    fn days_in_month() {
+        for (month, days) in (1..=12).zip(Date::DAYS_IN_MONTH) {
+            assert_eq!(
+                days,
+                time::util::days_in_month(Month::try_from(month).unwrap(), 2001)
+            );
+        }
+    }
+
+    #[cfg(feature = "num-traits")]
+    #[test]
+    fn bounded() {
+        assert_eq!(<Date as Bounded>::min_value(), Date::MIN);
+        assert_eq!(<Date as Bounded>::max_value(), Date::MAX);
+    }
 }
@@ -10,7 +10,26 @@ use chrono::{Datelike, NaiveDate};
 use jiff::civil;
 
 use super::Date;
-use crate::error::DateRangeError;
+use crate::error::{DateRangeError, InvalidDateField};
+
+impl From<Date> for u16 {
+    /// Converts a `Date` to the MS-DOS date as the underlying [`u16`]
+    /// value.
+    ///
+    /// This is equivalent to [`Date::to_raw`].
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use dos_date_time::Date;
+    /// #
+    /// assert_eq!(u16::from(Date::MIN), 0b0000_0000_0010_0001);
+    /// assert_eq!(u16::from(Date::MAX), 0b1111_1111_1001_1111);
+    /// ```
+    fn from(date: Date) -> Self {
+        date.to_raw()
+    }
+}
 
 impl From<Date> for time::Date {
     /// Converts a `Date` to a [`time::Date`].
@@ -70,6 +89,27 @@ impl From<Date> for civil::Date {
     /// assert_eq!(civil::Date::from(Date::MIN), civil::date(1980, 1, 1));
     /// assert_eq!(civil::Date::from(Date::MAX), civil::date(2107, 12, 31));
     /// ```
+    fn from(date: Date) -> Self {
+        let (year, month, day) = date.into();
+        civil::date(year, month, day)
+    }
+}
+
+#[cfg(feature = "jiff")]
+impl From<Date> for (i16, i8, i8) {
+    /// Converts a `Date` to a tuple of the year, month, and day.
+    ///
+    /// The types of the fields are the same as those of [`civil::date`], which
+    /// is useful for constructing [`jiff`] values from parts.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use dos_date_time::Date;
+    /// #
+    /// assert_eq!(<(i16, i8, i8)>::from(Date::MIN), (1980, 1, 1));
+    /// assert_eq!(<(i16, i8, i8)>::from(Date::MAX), (2107, 12, 31));
+    /// ```
     fn from(date: Date) -> Self {
         let (year, month, day) = (
             date.year()
@@ -82,7 +122,34 @@ impl From<Date> for civil::Date {
                 .try_into()
                 .expect("day should be in the range of `i8`"),
         );
-        civil::date(year, month, day)
+        (year, month, day)
+    }
+}
+
+impl TryFrom<u16> for Date {
+    type Error = InvalidDateField;
+
+    /// Converts the given MS-DOS date to a `Date`.
+    ///
+    /// This is the trait form of [`Date::new`], and is equivalent to
+    /// [`Date::checked_from_raw`].
+    ///
+    /// # Errors
+    ///
+    /// Returns [`Err`] if `date` is not a valid MS-DOS date.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use dos_date_time::Date;
+    /// #
+    /// assert_eq!(Date::try_from(0b0000_0000_0010_0001), Ok(Date::MIN));
+    /// assert_eq!(Date::try_from(0b1111_1111_1001_1111), Ok(Date::MAX));
+    ///
+    /// assert!(Date::try_from(0b0000_0000_0000_0001).is_err());
+    /// ```
+    fn try_from(date: u16) -> Result<Self, Self::Error> {
+        Self::checked_from_raw(date)
     }
 }
 
@@ -199,6 +266,27 @@ impl TryFrom<civil::Date> for Date {
     }
 }
 
+/// Returns [`true`] if `date` is in the range of the MS-DOS date.
+///
+/// This is equivalent to checking whether [`Date::try_from`] succeeds, but
+/// does not construct the error.
+///
+/// # Examples
+///
+/// ```
+/// # use dos_date_time::time::macros::date;
+/// #
+/// assert!(dos_date_time::is_date_representable(date!(1980-01-01)));
+/// assert!(dos_date_time::is_date_representable(date!(2107-12-31)));
+///
+/// assert!(!dos_date_time::is_date_representable(date!(1979-12-31)));
+/// assert!(!dos_date_time::is_date_representable(date!(2108-01-01)));
+/// ```
+#[must_use]
+pub const fn is_date_representable(date: time::Date) -> bool {
+    matches!(date.year(), 1980..=2107)
+}
+
 #[cfg(test)]
 mod tests {
     use time::macros::date;
@@ -206,6 +294,22 @@ mod tests {
     use super::*;
     use crate::error::DateRangeErrorKind;
 
+    #[test]
+    fn from_date_to_u16() {
+        assert_eq!(u16::from(Date::MIN), 0b0000_0000_0010_0001);
+        // <https://devblogs.microsoft.com/oldnewthing/20030905-02/?p=42653>.
+        assert_eq!(
+            u16::from(Date::new(0b0010_1101_0111_1010).unwrap()),
+            0b0010_1101_0111_1010
+        );
+        // <https://github.com/zip-rs/zip/blob/v0.6.4/src/types.rs#L553-L569>.
+        assert_eq!(
+            u16::from(Date::new(0b0100_1101_0111_0001).unwrap()),
+            0b0100_1101_0111_0001
+        );
+        assert_eq!(u16::from(Date::MAX), 0b1111_1111_1001_1111);
+    }
+
     #[test]
     fn from_date_to_time_date() {
         assert_eq!(time::Date::from(Date::MIN), date!(1980-01-01));
@@ -262,6 +366,23 @@ mod tests {
         assert_eq!(civil::Date::from(Date::MAX), civil::date(2107, 12, 31));
     }
 
+    #[cfg(feature = "jiff")]
+    #[test]
+    fn from_date_to_tuple() {
+        assert_eq!(<(i16, i8, i8)>::from(Date::MIN), (1980, 1, 1));
+        // <https://devblogs.microsoft.com/oldnewthing/20030905-02/?p=42653>.
+        assert_eq!(
+            <(i16, i8, i8)>::from(Date::new(0b0010_1101_0111_1010).unwrap()),
+            (2002, 11, 26)
+        );
+        // <https://github.com/zip-rs/zip/blob/v0.6.4/src/types.rs#L553-L569>.
+        assert_eq!(
+            <(i16, i8, i8)>::from(Date::new(0b0100_1101_0111_0001).unwrap()),
+            (2018, 11, 17)
+        );
+        assert_eq!(<(i16, i8, i8)>::from(Date::MAX), (2107, 12, 31));
+    }
+
     #[test]
     fn try_from_time_date_to_date_before_dos_date_epoch() {
         assert_eq!(
@@ -399,4 +520,87 @@ mod tests {
             DateRangeErrorKind::Overflow.into()
         );
     }
+
+    #[test]
+    fn try_from_u16_to_date() {
+        assert_eq!(Date::try_from(0b0000_0000_0010_0001).unwrap(), Date::MIN);
+        // <https://devblogs.microsoft.com/oldnewthing/20030905-02/?p=42653>.
+        assert_eq!(
+            Date::try_from(0b0010_1101_0111_1010).unwrap(),
+            Date::new(0b0010_1101_0111_1010).unwrap()
+        );
+        // <https://github.com/zip-rs/zip/blob/v0.6.4/src/types.rs#L553-L569>.
+        assert_eq!(
+            Date::try_from(0b0100_1101_0111_0001).unwrap(),
+            Date::new(0b0100_1101_0111_0001).unwrap()
+        );
+        assert_eq!(Date::try_from(0b1111_1111_1001_1111).unwrap(), Date::MAX);
+    }
+
+    #[test]
+    fn try_from_u16_to_date_with_invalid_date() {
+        // The Month field is 0.
+        assert_eq!(
+            Date::try_from(0b0000_0000_0000_0001).unwrap_err(),
+            InvalidDateField::MonthZero
+        );
+        // The Month field is 13.
+        assert_eq!(
+            Date::try_from(0b0000_0001_1010_0001).unwrap_err(),
+            InvalidDateField::MonthTooLarge(13)
+        );
+        // The Day field is 0.
+        assert_eq!(
+            Date::try_from(0b0000_0000_0010_0000).unwrap_err(),
+            InvalidDateField::DayZero
+        );
+        // The Day field is 30, which is after the last day of February.
+        assert_eq!(
+            Date::try_from(0b0000_0000_0101_1110).unwrap_err(),
+            InvalidDateField::DayTooLarge { day: 30, max: 29 }
+        );
+    }
+
+    #[test]
+    fn try_from_u16_agrees_with_new() {
+        for date in u16::MIN..=u16::MAX {
+            assert_eq!(Date::try_from(date).ok(), Date::new(date));
+        }
+    }
+
+    #[test]
+    fn u16_roundtrip() {
+        for date in Date::all_valid() {
+            assert_eq!(Date::try_from(u16::from(date)).unwrap(), date);
+        }
+    }
+
+    #[test]
+    fn is_date_representable() {
+        assert!(!super::is_date_representable(time::Date::MIN));
+        assert!(!super::is_date_representable(date!(1979-12-31)));
+        assert!(super::is_date_representable(date!(1980-01-01)));
+        // <https://github.com/zip-rs/zip/blob/v0.6.4/src/types.rs#L553-L569>.
+        assert!(super::is_date_representable(date!(2018-11-17)));
+        assert!(super::is_date_representable(date!(2107-12-31)));
+        assert!(!super::is_date_representable(date!(2108-01-01)));
+        assert!(!super::is_date_representable(time::Date::MAX));
+    }
+
+    #[test]
+    fn is_date_representable_agrees_with_try_from() {
+        let mut date = date!(1979-01-01);
+        while date <= date!(2109-01-01) {
+            assert_eq!(
+                super::is_date_representable(date),
+                Date::try_from(date).is_ok()
+            );
+            date = date.next_day().unwrap();
+        }
+    }
+
+    #[test]
+    const fn is_date_representable_is_const_fn() {
+        const _: bool = super::is_date_representable(time::Date::MIN);
+    }
 }
@@ -4,10 +4,57 @@
 
 //! Utilities for formatting and printing [`Date`].
 
+#[cfg(feature = "alloc")]
+use alloc::string::String;
 use core::fmt;
 
 use super::Date;
 
+impl Date {
+    /// Returns the value of this `Date` with the day of the week, such as
+    /// `"Saturday, 2018-11-17"`.
+    ///
+    /// The date part is the same as the [`Display`](fmt::Display) output.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use dos_date_time::Date;
+    /// #
+    /// assert_eq!(Date::MIN.to_long_string(), "Tuesday, 1980-01-01");
+    /// assert_eq!(Date::MAX.to_long_string(), "Saturday, 2107-12-31");
+    /// ```
+    #[cfg(feature = "alloc")]
+    #[must_use]
+    pub fn to_long_string(self) -> String {
+        format!("{}, {self}", self.weekday())
+    }
+
+    #[allow(clippy::missing_panics_doc)]
+    /// Writes the [`Display`](fmt::Display) output of this `Date` into `buf`.
+    ///
+    /// `buf` is cleared first, so its previous contents are discarded. This
+    /// is equivalent to `*buf = self.to_string()`, but reuses the allocation
+    /// of `buf`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use dos_date_time::Date;
+    /// #
+    /// let mut buf = String::new();
+    /// Date::MIN.to_string_buf(&mut buf);
+    /// assert_eq!(buf, "1980-01-01");
+    /// Date::MAX.to_string_buf(&mut buf);
+    /// assert_eq!(buf, "2107-12-31");
+    /// ```
+    #[cfg(feature = "alloc")]
+    pub fn to_string_buf(self, buf: &mut String) {
+        buf.clear();
+        fmt::write(buf, format_args!("{self}")).expect("formatting should not fail");
+    }
+}
+
 impl fmt::Display for Date {
     /// Shows the value of this `Date` in the well-known [RFC 3339 format].
     ///
@@ -27,6 +74,25 @@ impl fmt::Display for Date {
     }
 }
 
+impl fmt::Octal for Date {
+    /// Shows the MS-DOS date of this `Date` in octal.
+    ///
+    /// This forwards to the implementation for [`u16`], so the formatting
+    /// flags such as `#` and the width are supported.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use dos_date_time::Date;
+    /// #
+    /// assert_eq!(format!("{:o}", Date::MIN), "41");
+    /// assert_eq!(format!("{:#o}", Date::MAX), "0o177637");
+    /// ```
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        fmt::Octal::fmt(&self.to_raw(), f)
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use time::macros::date;
@@ -64,4 +130,72 @@ mod tests {
         );
         assert_eq!(format!("{}", Date::MAX), "2107-12-31");
     }
+
+    #[test]
+    fn octal() {
+        assert_eq!(format!("{:o}", Date::MIN), "41");
+        // <https://github.com/zip-rs/zip/blob/v0.6.4/src/types.rs#L553-L569>.
+        assert_eq!(
+            format!("{:o}", Date::from_date(date!(2018-11-17)).unwrap()),
+            "46561"
+        );
+        assert_eq!(format!("{:o}", Date::MAX), "177637");
+    }
+
+    #[test]
+    fn octal_with_flags() {
+        assert_eq!(format!("{:#o}", Date::MIN), "0o41");
+        assert_eq!(format!("{:06o}", Date::MIN), "000041");
+        assert_eq!(format!("{:#08o}", Date::MAX), "0o177637");
+        assert_eq!(
+            format!("{:o}", Date::MAX),
+            format!("{:o}", 0b1111_1111_1001_1111_u16)
+        );
+    }
+
+    #[cfg(feature = "alloc")]
+    #[test]
+    fn to_long_string() {
+        assert_eq!(Date::MIN.to_long_string(), "Tuesday, 1980-01-01");
+        // <https://devblogs.microsoft.com/oldnewthing/20030905-02/?p=42653>.
+        assert_eq!(
+            Date::from_date(date!(2002-11-26)).unwrap().to_long_string(),
+            "Tuesday, 2002-11-26"
+        );
+        // <https://github.com/zip-rs/zip/blob/v0.6.4/src/types.rs#L553-L569>.
+        assert_eq!(
+            Date::from_date(date!(2018-11-17)).unwrap().to_long_string(),
+            "Saturday, 2018-11-17"
+        );
+        assert_eq!(Date::MAX.to_long_string(), "Saturday, 2107-12-31");
+    }
+
+    #[cfg(feature = "alloc")]
+    #[test]
+    fn to_string_buf() {
+        use alloc::string::ToString;
+
+        // <https://github.com/zip-rs/zip/blob/v0.6.4/src/types.rs#L553-L569>.
+        for value in [
+            Date::MIN,
+            Date::from_date(date!(2018-11-17)).unwrap(),
+            Date::MAX,
+        ] {
+            let mut buf = String::new();
+            value.to_string_buf(&mut buf);
+            assert_eq!(buf, value.to_string());
+        }
+    }
+
+    #[cfg(feature = "alloc")]
+    #[test]
+    fn to_string_buf_reuses_buffer() {
+        let mut buf = String::from("this should be discarded entirely");
+        Date::MAX.to_string_buf(&mut buf);
+        assert_eq!(buf, "2107-12-31");
+        let capacity = buf.capacity();
+        Date::MIN.to_string_buf(&mut buf);
+        assert_eq!(buf, format!("{}", Date::MIN));
+        assert_eq!(buf.capacity(), capacity);
+    }
 }
@@ -0,0 +1,227 @@
+// SPDX-FileCopyrightText: 2025 Shun Sakai
+//
+// SPDX-License-Identifier: Apache-2.0 OR MIT
+
+//! Implementations of arithmetic operators for [`Date`].
+
+use core::ops::{Add, Sub};
+
+use super::Date;
+use crate::{Days, Months};
+
+impl Add<Days> for Date {
+    type Output = Self;
+
+    /// Computes `self + rhs`.
+    ///
+    /// `rhs` may be negative, in which case this moves backward.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the result is out of range for the MS-DOS date. See
+    /// [`Date::checked_add_days`] for a non-panicking alternative.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use dos_date_time::{Date, Days, time::macros::date};
+    /// #
+    /// assert_eq!(
+    ///     Date::MIN + Days(31),
+    ///     Date::from_date(date!(1980-02-01)).unwrap()
+    /// );
+    /// assert_eq!(Date::MAX + Days(-46750), Date::MIN);
+    /// ```
+    fn add(self, rhs: Days) -> Self::Output {
+        self.checked_add_days(rhs.0)
+            .expect("resulting value should be in the range of MS-DOS date")
+    }
+}
+
+impl Sub<Days> for Date {
+    type Output = Self;
+
+    /// Computes `self - rhs`.
+    ///
+    /// `rhs` may be negative, in which case this moves forward.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the result is out of range for the MS-DOS date. See
+    /// [`Date::checked_sub_days`] for a non-panicking alternative.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use dos_date_time::{Date, Days, time::macros::date};
+    /// #
+    /// assert_eq!(
+    ///     Date::MAX - Days(31),
+    ///     Date::from_date(date!(2107-11-30)).unwrap()
+    /// );
+    /// ```
+    fn sub(self, rhs: Days) -> Self::Output {
+        self.checked_sub_days(rhs.0)
+            .expect("resulting value should be in the range of MS-DOS date")
+    }
+}
+
+impl Add<Months> for Date {
+    type Output = Self;
+
+    /// Computes `self + rhs`.
+    ///
+    /// `rhs` may be negative, in which case this moves backward. The day of
+    /// this `Date` is clamped to the last day of the month if the resulting
+    /// month is shorter.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the result is out of range for the MS-DOS date. See
+    /// [`Date::checked_add_months`] for a non-panicking alternative.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use dos_date_time::{Date, Months, time::macros::date};
+    /// #
+    /// assert_eq!(
+    ///     Date::MIN + Months(1),
+    ///     Date::from_date(date!(1980-02-01)).unwrap()
+    /// );
+    /// assert_eq!(
+    ///     Date::from_date(date!(2000-01-31)).unwrap() + Months(1),
+    ///     Date::from_date(date!(2000-02-29)).unwrap()
+    /// );
+    /// ```
+    fn add(self, rhs: Months) -> Self::Output {
+        self.checked_add_months(rhs.0)
+            .expect("resulting value should be in the range of MS-DOS date")
+    }
+}
+
+impl Sub<Months> for Date {
+    type Output = Self;
+
+    /// Computes `self - rhs`.
+    ///
+    /// `rhs` may be negative, in which case this moves forward. The day of
+    /// this `Date` is clamped to the last day of the month if the resulting
+    /// month is shorter.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the result is out of range for the MS-DOS date. See
+    /// [`Date::checked_sub_months`] for a non-panicking alternative.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use dos_date_time::{Date, Months, time::macros::date};
+    /// #
+    /// assert_eq!(
+    ///     Date::MAX - Months(1),
+    ///     Date::from_date(date!(2107-11-30)).unwrap()
+    /// );
+    /// ```
+    fn sub(self, rhs: Months) -> Self::Output {
+        self.checked_sub_months(rhs.0)
+            .expect("resulting value should be in the range of MS-DOS date")
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use time::macros::date;
+
+    use super::*;
+
+    #[test]
+    fn add_days() {
+        assert_eq!(Date::MIN + Days(0), Date::MIN);
+        assert_eq!(
+            Date::MIN + Days(1),
+            Date::from_date(date!(1980-01-02)).unwrap()
+        );
+        // <https://github.com/zip-rs/zip/blob/v0.6.4/src/types.rs#L553-L569>.
+        assert_eq!(
+            Date::new(0b0100_1101_0111_0001).unwrap() + Days(-1),
+            Date::from_date(date!(2018-11-16)).unwrap()
+        );
+        assert_eq!(Date::MIN + Days(46750), Date::MAX);
+    }
+
+    #[test]
+    #[should_panic(expected = "resulting value should be in the range of MS-DOS date")]
+    fn add_days_with_overflow() {
+        let _ = Date::MAX + Days(1);
+    }
+
+    #[test]
+    fn sub_days() {
+        assert_eq!(Date::MAX - Days(0), Date::MAX);
+        assert_eq!(
+            Date::MAX - Days(1),
+            Date::from_date(date!(2107-12-30)).unwrap()
+        );
+        assert_eq!(
+            Date::MIN - Days(-1),
+            Date::from_date(date!(1980-01-02)).unwrap()
+        );
+        assert_eq!(Date::MAX - Days(46750), Date::MIN);
+    }
+
+    #[test]
+    #[should_panic(expected = "resulting value should be in the range of MS-DOS date")]
+    fn sub_days_with_overflow() {
+        let _ = Date::MIN - Days(1);
+    }
+
+    #[test]
+    fn add_months() {
+        assert_eq!(Date::MIN + Months(0), Date::MIN);
+        assert_eq!(
+            Date::MIN + Months(1),
+            Date::from_date(date!(1980-02-01)).unwrap()
+        );
+        // <https://github.com/zip-rs/zip/blob/v0.6.4/src/types.rs#L553-L569>.
+        assert_eq!(
+            Date::new(0b0100_1101_0111_0001).unwrap() + Months(-1),
+            Date::from_date(date!(2018-10-17)).unwrap()
+        );
+        assert_eq!(
+            Date::from_date(date!(2000-01-31)).unwrap() + Months(1),
+            Date::from_date(date!(2000-02-29)).unwrap()
+        );
+    }
+
+    #[test]
+    fn add_months_and_days_differ() {
+        assert_ne!(Date::MIN + Months(1), Date::MIN + Days(1));
+    }
+
+    #[test]
+    #[should_panic(expected = "resulting value should be in the range of MS-DOS date")]
+    fn add_months_with_overflow() {
+        let _ = Date::MAX + Months(1);
+    }
+
+    #[test]
+    fn sub_months() {
+        assert_eq!(Date::MAX - Months(0), Date::MAX);
+        assert_eq!(
+            Date::MAX - Months(1),
+            Date::from_date(date!(2107-11-30)).unwrap()
+        );
+        assert_eq!(
+            Date::MIN - Months(-1),
+            Date::from_date(date!(1980-02-01)).unwrap()
+        );
+    }
+
+    #[test]
+    #[should_panic(expected = "resulting value should be in the range of MS-DOS date")]
+    fn sub_months_with_overflow() {
+        let _ = Date::MIN - Months(1);
+    }
+}
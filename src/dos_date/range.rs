@@ -0,0 +1,250 @@
+// SPDX-FileCopyrightText: 2025 Shun Sakai
+//
+// SPDX-License-Identifier: Apache-2.0 OR MIT
+
+//! An inclusive range of [`Date`].
+
+use core::{fmt, ops::RangeInclusive};
+
+use super::Date;
+
+/// An inclusive range of [`Date`], such as `1980-01-01..=2107-12-31`.
+///
+/// This is a thin wrapper around [`RangeInclusive<Date>`] which counts days
+/// and shows the range in a readable form.
+///
+/// # Examples
+///
+/// ```
+/// # use dos_date_time::{Date, DateRange};
+/// #
+/// let range = DateRange::new(Date::MIN, Date::MAX);
+/// assert_eq!(range.len(), 46751);
+/// assert_eq!(format!("{range}"), "1980-01-01..=2107-12-31");
+/// ```
+#[derive(Clone, Debug, Eq, Hash, PartialEq)]
+pub struct DateRange(pub RangeInclusive<Date>);
+
+impl DateRange {
+    /// Creates a new `DateRange` from `start` to `end`, inclusive.
+    ///
+    /// The range is empty if `start` is after `end`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use dos_date_time::{Date, DateRange};
+    /// #
+    /// assert_eq!(
+    ///     DateRange::new(Date::MIN, Date::MAX),
+    ///     DateRange(Date::MIN..=Date::MAX)
+    /// );
+    /// ```
+    #[must_use]
+    pub const fn new(start: Date, end: Date) -> Self {
+        Self(RangeInclusive::new(start, end))
+    }
+
+    /// Returns [`true`] if `date` is contained in this `DateRange`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use dos_date_time::{Date, DateRange, time::macros::date};
+    /// #
+    /// let range = DateRange::new(
+    ///     Date::from_date(date!(2000-01-01)).unwrap(),
+    ///     Date::from_date(date!(2000-12-31)).unwrap(),
+    /// );
+    /// assert!(range.contains(Date::from_date(date!(2000-12-31)).unwrap()));
+    ///
+    /// assert!(!range.contains(Date::from_date(date!(2001-01-01)).unwrap()));
+    /// ```
+    #[must_use]
+    pub fn contains(&self, date: Date) -> bool {
+        self.0.contains(&date)
+    }
+
+    /// Returns the number of days in this `DateRange`, including both ends.
+    ///
+    /// Returns `0` if this `DateRange` is empty.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use dos_date_time::{Date, DateRange};
+    /// #
+    /// assert_eq!(DateRange::new(Date::MIN, Date::MIN).len(), 1);
+    /// assert_eq!(DateRange::new(Date::MIN, Date::MAX).len(), 46751);
+    ///
+    /// assert_eq!(DateRange::new(Date::MAX, Date::MIN).len(), 0);
+    /// ```
+    #[must_use]
+    pub fn len(&self) -> u16 {
+        if self.is_empty() {
+            return 0;
+        }
+        let (start, end) = (self.0.start(), self.0.end());
+        end.to_days_since_epoch() - start.to_days_since_epoch() + 1
+    }
+
+    /// Returns [`true`] if this `DateRange` contains no days.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use dos_date_time::{Date, DateRange};
+    /// #
+    /// assert!(!DateRange::new(Date::MIN, Date::MAX).is_empty());
+    ///
+    /// assert!(DateRange::new(Date::MAX, Date::MIN).is_empty());
+    /// ```
+    #[must_use]
+    pub fn is_empty(&self) -> bool {
+        self.0.is_empty()
+    }
+}
+
+impl fmt::Display for DateRange {
+    /// Shows this `DateRange` as the start and the end separated by `..=`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use dos_date_time::{Date, DateRange};
+    /// #
+    /// assert_eq!(
+    ///     format!("{}", DateRange::new(Date::MIN, Date::MAX)),
+    ///     "1980-01-01..=2107-12-31"
+    /// );
+    /// ```
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}..={}", self.0.start(), self.0.end())
+    }
+}
+
+impl From<RangeInclusive<Date>> for DateRange {
+    /// Converts a [`RangeInclusive<Date>`] to a `DateRange`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use dos_date_time::{Date, DateRange};
+    /// #
+    /// assert_eq!(
+    ///     DateRange::from(Date::MIN..=Date::MAX),
+    ///     DateRange::new(Date::MIN, Date::MAX)
+    /// );
+    /// ```
+    fn from(range: RangeInclusive<Date>) -> Self {
+        Self(range)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use time::macros::date;
+
+    use super::*;
+
+    #[test]
+    fn new() {
+        assert_eq!(
+            DateRange::new(Date::MIN, Date::MAX),
+            DateRange(Date::MIN..=Date::MAX)
+        );
+    }
+
+    #[test]
+    const fn new_is_const_fn() {
+        const _: DateRange = DateRange::new(Date::MIN, Date::MAX);
+    }
+
+    #[test]
+    fn contains() {
+        // <https://github.com/zip-rs/zip/blob/v0.6.4/src/types.rs#L553-L569>.
+        let (start, end) = (
+            Date::from_date(date!(2018-11-17)).unwrap(),
+            Date::from_date(date!(2018-11-30)).unwrap(),
+        );
+        let range = DateRange::new(start, end);
+        assert!(!range.contains(start.previous_day().unwrap()));
+        assert!(range.contains(start));
+        assert!(range.contains(Date::from_date(date!(2018-11-20)).unwrap()));
+        assert!(range.contains(end));
+        assert!(!range.contains(end.next_day().unwrap()));
+    }
+
+    #[test]
+    fn contains_at_boundaries() {
+        let range = DateRange::new(Date::MIN, Date::MAX);
+        assert!(range.contains(Date::MIN));
+        assert!(range.contains(Date::MAX));
+
+        let range = DateRange::new(Date::MAX, Date::MIN);
+        assert!(!range.contains(Date::MIN));
+        assert!(!range.contains(Date::MAX));
+    }
+
+    #[test]
+    fn len() {
+        assert_eq!(DateRange::new(Date::MIN, Date::MIN).len(), 1);
+        assert_eq!(
+            DateRange::new(Date::MIN, Date::MIN.next_day().unwrap()).len(),
+            2
+        );
+        assert_eq!(
+            DateRange::new(
+                Date::from_date(date!(2000-01-01)).unwrap(),
+                Date::from_date(date!(2000-12-31)).unwrap()
+            )
+            .len(),
+            366
+        );
+        assert_eq!(DateRange::new(Date::MAX, Date::MAX).len(), 1);
+        assert_eq!(DateRange::new(Date::MIN, Date::MAX).len(), 46751);
+    }
+
+    #[test]
+    fn len_with_empty_range() {
+        assert_eq!(
+            DateRange::new(Date::MIN.next_day().unwrap(), Date::MIN).len(),
+            0
+        );
+        assert_eq!(DateRange::new(Date::MAX, Date::MIN).len(), 0);
+    }
+
+    #[test]
+    fn is_empty() {
+        assert!(!DateRange::new(Date::MIN, Date::MIN).is_empty());
+        assert!(!DateRange::new(Date::MIN, Date::MAX).is_empty());
+        assert!(DateRange::new(Date::MAX, Date::MIN).is_empty());
+    }
+
+    #[test]
+    fn display() {
+        assert_eq!(
+            format!("{}", DateRange::new(Date::MIN, Date::MAX)),
+            "1980-01-01..=2107-12-31"
+        );
+        // <https://devblogs.microsoft.com/oldnewthing/20030905-02/?p=42653>.
+        assert_eq!(
+            format!(
+                "{}",
+                DateRange::new(
+                    Date::new(0b0010_1101_0111_1010).unwrap(),
+                    Date::new(0b0100_1101_0111_0001).unwrap()
+                )
+            ),
+            "2002-11-26..=2018-11-17"
+        );
+    }
+
+    #[test]
+    fn from_range_inclusive_to_date_range() {
+        assert_eq!(
+            DateRange::from(Date::MIN..=Date::MAX),
+            DateRange::new(Date::MIN, Date::MAX)
+        );
+    }
+}
@@ -6,14 +6,43 @@
 //!
 //! [MS-DOS date and time]: https://learn.microsoft.com/en-us/windows/win32/sysinfo/ms-dos-date-and-time
 
+mod breakdown;
+#[cfg(feature = "bytemuck")]
+mod bytemuck;
 mod cmp;
 mod consts;
 mod convert;
+mod exfat;
 mod fmt;
+mod ops;
+mod policy;
+#[cfg(feature = "serde")]
+mod serde;
+mod words;
 
-use time::Month;
+use core::{iter, time::Duration};
+#[cfg(feature = "std")]
+use std::{io, time::SystemTime, vec::Vec};
 
-use crate::{Date, Time, error::DateTimeRangeError};
+use time::{Month, OffsetDateTime, PrimitiveDateTime};
+
+pub use self::{
+    breakdown::Breakdown, convert::is_representable, exfat::ExfatDateTime, fmt::DisplayUtc,
+    ops::between, policy::ValidationPolicy, words::DosWords,
+};
+use crate::{
+    Date, Time,
+    error::{
+        BufferTooSmallError, ConvertError, ConvertErrorKind, DateError, DateTimeError,
+        DateTimeRangeError, DateTimeRangeErrorKind, InvalidBitsError, ParseError, ParseErrorKind,
+        ReadError, ReadErrorKind,
+    },
+};
+
+/// The alphabet of [Crockford's Base32].
+///
+/// [Crockford's Base32]: https://www.crockford.com/base32.html
+const CROCKFORD_BASE32: &[u8; 32] = b"0123456789ABCDEFGHJKMNPQRSTVWXYZ";
 
 /// `DateTime` is a type that combines a [`Date`] and a [`Time`] and represents
 /// [MS-DOS date and time].
@@ -28,6 +57,15 @@ use crate::{Date, Time, error::DateTimeRangeError};
 ///
 /// </div>
 ///
+/// The order of `DateTime` is the chronological order, which is the same as
+/// the order of the 32-bit unsigned integer value `(date << 16) | time`. See
+/// [`DateTime::MONOTONIC`] for more details.
+///
+/// The hash of `DateTime` is guaranteed to be the same as the hash of the
+/// tuple `(Date, Time)` returned by [`DateTime::as_tuple`]. So `DateTime` and
+/// `(Date, Time)` can be used interchangeably as keys which are hashed with the
+/// same hasher.
+///
 /// See the [format specification] for [Kaitai Struct] for more details on the
 /// structure of MS-DOS date and time.
 ///
@@ -37,6 +75,7 @@ use crate::{Date, Time, error::DateTimeRangeError};
 /// [format specification]: https://formats.kaitai.io/dos_datetime/
 /// [Kaitai Struct]: https://kaitai.io/
 #[derive(Clone, Copy, Debug, Eq, Hash, Ord, PartialEq, PartialOrd)]
+#[repr(C)]
 pub struct DateTime {
     date: Date,
     time: Time,
@@ -58,6 +97,116 @@ impl DateTime {
         Self { date, time }
     }
 
+    /// Creates a new `DateTime` with the given MS-DOS date and MS-DOS time,
+    /// validating them according to `policy`.
+    ///
+    /// With [`ValidationPolicy::Strict`], this is equivalent to creating
+    /// `DateTime` with [`Date::new`] and [`Time::new`]. With
+    /// [`ValidationPolicy::Lenient`], the Month field and the Day field which
+    /// are 0 are treated as 1.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`Err`] if the given MS-DOS date or MS-DOS time is not valid
+    /// under `policy`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use dos_date_time::{DateTime, ValidationPolicy};
+    /// #
+    /// assert_eq!(
+    ///     DateTime::new_with_policy(0b0000_0000_0010_0001, u16::MIN, ValidationPolicy::Strict),
+    ///     Ok(DateTime::MIN)
+    /// );
+    ///
+    /// // The Day field is 0.
+    /// assert!(
+    ///     DateTime::new_with_policy(0b0000_0000_0010_0000, u16::MIN, ValidationPolicy::Strict)
+    ///         .is_err()
+    /// );
+    /// assert_eq!(
+    ///     DateTime::new_with_policy(0b0000_0000_0010_0000, u16::MIN, ValidationPolicy::Lenient),
+    ///     Ok(DateTime::MIN)
+    /// );
+    /// ```
+    pub fn new_with_policy(
+        date: u16,
+        time: u16,
+        policy: ValidationPolicy,
+    ) -> Result<Self, InvalidBitsError> {
+        let date = match policy {
+            ValidationPolicy::Strict => date,
+            ValidationPolicy::Lenient => {
+                let (month, day) = ((date >> 5) & 0x0F, date & 0x1F);
+                date | (u16::from(month == 0) << 5) | u16::from(day == 0)
+            }
+        };
+        let (date, time) = (Date::checked_from_raw(date)?, Time::checked_from_raw(time)?);
+        Ok(Self::new(date, time))
+    }
+
+    /// Creates a new `DateTime` with the given year, month, day, hour, minute,
+    /// and second.
+    ///
+    /// Returns [`None`] if the given values do not form valid MS-DOS date and
+    /// time.
+    ///
+    /// Unlike [`DateTime::from_date_time`], this does not use the [`time`]
+    /// crate, so it is callable in const contexts.
+    ///
+    /// <div class="warning">
+    ///
+    /// The resolution of MS-DOS time is 2 seconds. So this method rounds
+    /// `second` down to an even number.
+    ///
+    /// </div>
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use dos_date_time::DateTime;
+    /// #
+    /// const MIN: Option<DateTime> = DateTime::try_from_parts(1980, 1, 1, 0, 0, 0);
+    /// assert_eq!(MIN, Some(DateTime::MIN));
+    /// assert_eq!(
+    ///     DateTime::try_from_parts(2107, 12, 31, 23, 59, 59),
+    ///     Some(DateTime::MAX)
+    /// );
+    ///
+    /// // `2001` is not a leap year.
+    /// assert_eq!(DateTime::try_from_parts(2001, 2, 29, 0, 0, 0), None);
+    /// // Before `1980-01-01 00:00:00`.
+    /// assert_eq!(DateTime::try_from_parts(1979, 12, 31, 23, 59, 58), None);
+    /// ```
+    #[must_use]
+    pub const fn try_from_parts(
+        year: u16,
+        month: u8,
+        day: u8,
+        hour: u8,
+        minute: u8,
+        second: u8,
+    ) -> Option<Self> {
+        if year < 1980 || year > 2107 || month < 1 || month > 12 {
+            return None;
+        }
+        let (month, day) = (month as u16, day as u16);
+        if day < 1 || day > Date::days_in_month(year, month) {
+            return None;
+        }
+        if hour > 23 || minute > 59 || second > 59 {
+            return None;
+        }
+        let date = ((year - 1980) << 9) | (month << 5) | day;
+        let time = ((hour as u16) << 11) | ((minute as u16) << 5) | (second as u16 / 2);
+        // SAFETY: `date` is a valid as the MS-DOS date.
+        let date = unsafe { Date::new_unchecked(date) };
+        // SAFETY: `time` is a valid as the MS-DOS time.
+        let time = unsafe { Time::new_unchecked(time) };
+        Some(Self::new(date, time))
+    }
+
     /// Creates a new `DateTime` with the given [`time::Date`] and
     /// [`time::Time`].
     ///
@@ -104,394 +253,4881 @@ impl DateTime {
         Ok(dt)
     }
 
-    /// Returns [`true`] if `self` is valid MS-DOS date and time, and [`false`]
-    /// otherwise.
-    #[must_use]
-    pub fn is_valid(self) -> bool {
-        self.date().is_valid() && self.time().is_valid()
-    }
-
-    /// Gets the [`Date`] of this `DateTime`.
+    /// Creates a new `DateTime` with the given [`time::Date`] and
+    /// [`time::Time`], which must be exactly representable as MS-DOS date and
+    /// time.
     ///
-    /// # Examples
+    /// Unlike [`DateTime::from_date_time`], this does not round.
     ///
-    /// ```
-    /// # use dos_date_time::{Date, DateTime};
-    /// #
-    /// assert_eq!(DateTime::MIN.date(), Date::MIN);
-    /// assert_eq!(DateTime::MAX.date(), Date::MAX);
-    /// ```
-    #[must_use]
-    pub const fn date(self) -> Date {
-        self.date
-    }
-
-    /// Gets the [`Time`] of this `DateTime`.
+    /// # Errors
+    ///
+    /// Returns [`Err`] if `date` is out of range for the MS-DOS date, or if
+    /// `time` has an odd second or a fractional second.
     ///
     /// # Examples
     ///
     /// ```
-    /// # use dos_date_time::{DateTime, Time};
+    /// # use dos_date_time::{
+    /// #     DateTime,
+    /// #     time::{
+    /// #         Time,
+    /// #         macros::{date, time},
+    /// #     },
+    /// # };
     /// #
-    /// assert_eq!(DateTime::MIN.time(), Time::MIN);
-    /// assert_eq!(DateTime::MAX.time(), Time::MAX);
+    /// assert_eq!(
+    ///     DateTime::from_date_time_exact(date!(1980-01-01), Time::MIDNIGHT),
+    ///     Ok(DateTime::MIN)
+    /// );
+    /// assert_eq!(
+    ///     DateTime::from_date_time_exact(date!(2107-12-31), time!(23:59:58)),
+    ///     Ok(DateTime::MAX)
+    /// );
+    ///
+    /// // Before `1980-01-01 00:00:00`.
+    /// assert!(DateTime::from_date_time_exact(date!(1979-12-31), time!(23:59:58)).is_err());
+    /// // The second is odd.
+    /// assert!(DateTime::from_date_time_exact(date!(2107-12-31), time!(23:59:59)).is_err());
     /// ```
-    #[must_use]
-    pub const fn time(self) -> Time {
-        self.time
+    pub fn from_date_time_exact(date: time::Date, time: time::Time) -> Result<Self, DateTimeError> {
+        let date = Date::from_date(date).map_err(DateTimeRangeError::from)?;
+        let time = Time::from_time_exact(time)?;
+        let dt = Self::new(date, time);
+        Ok(dt)
     }
 
-    /// Gets the year of this `DateTime`.
+    /// Creates a new `DateTime` with the given [`OffsetDateTime`], which must
+    /// be in UTC.
+    ///
+    /// Unlike normalizing `dt` to UTC, this rejects `dt` if its offset is not
+    /// zero.
+    ///
+    /// <div class="warning">
+    ///
+    /// The resolution of MS-DOS date and time is 2 seconds. So this method
+    /// rounds towards zero, truncating any fractional part of the exact result
+    /// of dividing seconds by 2.
+    ///
+    /// </div>
+    ///
+    /// # Errors
+    ///
+    /// Returns [`Err`] if the offset of `dt` is not zero, or if `dt` is out of
+    /// range for MS-DOS date and time.
     ///
     /// # Examples
     ///
     /// ```
-    /// # use dos_date_time::DateTime;
+    /// # use dos_date_time::{DateTime, time::macros::datetime};
     /// #
-    /// assert_eq!(DateTime::MIN.year(), 1980);
-    /// assert_eq!(DateTime::MAX.year(), 2107);
+    /// assert_eq!(
+    ///     DateTime::try_from_utc(datetime!(1980-01-01 00:00:00 UTC)),
+    ///     Ok(DateTime::MIN)
+    /// );
+    /// assert_eq!(
+    ///     DateTime::try_from_utc(datetime!(2107-12-31 23:59:59 UTC)),
+    ///     Ok(DateTime::MAX)
+    /// );
+    ///
+    /// // The offset is not zero.
+    /// assert!(DateTime::try_from_utc(datetime!(1980-01-01 01:00:00 +01:00)).is_err());
+    /// // Before `1980-01-01 00:00:00`.
+    /// assert!(DateTime::try_from_utc(datetime!(1979-12-31 23:59:59 UTC)).is_err());
     /// ```
-    #[must_use]
-    pub const fn year(self) -> u16 {
-        self.date().year()
+    pub fn try_from_utc(dt: OffsetDateTime) -> Result<Self, ConvertError> {
+        if !dt.offset().is_utc() {
+            return Err(ConvertErrorKind::NotUtc.into());
+        }
+        let dt = Self::from_date_time(dt.date(), dt.time())?;
+        Ok(dt)
     }
 
-    /// Gets the month of this `DateTime`.
+    /// Creates a new `DateTime` with the given [`PrimitiveDateTime`], clamping
+    /// it to the range of MS-DOS date and time.
+    ///
+    /// Unlike [`DateTime::try_from`], this returns [`DateTime::MIN`] if `dt` is
+    /// before `1980-01-01 00:00:00`, and [`DateTime::MAX`] if `dt` is after
+    /// `2107-12-31 23:59:59`.
+    ///
+    /// <div class="warning">
+    ///
+    /// The resolution of MS-DOS date and time is 2 seconds. So this method
+    /// rounds towards zero, truncating any fractional part of the exact result
+    /// of dividing seconds by 2.
+    ///
+    /// </div>
     ///
     /// # Examples
     ///
     /// ```
-    /// # use dos_date_time::{DateTime, time::Month};
+    /// # use dos_date_time::{DateTime, time::macros::datetime};
     /// #
-    /// assert_eq!(DateTime::MIN.month(), Month::January);
-    /// assert_eq!(DateTime::MAX.month(), Month::December);
+    /// assert_eq!(
+    ///     DateTime::saturating_from_primitive(datetime!(1900-01-01 00:00:00)),
+    ///     DateTime::MIN
+    /// );
+    /// assert_eq!(
+    ///     DateTime::saturating_from_primitive(datetime!(2200-01-01 00:00:00)),
+    ///     DateTime::MAX
+    /// );
     /// ```
     #[must_use]
-    pub fn month(self) -> Month {
-        self.date().month()
+    pub fn saturating_from_primitive(dt: PrimitiveDateTime) -> Self {
+        Self::try_from(dt).unwrap_or_else(|err| match err.kind() {
+            DateTimeRangeErrorKind::Negative => Self::MIN,
+            DateTimeRangeErrorKind::Overflow => Self::MAX,
+        })
     }
 
-    /// Gets the day of this `DateTime`.
+    /// Creates a new `DateTime` with the given [Unix time] in seconds as a
+    /// floating-point number.
+    ///
+    /// The MS-DOS date and time do not have a time zone, so the result is in
+    /// UTC.
+    ///
+    /// <div class="warning">
+    ///
+    /// The resolution of MS-DOS date and time is 2 seconds. So this method
+    /// rounds towards zero, truncating any fractional part of the exact result
+    /// of dividing seconds by 2.
+    ///
+    /// </div>
+    ///
+    /// # Errors
+    ///
+    /// Returns [`Err`] if `secs` is NaN or infinite, or if `secs` is out of
+    /// range for MS-DOS date and time.
     ///
     /// # Examples
     ///
     /// ```
     /// # use dos_date_time::DateTime;
     /// #
-    /// assert_eq!(DateTime::MIN.day(), 1);
-    /// assert_eq!(DateTime::MAX.day(), 31);
+    /// assert_eq!(
+    ///     DateTime::from_unix_time_f64(315_532_800.0),
+    ///     Ok(DateTime::MIN)
+    /// );
+    /// assert_eq!(
+    ///     DateTime::from_unix_time_f64(4_354_819_199.9),
+    ///     Ok(DateTime::MAX)
+    /// );
+    ///
+    /// // NaN.
+    /// assert!(DateTime::from_unix_time_f64(f64::NAN).is_err());
+    /// // Before `1980-01-01 00:00:00`.
+    /// assert!(DateTime::from_unix_time_f64(0.0).is_err());
     /// ```
-    #[must_use]
-    pub fn day(self) -> u8 {
-        self.date().day()
+    ///
+    /// [Unix time]: https://en.wikipedia.org/wiki/Unix_time
+    pub fn from_unix_time_f64(secs: f64) -> Result<Self, ConvertError> {
+        if !secs.is_finite() {
+            return Err(ConvertErrorKind::NotFinite.into());
+        }
+        let dt = time::Duration::checked_seconds_f64(secs)
+            .and_then(|secs| OffsetDateTime::UNIX_EPOCH.checked_add(secs))
+            .ok_or_else(|| {
+                if secs.is_sign_negative() {
+                    ConvertErrorKind::Range(DateTimeRangeErrorKind::Negative)
+                } else {
+                    ConvertErrorKind::Range(DateTimeRangeErrorKind::Overflow)
+                }
+            })?;
+        Self::try_from_utc(dt)
     }
 
-    /// Gets the hour of this `DateTime`.
+    /// Creates a new `DateTime` with the given [`SystemTime`].
+    ///
+    /// The MS-DOS date and time do not have a time zone, so the result is in
+    /// UTC.
+    ///
+    /// <div class="warning">
+    ///
+    /// The resolution of MS-DOS date and time is 2 seconds. So this method
+    /// rounds towards zero, truncating any fractional part of the exact result
+    /// of dividing seconds by 2.
+    ///
+    /// </div>
+    ///
+    /// # Errors
+    ///
+    /// Returns [`Err`] if `st` is out of range for MS-DOS date and time.
     ///
     /// # Examples
     ///
     /// ```
+    /// # use std::time::{Duration, SystemTime};
+    /// #
     /// # use dos_date_time::DateTime;
     /// #
-    /// assert_eq!(DateTime::MIN.hour(), 0);
-    /// assert_eq!(DateTime::MAX.hour(), 23);
+    /// assert_eq!(
+    ///     DateTime::from_system_time(SystemTime::UNIX_EPOCH + Duration::from_secs(315_532_800)),
+    ///     Ok(DateTime::MIN)
+    /// );
+    ///
+    /// // Before `1980-01-01 00:00:00`.
+    /// assert!(DateTime::from_system_time(SystemTime::UNIX_EPOCH).is_err());
     /// ```
-    #[must_use]
-    pub fn hour(self) -> u8 {
-        self.time().hour()
-    }
-
-    /// Gets the minute of this `DateTime`.
     ///
-    /// # Examples
+    /// Combined with [`Metadata::modified`], this gets the last modification
+    /// time of a file as MS-DOS date and time:
     ///
-    /// ```
+    /// ```no_run
+    /// # use std::fs;
+    /// #
     /// # use dos_date_time::DateTime;
     /// #
-    /// assert_eq!(DateTime::MIN.minute(), 0);
-    /// assert_eq!(DateTime::MAX.minute(), 59);
+    /// let modified = fs::metadata("foo.txt")?.modified()?;
+    /// let dt = DateTime::from_system_time(modified)?;
+    /// # Ok::<_, Box<dyn std::error::Error>>(())
     /// ```
-    #[must_use]
-    pub fn minute(self) -> u8 {
-        self.time().minute()
+    ///
+    /// [`Metadata::modified`]: std::fs::Metadata::modified
+    #[cfg(feature = "std")]
+    pub fn from_system_time(st: SystemTime) -> Result<Self, DateTimeRangeError> {
+        let secs = st
+            .duration_since(SystemTime::UNIX_EPOCH)
+            .map_err(|_| DateTimeRangeErrorKind::Negative)?;
+        let dt = time::Duration::try_from(secs)
+            .ok()
+            .and_then(|secs| OffsetDateTime::UNIX_EPOCH.checked_add(secs))
+            .ok_or(DateTimeRangeErrorKind::Overflow)?;
+        Self::from_date_time(dt.date(), dt.time())
     }
 
-    /// Gets the second of this `DateTime`.
+    /// Creates a new `DateTime` by adding the given [`jiff::Span`] to
+    /// [`DateTime::MIN`], that is, `1980-01-01 00:00:00`.
+    ///
+    /// Calendar units of `span`, such as years and months, are resolved
+    /// relative to `1980-01-01`.
+    ///
+    /// <div class="warning">
+    ///
+    /// The resolution of MS-DOS date and time is 2 seconds. So this method
+    /// rounds towards zero, truncating any fractional part of the exact result
+    /// of dividing seconds by 2.
+    ///
+    /// </div>
+    ///
+    /// # Errors
+    ///
+    /// Returns [`Err`] if the result is out of range for MS-DOS date and time.
     ///
     /// # Examples
     ///
     /// ```
-    /// # use dos_date_time::DateTime;
+    /// # use dos_date_time::{
+    /// #     DateTime,
+    /// #     jiff::ToSpan,
+    /// #     time::macros::datetime,
+    /// # };
     /// #
-    /// assert_eq!(DateTime::MIN.second(), 0);
-    /// assert_eq!(DateTime::MAX.second(), 58);
+    /// assert_eq!(
+    ///     DateTime::from_dos_epoch_span(27.years().days(1).seconds(3)),
+    ///     Ok(DateTime::try_from(datetime!(2007-01-02 00:00:02)).unwrap())
+    /// );
+    ///
+    /// // Before `1980-01-01 00:00:00`.
+    /// assert!(DateTime::from_dos_epoch_span(-1.second()).is_err());
+    /// // After `2107-12-31 23:59:59`.
+    /// assert!(DateTime::from_dos_epoch_span(128.years()).is_err());
     /// ```
-    #[must_use]
-    pub fn second(self) -> u8 {
-        self.time().second()
+    #[cfg(feature = "jiff")]
+    pub fn from_dos_epoch_span(span: jiff::Span) -> Result<Self, DateTimeRangeError> {
+        let dt = jiff::civil::DateTime::from(Self::MIN)
+            .checked_add(span)
+            .map_err(|_| {
+                if span.is_negative() {
+                    DateTimeRangeErrorKind::Negative
+                } else {
+                    DateTimeRangeErrorKind::Overflow
+                }
+            })?;
+        dt.try_into()
     }
-}
 
-impl Default for DateTime {
-    /// Returns the default value of "1980-01-01 00:00:00".
+    #[allow(clippy::missing_panics_doc)]
+    /// Parses MS-DOS date and time from the given ASCII bytes.
     ///
-    /// Equivalent to [`DateTime::MIN`] except that it is not callable in const
-    /// contexts.
+    /// The input must be in the format `YYYY-MM-DD hh:mm:ss`, where the
+    /// separator between the date and the time is either a space or `T`.
+    ///
+    /// This method does not allocate.
+    ///
+    /// <div class="warning">
+    ///
+    /// The resolution of MS-DOS date and time is 2 seconds. So this method
+    /// rounds towards zero, truncating any fractional part of the exact result
+    /// of dividing seconds by 2.
+    ///
+    /// </div>
+    ///
+    /// # Errors
+    ///
+    /// Returns [`Err`] if `bytes` is not in the above format, or if it
+    /// represents invalid date and time or date and time out of range for
+    /// MS-DOS date and time.
     ///
     /// # Examples
     ///
     /// ```
     /// # use dos_date_time::DateTime;
     /// #
-    /// assert_eq!(DateTime::default(), DateTime::MIN);
-    /// ```
-    fn default() -> Self {
-        Self::MIN
-    }
-}
-
-#[cfg(test)]
-mod tests {
+    /// assert_eq!(
+    ///     DateTime::from_ascii(b"1980-01-01 00:00:00"),
+    ///     Ok(DateTime::MIN)
+    /// );
+    /// assert_eq!(
+    ///     DateTime::from_ascii(b"2107-12-31T23:59:58"),
+    ///     Ok(DateTime::MAX)
+    /// );
+    ///
+    /// // The separator is invalid.
+    /// assert!(DateTime::from_ascii(b"1980-01-01_00:00:00").is_err());
+    /// // Before `1980-01-01 00:00:00`.
+    /// assert!(DateTime::from_ascii(b"1979-12-31 23:59:59").is_err());
+    /// ```
+    pub fn from_ascii(bytes: &[u8]) -> Result<Self, ParseError> {
+        fn parse_number(digits: &[u8]) -> Result<u16, ParseErrorKind> {
+            digits
+                .iter()
+                .try_fold(u16::MIN, |acc, &digit| {
+                    digit
+                        .is_ascii_digit()
+                        .then(|| (acc * 10) + u16::from(digit - b'0'))
+                })
+                .ok_or(ParseErrorKind::InvalidNumber)
+        }
+
+        let bytes: &[u8; 19] = bytes
+            .try_into()
+            .map_err(|_| ParseErrorKind::InvalidLength)?;
+        if bytes[4] != b'-'
+            || bytes[7] != b'-'
+            || !matches!(bytes[10], b' ' | b'T')
+            || bytes[13] != b':'
+            || bytes[16] != b':'
+        {
+            return Err(ParseErrorKind::InvalidSeparator.into());
+        }
+        let (year, month, day, hour, minute, second) = (
+            parse_number(&bytes[..4])?,
+            parse_number(&bytes[5..7])?,
+            parse_number(&bytes[8..10])?,
+            parse_number(&bytes[11..13])?,
+            parse_number(&bytes[14..16])?,
+            parse_number(&bytes[17..])?,
+        );
+        let to_u8 = |n: u16| u8::try_from(n).expect("number should be in the range of `u8`");
+        let month = Month::try_from(to_u8(month)).map_err(|_| ParseErrorKind::InvalidComponent)?;
+        let date = time::Date::from_calendar_date(year.into(), month, to_u8(day))
+            .map_err(|_| ParseErrorKind::InvalidComponent)?;
+        let time = time::Time::from_hms(to_u8(hour), to_u8(minute), to_u8(second))
+            .map_err(|_| ParseErrorKind::InvalidComponent)?;
+        let dt = Self::from_date_time(date, time)?;
+        Ok(dt)
+    }
+
+    /// Parses MS-DOS date and time from the given pair of the raw MS-DOS date
+    /// and MS-DOS time separated by a comma, such as `"33,0"`.
+    ///
+    /// Each word may be written in decimal, in hexadecimal with the `0x`
+    /// prefix, or in binary with the `0b` prefix. Whitespace around each word
+    /// is ignored.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`Err`] if `s` is not in the above format, or if either word is
+    /// not valid as the MS-DOS date or the MS-DOS time.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use dos_date_time::DateTime;
+    /// #
+    /// assert_eq!(DateTime::from_raw_pair_str("33,0"), Ok(DateTime::MIN));
+    /// assert_eq!(
+    ///     DateTime::from_raw_pair_str("0xFF9F,0xBF7D"),
+    ///     Ok(DateTime::MAX)
+    /// );
+    ///
+    /// // The separator is missing.
+    /// assert!(DateTime::from_raw_pair_str("33").is_err());
+    /// // The Day field is 0.
+    /// assert!(DateTime::from_raw_pair_str("32,0").is_err());
+    /// ```
+    pub fn from_raw_pair_str(s: &str) -> Result<Self, ParseError> {
+        fn parse_word(word: &str) -> Result<u16, ParseErrorKind> {
+            let word = word.trim();
+            let (digits, radix) = word
+                .strip_prefix("0x")
+                .map(|digits| (digits, 16))
+                .or_else(|| word.strip_prefix("0b").map(|digits| (digits, 2)))
+                .unwrap_or((word, 10));
+            if digits.is_empty() || !digits.chars().all(|c| c.is_digit(radix)) {
+                return Err(ParseErrorKind::InvalidNumber);
+            }
+            u16::from_str_radix(digits, radix).map_err(|_| ParseErrorKind::InvalidNumber)
+        }
+
+        let (date, time) = s.split_once(',').ok_or(ParseErrorKind::InvalidSeparator)?;
+        let (date, time) = (parse_word(date)?, parse_word(time)?);
+        let date = Date::new(date).ok_or(ParseErrorKind::InvalidComponent)?;
+        let time = Time::new(time).ok_or(ParseErrorKind::InvalidComponent)?;
+        Ok(Self::new(date, time))
+    }
+
+    /// Parses MS-DOS date and time from the given key produced by
+    /// [`DateTime::to_base32_key`].
+    ///
+    /// The key is 7 characters of [Crockford's Base32] encoding
+    /// [`DateTime::to_sort_key`]. As in Crockford's Base32, lowercase letters
+    /// are accepted, and `I` and `L` are read as `1` and `O` as `0`.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`Err`] if `s` is not 7 characters long, if it contains a
+    /// character which is not in the alphabet, or if it does not represent
+    /// valid MS-DOS date and time.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use dos_date_time::DateTime;
+    /// #
+    /// assert_eq!(DateTime::from_base32_key("0022000"), Ok(DateTime::MIN));
+    /// assert_eq!(DateTime::from_base32_key("3ZSZFVX"), Ok(DateTime::MAX));
+    ///
+    /// // `U` is not in the alphabet.
+    /// assert!(DateTime::from_base32_key("002200U").is_err());
+    /// ```
+    ///
+    /// [Crockford's Base32]: https://www.crockford.com/base32.html
+    pub fn from_base32_key(s: &str) -> Result<Self, ParseError> {
+        if s.len() != 7 {
+            return Err(ParseErrorKind::InvalidLength.into());
+        }
+        let mut key = u64::MIN;
+        for c in s.bytes() {
+            let c = match c.to_ascii_uppercase() {
+                b'O' => b'0',
+                b'I' | b'L' => b'1',
+                c => c,
+            };
+            let digit = CROCKFORD_BASE32
+                .iter()
+                .position(|&d| d == c)
+                .ok_or(ParseErrorKind::InvalidNumber)?;
+            key = (key << 5) | digit as u64;
+        }
+        let key = u32::try_from(key).map_err(|_| ParseErrorKind::InvalidNumber)?;
+        let dt = Self::from_zip_u32(key).ok_or(ParseErrorKind::InvalidComponent)?;
+        Ok(dt)
+    }
+
+    /// Creates a new `DateTime` with the given [`DosWords`].
+    ///
+    /// This is the inverse of [`DateTime::to_words`].
+    ///
+    /// Returns [`None`] if the given MS-DOS date or MS-DOS time is not valid.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use dos_date_time::{DateTime, DosWords};
+    /// #
+    /// assert_eq!(
+    ///     DateTime::from_words(DosWords {
+    ///         date: 0b0000_0000_0010_0001,
+    ///         time: u16::MIN
+    ///     }),
+    ///     Some(DateTime::MIN)
+    /// );
+    ///
+    /// // The Day field is 0.
+    /// assert_eq!(
+    ///     DateTime::from_words(DosWords {
+    ///         date: 0b0000_0000_0010_0000,
+    ///         time: u16::MIN
+    ///     }),
+    ///     None
+    /// );
+    /// ```
+    #[must_use]
+    pub fn from_words(words: DosWords) -> Option<Self> {
+        let (date, time) = (Date::new(words.date)?, Time::new(words.time)?);
+        Some(Self::new(date, time))
+    }
+
+    /// Creates a new `DateTime` from the given 32-bit value in the layout used
+    /// by the ZIP file format, or [`None`] if it is not valid MS-DOS date and
+    /// time.
+    ///
+    /// The ZIP file format stores the last modification time and date of an
+    /// entry as adjacent little-endian [`u16`] values, time first. Read as a
+    /// single little-endian [`u32`] value, the MS-DOS time is therefore the
+    /// low word and the MS-DOS date is the high word. Values from tools which
+    /// put the date in the low word are rejected or misinterpreted, so use
+    /// [`DateTime::from_words`] to handle them explicitly.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use dos_date_time::DateTime;
+    /// #
+    /// assert_eq!(DateTime::from_zip_u32(0x0021_0000), Some(DateTime::MIN));
+    /// assert_eq!(DateTime::from_zip_u32(0xFF9F_BF7D), Some(DateTime::MAX));
+    ///
+    /// // The Day field is 0.
+    /// assert_eq!(DateTime::from_zip_u32(0x0020_0000), None);
+    /// ```
+    #[allow(clippy::cast_possible_truncation)]
+    #[must_use]
+    pub const fn from_zip_u32(value: u32) -> Option<Self> {
+        // Each word is 16 bits, and each field is at most 7 bits, so these never
+        // truncate.
+        let (date, time) = ((value >> 16) as u16, value as u16);
+        Self::try_from_parts(
+            1980 + (date >> 9),
+            ((date >> 5) & 0x0F) as u8,
+            (date & 0x1F) as u8,
+            (time >> 11) as u8,
+            ((time >> 5) & 0x3F) as u8,
+            (time & 0x1F) as u8 * 2,
+        )
+    }
+
+    /// Creates a new `DateTime` from the given index into the valid MS-DOS date
+    /// and time values.
+    ///
+    /// The index is a dense ordinal over the valid values in chronological
+    /// order, so `0` is [`DateTime::MIN`] and [`DateTime::INDEX_COUNT`] minus
+    /// one is [`DateTime::MAX`]. Unlike the raw bit patterns, there are no gaps
+    /// for invalid dates or times. This is the inverse of
+    /// [`DateTime::to_index`].
+    ///
+    /// Returns [`None`] if `index` is greater than or equal to
+    /// [`DateTime::INDEX_COUNT`].
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use dos_date_time::{DateTime, time::macros::datetime};
+    /// #
+    /// assert_eq!(DateTime::from_index(0), Some(DateTime::MIN));
+    /// assert_eq!(
+    ///     DateTime::from_index(1),
+    ///     Some(DateTime::try_from(datetime!(1980-01-01 00:00:02)).unwrap())
+    /// );
+    /// assert_eq!(
+    ///     DateTime::from_index(DateTime::INDEX_COUNT - 1),
+    ///     Some(DateTime::MAX)
+    /// );
+    ///
+    /// assert_eq!(DateTime::from_index(DateTime::INDEX_COUNT), None);
+    /// ```
+    #[must_use]
+    pub fn from_index(index: u32) -> Option<Self> {
+        let days = u16::try_from(index / 43200).ok()?;
+        let date = Date::from_days_since_epoch(days).ok()?;
+        let time = Time::from_second_of_day((index % 43200) * 2)?;
+        Some(Self::new(date, time))
+    }
+
+    /// Reads a `DateTime` from the front of the given byte cursor, and advances
+    /// the cursor past the bytes read.
+    ///
+    /// This reads 4 bytes: the MS-DOS time as a little-endian [`u16`] value,
+    /// followed by the MS-DOS date as a little-endian [`u16`] value. This is
+    /// the layout used by the [ZIP file format].
+    ///
+    /// The cursor is not advanced if an error is returned.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`Err`] if `cursor` has fewer than 4 bytes, or if the bytes are
+    /// not valid MS-DOS date and time.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use dos_date_time::DateTime;
+    /// #
+    /// let mut cursor = [0x00, 0x00, 0x21, 0x00, 0xFF].as_slice();
+    /// assert_eq!(DateTime::read_le(&mut cursor), Ok(DateTime::MIN));
+    /// assert_eq!(cursor, [0xFF]);
+    ///
+    /// // Not enough bytes.
+    /// assert!(DateTime::read_le(&mut cursor).is_err());
+    /// ```
+    ///
+    /// [ZIP file format]: https://pkwaredownloads.blob.core.windows.net/pem/APPNOTE.txt
+    pub fn read_le(cursor: &mut &[u8]) -> Result<Self, ReadError> {
+        let (&[time_lo, time_hi, date_lo, date_hi], rest) = cursor
+            .split_first_chunk()
+            .ok_or(ReadErrorKind::UnexpectedEof)?;
+        let (date, time) = (
+            Date::new(u16::from_le_bytes([date_lo, date_hi])),
+            Time::new(u16::from_le_bytes([time_lo, time_hi])),
+        );
+        let dt = date
+            .zip(time)
+            .map(|(date, time)| Self::new(date, time))
+            .ok_or(ReadErrorKind::InvalidBits)?;
+        *cursor = rest;
+        Ok(dt)
+    }
+
+    /// Reads `count` `DateTime` records from the given reader.
+    ///
+    /// Each record is 4 bytes in the same layout as [`DateTime::read_le`]: the
+    /// MS-DOS time as a little-endian [`u16`] value, followed by the MS-DOS
+    /// date as a little-endian [`u16`] value. This is the layout used by the
+    /// [ZIP file format].
+    ///
+    /// A record which is not valid MS-DOS date and time does not stop reading,
+    /// and is returned as [`Err`] in its position.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`Err`] if reading from `reader` fails, including if `reader`
+    /// ends before `count` records are read.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use dos_date_time::DateTime;
+    /// #
+    /// let mut reader = [0x00, 0x00, 0x21, 0x00, 0x00, 0x00, 0x20, 0x00].as_slice();
+    /// let records = DateTime::read_all_zip(&mut reader, 2).unwrap();
+    /// assert_eq!(records.len(), 2);
+    /// assert_eq!(records[0], Ok(DateTime::MIN));
+    /// // The Day field is 0.
+    /// assert!(records[1].is_err());
+    ///
+    /// // Not enough bytes.
+    /// assert!(DateTime::read_all_zip(&mut reader, 1).is_err());
+    /// ```
+    ///
+    /// [ZIP file format]: https://pkwaredownloads.blob.core.windows.net/pem/APPNOTE.txt
     #[cfg(feature = "std")]
-    use std::{
-        collections::hash_map::DefaultHasher,
-        hash::{Hash, Hasher},
-    };
+    pub fn read_all_zip<R: io::Read>(
+        reader: &mut R,
+        count: usize,
+    ) -> io::Result<Vec<Result<Self, InvalidBitsError>>> {
+        (0..count)
+            .map(|_| {
+                let mut buf = [u8::MIN; 4];
+                reader.read_exact(&mut buf)?;
+                let [time_lo, time_hi, date_lo, date_hi] = buf;
+                let (date, time) = (
+                    u16::from_le_bytes([date_lo, date_hi]),
+                    u16::from_le_bytes([time_lo, time_hi]),
+                );
+                Ok(Self::new_with_policy(date, time, ValidationPolicy::Strict))
+            })
+            .collect()
+    }
+
+    /// Writes this `DateTime` to the front of the given buffer, and returns the
+    /// number of bytes written.
+    ///
+    /// This writes 4 bytes in the same layout as [`DateTime::read_le`]: the
+    /// MS-DOS time as a little-endian [`u16`] value, followed by the MS-DOS
+    /// date as a little-endian [`u16`] value.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`Err`] if `out` is shorter than 4 bytes.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use dos_date_time::DateTime;
+    /// #
+    /// let mut buf = [u8::MIN; 5];
+    /// assert_eq!(DateTime::MAX.write_le(&mut buf), Ok(4));
+    /// assert_eq!(buf, [0x7D, 0xBF, 0x9F, 0xFF, 0x00]);
+    ///
+    /// // The buffer is too small.
+    /// assert!(DateTime::MAX.write_le(&mut [u8::MIN; 3]).is_err());
+    /// ```
+    pub fn write_le(self, out: &mut [u8]) -> Result<usize, BufferTooSmallError> {
+        const LEN: usize = 4;
+
+        let actual = out.len();
+        let (out, _) = out
+            .split_first_chunk_mut::<LEN>()
+            .ok_or_else(|| BufferTooSmallError::new(LEN, actual))?;
+        *out = self.to_zip_bytes();
+        Ok(LEN)
+    }
+
+    /// Returns the byte representation of this `DateTime` in the layout used
+    /// by the [ZIP file format].
+    ///
+    /// This is the MS-DOS time as a little-endian [`u16`] value, followed by
+    /// the MS-DOS date as a little-endian [`u16`] value, which is the same
+    /// layout as [`DateTime::read_le`] and [`DateTime::write_le`].
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use dos_date_time::DateTime;
+    /// #
+    /// assert_eq!(DateTime::MIN.to_zip_bytes(), [0x00, 0x00, 0x21, 0x00]);
+    /// assert_eq!(DateTime::MAX.to_zip_bytes(), [0x7D, 0xBF, 0x9F, 0xFF]);
+    /// ```
+    ///
+    /// [ZIP file format]: https://pkwaredownloads.blob.core.windows.net/pem/APPNOTE.txt
+    #[must_use]
+    pub const fn to_zip_bytes(self) -> [u8; 4] {
+        let (time, date) = (
+            self.time().to_raw().to_le_bytes(),
+            self.date().to_raw().to_le_bytes(),
+        );
+        [time[0], time[1], date[0], date[1]]
+    }
+
+    /// Returns the MS-DOS date and MS-DOS time of this `DateTime` as
+    /// [`DosWords`].
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use dos_date_time::{DateTime, DosWords};
+    /// #
+    /// assert_eq!(
+    ///     DateTime::MIN.to_words(),
+    ///     DosWords {
+    ///         date: 0b0000_0000_0010_0001,
+    ///         time: u16::MIN
+    ///     }
+    /// );
+    /// assert_eq!(
+    ///     DateTime::MAX.to_words(),
+    ///     DosWords {
+    ///         date: 0b1111_1111_1001_1111,
+    ///         time: 0b1011_1111_0111_1101
+    ///     }
+    /// );
+    /// ```
+    #[must_use]
+    pub const fn to_words(self) -> DosWords {
+        DosWords {
+            date: self.date().to_raw(),
+            time: self.time().to_raw(),
+        }
+    }
+
+    /// Returns the 32-bit unsigned integer value `(date << 16) | time` of this
+    /// `DateTime`, where `date` and `time` are the MS-DOS date and time.
+    ///
+    /// The order of the returned value is the chronological order, as
+    /// described in [`DateTime::MONOTONIC`], so this is suitable as a sort
+    /// key. This is the same layout as [`DateTime::from_zip_u32`], which is
+    /// the inverse of this method.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use dos_date_time::DateTime;
+    /// #
+    /// assert_eq!(DateTime::MIN.to_sort_key(), 0x0021_0000);
+    /// assert_eq!(DateTime::MAX.to_sort_key(), 0xFF9F_BF7D);
+    /// ```
+    #[must_use]
+    pub const fn to_sort_key(self) -> u32 {
+        ((self.date().to_raw() as u32) << 16) | self.time().to_raw() as u32
+    }
+
+    /// Gets the index of this `DateTime` into the valid MS-DOS date and time
+    /// values.
+    ///
+    /// See [`DateTime::from_index`] for the details of the index.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use dos_date_time::DateTime;
+    /// #
+    /// assert_eq!(DateTime::MIN.to_index(), 0);
+    /// assert_eq!(DateTime::MAX.to_index(), DateTime::INDEX_COUNT - 1);
+    /// ```
+    #[must_use]
+    pub fn to_index(self) -> u32 {
+        (u32::from(self.date().to_days_since_epoch()) * 43200) + (self.time().second_of_day() / 2)
+    }
+
+    #[allow(clippy::missing_panics_doc)]
+    /// Converts this `DateTime` to a [`PrimitiveDateTime`].
+    ///
+    /// This is equivalent to [`PrimitiveDateTime::from`], but is callable in
+    /// const contexts.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use dos_date_time::{
+    /// #     DateTime,
+    /// #     time::{PrimitiveDateTime, macros::datetime},
+    /// # };
+    /// #
+    /// const MIN: PrimitiveDateTime = DateTime::MIN.to_primitive();
+    /// assert_eq!(MIN, datetime!(1980-01-01 00:00:00));
+    /// assert_eq!(DateTime::MAX.to_primitive(), datetime!(2107-12-31 23:59:58));
+    /// ```
+    #[allow(clippy::cast_possible_truncation)]
+    #[must_use]
+    pub const fn to_primitive(self) -> PrimitiveDateTime {
+        let month = Month::January.nth_next(self.month0());
+        let Ok(date) = time::Date::from_calendar_date(self.year() as i32, month, self.day0() + 1)
+        else {
+            panic!("date should be in the range of `time::Date`");
+        };
+        let time = self.time().to_raw();
+        // The Hour field is 5 bits, the Minute field is 6 bits, and the
+        // DoubleSeconds field is 5 bits, so these never truncate.
+        let (hour, minute, second) = (
+            (time >> 11) as u8,
+            ((time >> 5) & 0x3F) as u8,
+            ((time & 0x1F) as u8) * 2,
+        );
+        let Ok(time) = time::Time::from_hms(hour, minute, second) else {
+            panic!("time should be valid");
+        };
+        PrimitiveDateTime::new(date, time)
+    }
+
+    /// Returns an iterator that yields `start`, `start + step`,
+    /// `start + 2 * step`, and so on, until the value exceeds
+    /// [`DateTime::MAX`].
+    ///
+    /// <div class="warning">
+    ///
+    /// The resolution of MS-DOS date and time is 2 seconds. So each value is
+    /// rounded towards zero, truncating any fractional part of the exact result
+    /// of dividing seconds by 2, and `step` shorter than 2 seconds is rounded
+    /// up to 2 seconds.
+    ///
+    /// </div>
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use core::time::Duration;
+    /// #
+    /// # use dos_date_time::{DateTime, time::macros::datetime};
+    /// #
+    /// let mut iter = DateTime::step_by(DateTime::MIN, Duration::from_secs(3));
+    /// assert_eq!(iter.next(), Some(DateTime::MIN));
+    /// assert_eq!(
+    ///     iter.next(),
+    ///     Some(DateTime::try_from(datetime!(1980-01-01 00:00:02)).unwrap())
+    /// );
+    /// assert_eq!(
+    ///     iter.next(),
+    ///     Some(DateTime::try_from(datetime!(1980-01-01 00:00:06)).unwrap())
+    /// );
+    ///
+    /// let mut iter = DateTime::step_by(DateTime::MAX, Duration::from_secs(2));
+    /// assert_eq!(iter.next(), Some(DateTime::MAX));
+    /// assert_eq!(iter.next(), None);
+    /// ```
+    pub fn step_by(start: Self, step: Duration) -> impl Iterator<Item = Self> {
+        let step = time::Duration::try_from(step.max(Duration::from_secs(2))).ok();
+        iter::successors(Some(PrimitiveDateTime::from(start)), move |dt| {
+            step.and_then(|step| dt.checked_add(step))
+        })
+        .map_while(|dt| Self::try_from(dt).ok())
+    }
+
+    /// Returns [`true`] if `self` is valid MS-DOS date and time, and [`false`]
+    /// otherwise.
+    #[must_use]
+    pub fn is_valid(self) -> bool {
+        self.date().is_valid() && self.time().is_valid()
+    }
+
+    /// Returns `Some(self)` if `self` is valid MS-DOS date and time, and
+    /// [`None`] otherwise.
+    ///
+    /// This is the same as [`DateTime::is_valid`], but returns the value for
+    /// chaining with [`Option::and_then`].
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use dos_date_time::{Date, DateTime, Time};
+    /// #
+    /// assert_eq!(DateTime::MIN.normalized(), Some(DateTime::MIN));
+    ///
+    /// let dt = DateTime::new(unsafe { Date::new_unchecked(u16::MAX) }, Time::MIN);
+    /// assert_eq!(dt.normalized(), None);
+    /// ```
+    #[must_use]
+    pub fn normalized(self) -> Option<Self> {
+        self.is_valid().then_some(self)
+    }
+
+    /// Returns [`None`] if `self` is valid MS-DOS date and time, and the error
+    /// describing why it is invalid otherwise.
+    ///
+    /// This is intended for logging a `DateTime` created from untrusted input,
+    /// such as with [`Date::new_unchecked`]. If both the date and the time are
+    /// invalid, the reason for the date is returned.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use dos_date_time::{Date, DateTime, Time, error::InvalidDateField};
+    /// #
+    /// assert_eq!(DateTime::MIN.invalid_reason(), None);
+    ///
+    /// // 1980-02-30.
+    /// let dt = DateTime::new(
+    ///     unsafe { Date::new_unchecked(0b0000_0000_0101_1110) },
+    ///     Time::MIN,
+    /// );
+    /// assert_eq!(
+    ///     dt.invalid_reason(),
+    ///     Some(InvalidDateField::DayTooLarge { day: 30, max: 29 }.into())
+    /// );
+    /// ```
+    #[must_use]
+    pub fn invalid_reason(self) -> Option<InvalidBitsError> {
+        let (date, time) = (self.date().to_raw(), self.time().to_raw());
+        Self::new_with_policy(date, time, ValidationPolicy::Strict).err()
+    }
+
+    /// Restricts this `DateTime` to the range `min..=max`.
+    ///
+    /// This is the same as [`Ord::clamp`]. `DateTime` has a total order, which
+    /// is the chronological order, so any two values can be compared.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `min > max`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use dos_date_time::{DateTime, time::macros::datetime};
+    /// #
+    /// let (min, max) = (
+    ///     DateTime::try_from(datetime!(2002-11-26 19:25:00)).unwrap(),
+    ///     DateTime::try_from(datetime!(2107-01-01 00:00:00)).unwrap(),
+    /// );
+    /// assert_eq!(DateTime::MIN.clamp(min, max), min);
+    /// assert_eq!(
+    ///     DateTime::try_from(datetime!(2018-11-17 10:38:30))
+    ///         .unwrap()
+    ///         .clamp(min, max),
+    ///     DateTime::try_from(datetime!(2018-11-17 10:38:30)).unwrap()
+    /// );
+    /// assert_eq!(DateTime::MAX.clamp(min, max), max);
+    /// ```
+    #[must_use]
+    pub fn clamp(self, min: Self, max: Self) -> Self {
+        assert!(min <= max, "`min` should be less than or equal to `max`");
+        Ord::clamp(self, min, max)
+    }
+
+    /// Restricts the year of this `DateTime` to the range
+    /// `min_year..=max_year`, keeping the month, the day, and the time.
+    ///
+    /// If the year is clamped to a non-leap year and the day is February 29,
+    /// the day is adjusted to February 28. A year outside the range of the
+    /// MS-DOS date saturates to `1980` or `2107`.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `min_year > max_year`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use dos_date_time::{DateTime, time::macros::datetime};
+    /// #
+    /// assert_eq!(
+    ///     DateTime::MAX.clamp_year(1990, 1999),
+    ///     DateTime::try_from(datetime!(1999-12-31 23:59:58)).unwrap()
+    /// );
+    /// assert_eq!(
+    ///     DateTime::try_from(datetime!(2000-02-29 12:34:56))
+    ///         .unwrap()
+    ///         .clamp_year(1990, 1999),
+    ///     DateTime::try_from(datetime!(1999-02-28 12:34:56)).unwrap()
+    /// );
+    /// ```
+    #[must_use]
+    pub fn clamp_year(self, min_year: u16, max_year: u16) -> Self {
+        assert!(
+            min_year <= max_year,
+            "`min_year` should be less than or equal to `max_year`"
+        );
+        let year = self.year().clamp(min_year, max_year).clamp(1980, 2107);
+        let date = self.date().to_raw();
+        let (month, day) = ((date >> 5) & 0x0F, date & 0x1F);
+        let day = day.min(Date::days_in_month(year, month));
+        self.with_date(Date::__from_calendar_date(year, month, day))
+    }
+
+    /// Returns the earliest and the latest `DateTime` in `iter`, or [`None`] if
+    /// `iter` is empty.
+    ///
+    /// This computes both in a single pass over `iter`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use dos_date_time::{DateTime, time::macros::datetime};
+    /// #
+    /// let dt = DateTime::try_from(datetime!(2018-11-17 10:38:30)).unwrap();
+    /// assert_eq!(
+    ///     DateTime::envelope([dt, DateTime::MAX, DateTime::MIN]),
+    ///     Some((DateTime::MIN, DateTime::MAX))
+    /// );
+    /// assert_eq!(DateTime::envelope([dt]), Some((dt, dt)));
+    ///
+    /// assert_eq!(DateTime::envelope([]), None);
+    /// ```
+    pub fn envelope<I: IntoIterator<Item = Self>>(iter: I) -> Option<(Self, Self)> {
+        let mut iter = iter.into_iter();
+        let first = iter.next()?;
+        Some(iter.fold((first, first), |(min, max), dt| (min.min(dt), max.max(dt))))
+    }
+
+    /// Binary searches `sorted` for `target`.
+    ///
+    /// This is a wrapper over [`slice::binary_search`]. The [`Ord`] order of
+    /// `DateTime` is the same as the chronological order, so `sorted` can be
+    /// sorted with [`slice::sort`].
+    ///
+    /// # Errors
+    ///
+    /// Returns [`Err`] with the index where `target` could be inserted while
+    /// keeping `sorted` sorted if `target` is not found.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use dos_date_time::{DateTime, time::macros::datetime};
+    /// #
+    /// let dt = DateTime::try_from(datetime!(2018-11-17 10:38:30)).unwrap();
+    /// let sorted = [DateTime::MIN, dt, DateTime::MAX];
+    /// assert_eq!(DateTime::bisect(&sorted, dt), Ok(1));
+    ///
+    /// let dt = DateTime::try_from(datetime!(2002-11-26 19:25:00)).unwrap();
+    /// assert_eq!(DateTime::bisect(&sorted, dt), Err(1));
+    /// ```
+    pub fn bisect(sorted: &[Self], target: Self) -> Result<usize, usize> {
+        sorted.binary_search(&target)
+    }
+
+    /// Returns the signed duration from `self` to `other`.
+    ///
+    /// The returned value is negative if `other` is before `self`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use dos_date_time::{
+    /// #     DateTime,
+    /// #     time::{Duration, macros::datetime},
+    /// # };
+    /// #
+    /// assert_eq!(
+    ///     DateTime::MIN.duration_until(datetime!(1980-01-02 00:00:00)),
+    ///     Duration::DAY
+    /// );
+    /// assert_eq!(
+    ///     DateTime::MIN.duration_until(datetime!(1979-12-31 00:00:00)),
+    ///     -Duration::DAY
+    /// );
+    /// ```
+    #[must_use]
+    pub fn duration_until(self, other: PrimitiveDateTime) -> time::Duration {
+        other - PrimitiveDateTime::from(self)
+    }
+
+    /// Returns the signed duration from `self` to `reference`.
+    ///
+    /// This is equivalent to `reference - self`. The returned value is
+    /// negative if `reference` is before `self`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use dos_date_time::{
+    /// #     DateTime,
+    /// #     time::{Duration, macros::datetime},
+    /// # };
+    /// #
+    /// let dt = DateTime::try_from(datetime!(2018-11-17 10:38:30)).unwrap();
+    /// let now = DateTime::try_from(datetime!(2018-11-20 10:38:30)).unwrap();
+    /// assert_eq!(dt.age_from(now), Duration::days(3));
+    /// assert_eq!(now.age_from(dt), -Duration::days(3));
+    /// ```
+    #[must_use]
+    pub fn age_from(self, reference: Self) -> time::Duration {
+        reference - self
+    }
+
+    /// Returns [`true`] if `self` and `other` are at most `tolerance` apart.
+    ///
+    /// This compares the absolute difference between `self` and `other`, so
+    /// the order of the arguments does not matter. It is useful for
+    /// deduplicating timestamps from sources which round differently.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use core::time::Duration;
+    /// #
+    /// # use dos_date_time::{DateTime, time::macros::datetime};
+    /// #
+    /// let dt = DateTime::try_from(datetime!(2018-11-17 10:38:30)).unwrap();
+    /// let other = DateTime::try_from(datetime!(2018-11-17 10:38:32)).unwrap();
+    /// assert!(dt.approx_eq(other, Duration::from_secs(3)));
+    /// assert!(other.approx_eq(dt, Duration::from_secs(2)));
+    ///
+    /// assert!(!dt.approx_eq(other, Duration::from_secs(1)));
+    /// ```
+    #[must_use]
+    pub fn approx_eq(self, other: Self, tolerance: Duration) -> bool {
+        (self - other).unsigned_abs() <= tolerance
+    }
+
+    /// Computes `self + duration`, returning [`None`] if the result is out of
+    /// range for MS-DOS date and time.
+    ///
+    /// `duration` may be negative, in which case this moves backward.
+    ///
+    /// <div class="warning">
+    ///
+    /// The resolution of MS-DOS date and time is 2 seconds. So this method
+    /// rounds towards zero, truncating any fractional part of the exact result
+    /// of dividing seconds by 2.
+    ///
+    /// </div>
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use dos_date_time::{
+    /// #     DateTime,
+    /// #     time::{Duration, macros::datetime},
+    /// # };
+    /// #
+    /// let dt = DateTime::try_from(datetime!(2018-11-17 10:38:30)).unwrap();
+    /// assert_eq!(
+    ///     dt.checked_add(Duration::hours(1)),
+    ///     Some(DateTime::try_from(datetime!(2018-11-17 11:38:30)).unwrap())
+    /// );
+    /// assert_eq!(
+    ///     dt.checked_add(-Duration::hours(1)),
+    ///     Some(DateTime::try_from(datetime!(2018-11-17 09:38:30)).unwrap())
+    /// );
+    ///
+    /// assert_eq!(DateTime::MAX.checked_add(Duration::SECOND * 2), None);
+    /// assert_eq!(DateTime::MIN.checked_add(-Duration::SECOND), None);
+    /// ```
+    #[must_use]
+    pub fn checked_add(self, duration: time::Duration) -> Option<Self> {
+        PrimitiveDateTime::from(self)
+            .checked_add(duration)
+            .and_then(|dt| Self::try_from(dt).ok())
+    }
+
+    /// Computes `self - duration`, returning [`None`] if the result is out of
+    /// range for MS-DOS date and time.
+    ///
+    /// `duration` may be negative, in which case this moves forward.
+    ///
+    /// <div class="warning">
+    ///
+    /// The resolution of MS-DOS date and time is 2 seconds. So this method
+    /// rounds towards zero, truncating any fractional part of the exact result
+    /// of dividing seconds by 2.
+    ///
+    /// </div>
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use dos_date_time::{
+    /// #     DateTime,
+    /// #     time::{Duration, macros::datetime},
+    /// # };
+    /// #
+    /// let dt = DateTime::try_from(datetime!(2018-11-17 10:38:30)).unwrap();
+    /// assert_eq!(
+    ///     dt.checked_sub(Duration::hours(1)),
+    ///     Some(DateTime::try_from(datetime!(2018-11-17 09:38:30)).unwrap())
+    /// );
+    ///
+    /// assert_eq!(DateTime::MIN.checked_sub(Duration::SECOND * 2), None);
+    /// assert_eq!(DateTime::MAX.checked_sub(-Duration::SECOND * 2), None);
+    /// ```
+    #[must_use]
+    pub fn checked_sub(self, duration: time::Duration) -> Option<Self> {
+        PrimitiveDateTime::from(self)
+            .checked_sub(duration)
+            .and_then(|dt| Self::try_from(dt).ok())
+    }
+
+    /// Computes `self + duration`, saturating at [`DateTime::MIN`] or
+    /// [`DateTime::MAX`] if the result is out of range for MS-DOS date and
+    /// time.
+    ///
+    /// `duration` may be negative, in which case this moves backward.
+    ///
+    /// <div class="warning">
+    ///
+    /// The resolution of MS-DOS date and time is 2 seconds. So this method
+    /// rounds towards zero, truncating any fractional part of the exact result
+    /// of dividing seconds by 2.
+    ///
+    /// </div>
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use dos_date_time::{
+    /// #     DateTime,
+    /// #     time::{Duration, macros::datetime},
+    /// # };
+    /// #
+    /// let dt = DateTime::try_from(datetime!(2018-11-17 10:38:30)).unwrap();
+    /// assert_eq!(
+    ///     dt.saturating_add(-Duration::hours(1)),
+    ///     DateTime::try_from(datetime!(2018-11-17 09:38:30)).unwrap()
+    /// );
+    ///
+    /// assert_eq!(DateTime::MAX.saturating_add(Duration::DAY), DateTime::MAX);
+    /// assert_eq!(DateTime::MIN.saturating_add(-Duration::DAY), DateTime::MIN);
+    /// ```
+    #[must_use]
+    pub fn saturating_add(self, duration: time::Duration) -> Self {
+        Self::saturating_from_primitive(PrimitiveDateTime::from(self).saturating_add(duration))
+    }
+
+    /// Computes `self - duration`, saturating at [`DateTime::MIN`] or
+    /// [`DateTime::MAX`] if the result is out of range for MS-DOS date and
+    /// time.
+    ///
+    /// `duration` may be negative, in which case this moves forward.
+    ///
+    /// <div class="warning">
+    ///
+    /// The resolution of MS-DOS date and time is 2 seconds. So this method
+    /// rounds towards zero, truncating any fractional part of the exact result
+    /// of dividing seconds by 2.
+    ///
+    /// </div>
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use dos_date_time::{
+    /// #     DateTime,
+    /// #     time::{Duration, macros::datetime},
+    /// # };
+    /// #
+    /// let dt = DateTime::try_from(datetime!(2018-11-17 10:38:30)).unwrap();
+    /// assert_eq!(
+    ///     dt.saturating_sub(Duration::hours(1)),
+    ///     DateTime::try_from(datetime!(2018-11-17 09:38:30)).unwrap()
+    /// );
+    ///
+    /// assert_eq!(DateTime::MIN.saturating_sub(Duration::DAY), DateTime::MIN);
+    /// assert_eq!(DateTime::MAX.saturating_sub(-Duration::DAY), DateTime::MAX);
+    /// ```
+    #[must_use]
+    pub fn saturating_sub(self, duration: time::Duration) -> Self {
+        Self::saturating_from_primitive(PrimitiveDateTime::from(self).saturating_sub(duration))
+    }
+
+    /// Returns the `DateTime` 2 seconds later, or [`None`] if this `DateTime`
+    /// is [`DateTime::MAX`].
+    ///
+    /// The resolution of MS-DOS date and time is 2 seconds, so this is the
+    /// next representable `DateTime`. This does not use the [`time`] crate,
+    /// so it is callable in const contexts.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use dos_date_time::{DateTime, time::macros::datetime};
+    /// #
+    /// assert_eq!(
+    ///     DateTime::MIN.checked_next(),
+    ///     Some(DateTime::try_from(datetime!(1980-01-01 00:00:02)).unwrap())
+    /// );
+    /// assert_eq!(
+    ///     DateTime::try_from(datetime!(2001-12-31 23:59:58))
+    ///         .unwrap()
+    ///         .checked_next(),
+    ///     Some(DateTime::try_from(datetime!(2002-01-01 00:00:00)).unwrap())
+    /// );
+    /// assert_eq!(DateTime::MAX.checked_next(), None);
+    /// ```
+    #[must_use]
+    pub const fn checked_next(self) -> Option<Self> {
+        let time = self.time().to_raw();
+        let (hour, minute, double_seconds) = (time >> 11, (time >> 5) & 0x3F, time & 0x1F);
+        let time = if double_seconds < 29 {
+            time + 1
+        } else if minute < 59 {
+            (time & 0xFFE0) + (1 << 5)
+        } else if hour < 23 {
+            (hour + 1) << 11
+        } else {
+            return match self.date().next_day() {
+                Some(date) => Some(Self::new(date, Time::MIN)),
+                None => None,
+            };
+        };
+        // SAFETY: `time` is a valid as the MS-DOS time.
+        Some(self.with_time(unsafe { Time::new_unchecked(time) }))
+    }
+
+    /// Returns the `DateTime` 2 seconds earlier, or [`None`] if this
+    /// `DateTime` is [`DateTime::MIN`].
+    ///
+    /// The resolution of MS-DOS date and time is 2 seconds, so this is the
+    /// previous representable `DateTime`. This does not use the [`time`]
+    /// crate, so it is callable in const contexts.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use dos_date_time::{DateTime, time::macros::datetime};
+    /// #
+    /// assert_eq!(DateTime::MIN.checked_prev(), None);
+    /// assert_eq!(
+    ///     DateTime::try_from(datetime!(2002-01-01 00:00:00))
+    ///         .unwrap()
+    ///         .checked_prev(),
+    ///     Some(DateTime::try_from(datetime!(2001-12-31 23:59:58)).unwrap())
+    /// );
+    /// assert_eq!(
+    ///     DateTime::MAX.checked_prev(),
+    ///     Some(DateTime::try_from(datetime!(2107-12-31 23:59:56)).unwrap())
+    /// );
+    /// ```
+    #[must_use]
+    pub const fn checked_prev(self) -> Option<Self> {
+        let time = self.time().to_raw();
+        let (hour, minute, double_seconds) = (time >> 11, (time >> 5) & 0x3F, time & 0x1F);
+        let time = if double_seconds > 0 {
+            time - 1
+        } else if minute > 0 {
+            (time & 0xFFE0) - (1 << 5) + 29
+        } else if hour > 0 {
+            ((hour - 1) << 11) + (59 << 5) + 29
+        } else {
+            return match self.date().previous_day() {
+                Some(date) => Some(Self::new(date, Time::MAX)),
+                None => None,
+            };
+        };
+        // SAFETY: `time` is a valid as the MS-DOS time.
+        Some(self.with_time(unsafe { Time::new_unchecked(time) }))
+    }
+
+    /// Returns the `DateTime` 2 seconds later, saturating at
+    /// [`DateTime::MAX`].
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use dos_date_time::{DateTime, time::macros::datetime};
+    /// #
+    /// assert_eq!(
+    ///     DateTime::MIN.saturating_next(),
+    ///     DateTime::try_from(datetime!(1980-01-01 00:00:02)).unwrap()
+    /// );
+    ///
+    /// assert_eq!(DateTime::MAX.saturating_next(), DateTime::MAX);
+    /// ```
+    #[must_use]
+    pub const fn saturating_next(self) -> Self {
+        match self.checked_next() {
+            Some(dt) => dt,
+            None => Self::MAX,
+        }
+    }
+
+    /// Returns the `DateTime` 2 seconds earlier, saturating at
+    /// [`DateTime::MIN`].
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use dos_date_time::{DateTime, time::macros::datetime};
+    /// #
+    /// assert_eq!(
+    ///     DateTime::MAX.saturating_prev(),
+    ///     DateTime::try_from(datetime!(2107-12-31 23:59:56)).unwrap()
+    /// );
+    ///
+    /// assert_eq!(DateTime::MIN.saturating_prev(), DateTime::MIN);
+    /// ```
+    #[must_use]
+    pub const fn saturating_prev(self) -> Self {
+        match self.checked_prev() {
+            Some(dt) => dt,
+            None => Self::MIN,
+        }
+    }
+
+    /// Computes `self + days`, returning [`None`] if the result is out of range
+    /// for MS-DOS date and time.
+    ///
+    /// `days` may be negative, in which case this moves backward. The [`Time`]
+    /// of this `DateTime` is kept.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use dos_date_time::{DateTime, time::macros::datetime};
+    /// #
+    /// assert_eq!(
+    ///     DateTime::MIN.checked_add_days(31),
+    ///     Some(DateTime::try_from(datetime!(1980-02-01 00:00:00)).unwrap())
+    /// );
+    ///
+    /// assert_eq!(DateTime::MAX.checked_add_days(1), None);
+    /// assert_eq!(DateTime::MIN.checked_add_days(-1), None);
+    /// ```
+    #[must_use]
+    pub fn checked_add_days(self, days: i32) -> Option<Self> {
+        self.date()
+            .checked_add_days(days)
+            .map(|date| self.with_date(date))
+    }
+
+    /// Computes `self - days`, returning [`None`] if the result is out of range
+    /// for MS-DOS date and time.
+    ///
+    /// `days` may be negative, in which case this moves forward. The [`Time`]
+    /// of this `DateTime` is kept.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use dos_date_time::{DateTime, time::macros::datetime};
+    /// #
+    /// assert_eq!(
+    ///     DateTime::MAX.checked_sub_days(31),
+    ///     Some(DateTime::try_from(datetime!(2107-11-30 23:59:58)).unwrap())
+    /// );
+    ///
+    /// assert_eq!(DateTime::MIN.checked_sub_days(1), None);
+    /// assert_eq!(DateTime::MAX.checked_sub_days(-1), None);
+    /// ```
+    #[must_use]
+    pub fn checked_sub_days(self, days: i32) -> Option<Self> {
+        self.date()
+            .checked_sub_days(days)
+            .map(|date| self.with_date(date))
+    }
+
+    /// Computes `self + months`, returning [`None`] if the result is out of
+    /// range for MS-DOS date and time.
+    ///
+    /// `months` may be negative, in which case this moves backward. The day of
+    /// the month is clamped to the last day of the month if the resulting
+    /// month is shorter. The [`Time`] of this `DateTime` is kept.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use dos_date_time::{DateTime, time::macros::datetime};
+    /// #
+    /// assert_eq!(
+    ///     DateTime::MIN.checked_add_months(1),
+    ///     Some(DateTime::try_from(datetime!(1980-02-01 00:00:00)).unwrap())
+    /// );
+    ///
+    /// assert_eq!(DateTime::MAX.checked_add_months(1), None);
+    /// assert_eq!(DateTime::MIN.checked_add_months(-1), None);
+    /// ```
+    #[must_use]
+    pub fn checked_add_months(self, months: i32) -> Option<Self> {
+        self.date()
+            .checked_add_months(months)
+            .map(|date| self.with_date(date))
+    }
+
+    /// Computes `self - months`, returning [`None`] if the result is out of
+    /// range for MS-DOS date and time.
+    ///
+    /// `months` may be negative, in which case this moves forward. The day of
+    /// the month is clamped to the last day of the month if the resulting
+    /// month is shorter. The [`Time`] of this `DateTime` is kept.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use dos_date_time::{DateTime, time::macros::datetime};
+    /// #
+    /// assert_eq!(
+    ///     DateTime::MAX.checked_sub_months(1),
+    ///     Some(DateTime::try_from(datetime!(2107-11-30 23:59:58)).unwrap())
+    /// );
+    ///
+    /// assert_eq!(DateTime::MIN.checked_sub_months(1), None);
+    /// assert_eq!(DateTime::MAX.checked_sub_months(-1), None);
+    /// ```
+    #[must_use]
+    pub fn checked_sub_months(self, months: i32) -> Option<Self> {
+        self.date()
+            .checked_sub_months(months)
+            .map(|date| self.with_date(date))
+    }
+
+    /// Gets the [`Date`] of this `DateTime`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use dos_date_time::{Date, DateTime};
+    /// #
+    /// assert_eq!(DateTime::MIN.date(), Date::MIN);
+    /// assert_eq!(DateTime::MAX.date(), Date::MAX);
+    /// ```
+    #[must_use]
+    pub const fn date(self) -> Date {
+        self.date
+    }
+
+    /// Gets the [`Time`] of this `DateTime`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use dos_date_time::{DateTime, Time};
+    /// #
+    /// assert_eq!(DateTime::MIN.time(), Time::MIN);
+    /// assert_eq!(DateTime::MAX.time(), Time::MAX);
+    /// ```
+    #[must_use]
+    pub const fn time(self) -> Time {
+        self.time
+    }
+
+    /// Returns the [`Date`] and the [`Time`] of this `DateTime` as a tuple.
+    ///
+    /// The hash of the returned tuple is the same as the hash of this
+    /// `DateTime`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use dos_date_time::{Date, DateTime, Time};
+    /// #
+    /// assert_eq!(DateTime::MIN.as_tuple(), (Date::MIN, Time::MIN));
+    /// assert_eq!(DateTime::MAX.as_tuple(), (Date::MAX, Time::MAX));
+    /// ```
+    #[must_use]
+    pub const fn as_tuple(self) -> (Date, Time) {
+        (self.date(), self.time())
+    }
+
+    /// Returns a `DateTime` with the [`Date`] of this `DateTime` replaced by
+    /// `date`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use dos_date_time::{Date, DateTime};
+    /// #
+    /// assert_eq!(
+    ///     DateTime::MIN.with_date(Date::MAX),
+    ///     DateTime::new(Date::MAX, DateTime::MIN.time())
+    /// );
+    /// ```
+    #[must_use]
+    pub const fn with_date(self, date: Date) -> Self {
+        Self::new(date, self.time())
+    }
+
+    /// Returns a `DateTime` with the [`Time`] of this `DateTime` replaced by
+    /// `time`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use dos_date_time::{DateTime, Time};
+    /// #
+    /// assert_eq!(
+    ///     DateTime::MIN.with_time(Time::MAX),
+    ///     DateTime::new(DateTime::MIN.date(), Time::MAX)
+    /// );
+    /// ```
+    #[must_use]
+    pub const fn with_time(self, time: Time) -> Self {
+        Self::new(self.date(), time)
+    }
+
+    /// Returns a `DateTime` with the [`Time`] of this `DateTime` replaced by
+    /// the time `secs` seconds after midnight, or [`None`] if `secs` is not
+    /// less than 86400.
+    ///
+    /// This is equivalent to [`Time::from_second_of_day`] followed by
+    /// [`DateTime::with_time`].
+    ///
+    /// <div class="warning">
+    ///
+    /// The resolution of MS-DOS time is 2 seconds. So this method rounds `secs`
+    /// down to an even number of seconds.
+    ///
+    /// </div>
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use dos_date_time::{DateTime, Time, time::macros::datetime};
+    /// #
+    /// assert_eq!(
+    ///     DateTime::MIN.with_time_from_secs(37110),
+    ///     Some(DateTime::try_from(datetime!(1980-01-01 10:18:30)).unwrap())
+    /// );
+    /// assert_eq!(
+    ///     DateTime::MIN.with_time_from_secs(86399),
+    ///     Some(DateTime::new(DateTime::MIN.date(), Time::MAX))
+    /// );
+    ///
+    /// assert_eq!(DateTime::MIN.with_time_from_secs(86400), None);
+    /// ```
+    #[must_use]
+    pub fn with_time_from_secs(self, secs: u32) -> Option<Self> {
+        Time::from_second_of_day(secs).map(|time| self.with_time(time))
+    }
+
+    /// Returns a `DateTime` with the [`Date`] of this `DateTime` replaced by
+    /// the given year and ordinal day, keeping the [`Time`].
+    ///
+    /// This is equivalent to [`Date::from_ordinal_date`] followed by
+    /// [`DateTime::with_date`].
+    ///
+    /// # Errors
+    ///
+    /// Returns [`Err`] if `ordinal` is not a valid ordinal day in `year`, or if
+    /// the date is out of range for the MS-DOS date.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use dos_date_time::{DateTime, time::macros::datetime};
+    /// #
+    /// assert_eq!(
+    ///     DateTime::MAX.with_date_from_ordinal(1980, 1),
+    ///     Ok(DateTime::try_from(datetime!(1980-01-01 23:59:58)).unwrap())
+    /// );
+    ///
+    /// // `2107` is not a leap year.
+    /// assert!(DateTime::MIN.with_date_from_ordinal(2107, 366).is_err());
+    /// ```
+    pub fn with_date_from_ordinal(self, year: u16, ordinal: u16) -> Result<Self, DateError> {
+        Date::from_ordinal_date(year, ordinal).map(|date| self.with_date(date))
+    }
+
+    /// Returns a `DateTime` with the [`Time`] of this `DateTime` replaced by
+    /// [`Time::MIN`], which is "00:00:00".
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use dos_date_time::{DateTime, time::macros::datetime};
+    /// #
+    /// assert_eq!(
+    ///     DateTime::MAX.start_of_day(),
+    ///     DateTime::try_from(datetime!(2107-12-31 00:00:00)).unwrap()
+    /// );
+    /// ```
+    #[must_use]
+    pub const fn start_of_day(self) -> Self {
+        self.with_time(Time::MIN)
+    }
+
+    /// Returns a `DateTime` with the [`Time`] of this `DateTime` replaced by
+    /// [`Time::MAX`], which is "23:59:58".
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use dos_date_time::{DateTime, time::macros::datetime};
+    /// #
+    /// assert_eq!(
+    ///     DateTime::MIN.end_of_day(),
+    ///     DateTime::try_from(datetime!(1980-01-01 23:59:58)).unwrap()
+    /// );
+    /// ```
+    #[must_use]
+    pub const fn end_of_day(self) -> Self {
+        self.with_time(Time::MAX)
+    }
+
+    /// Returns a `DateTime` with the [`Date`] of this `DateTime` replaced by
+    /// that of `other`.
+    ///
+    /// This is equivalent to `self.with_date(other.date())`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use dos_date_time::{Date, DateTime, Time};
+    /// #
+    /// assert_eq!(
+    ///     DateTime::MIN.with_date_of(DateTime::MAX),
+    ///     DateTime::new(Date::MAX, Time::MIN)
+    /// );
+    /// ```
+    #[must_use]
+    pub const fn with_date_of(self, other: Self) -> Self {
+        self.with_date(other.date())
+    }
+
+    /// Returns a `DateTime` with the [`Time`] of this `DateTime` replaced by
+    /// that of `other`.
+    ///
+    /// This is equivalent to `self.with_time(other.time())`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use dos_date_time::{Date, DateTime, Time};
+    /// #
+    /// assert_eq!(
+    ///     DateTime::MIN.with_time_of(DateTime::MAX),
+    ///     DateTime::new(Date::MIN, Time::MAX)
+    /// );
+    /// ```
+    #[must_use]
+    pub const fn with_time_of(self, other: Self) -> Self {
+        self.with_time(other.time())
+    }
+
+    /// Replaces the [`Date`] of this `DateTime` with `date` in place.
+    ///
+    /// This is the in-place equivalent of [`DateTime::with_date`].
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use dos_date_time::{Date, DateTime, Time};
+    /// #
+    /// let mut dt = DateTime::MIN;
+    /// dt.set_date(Date::MAX);
+    /// assert_eq!(dt, DateTime::new(Date::MAX, Time::MIN));
+    /// ```
+    pub const fn set_date(&mut self, date: Date) {
+        self.date = date;
+    }
+
+    /// Replaces the [`Time`] of this `DateTime` with `time` in place.
+    ///
+    /// This is the in-place equivalent of [`DateTime::with_time`].
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use dos_date_time::{Date, DateTime, Time};
+    /// #
+    /// let mut dt = DateTime::MIN;
+    /// dt.set_time(Time::MAX);
+    /// assert_eq!(dt, DateTime::new(Date::MIN, Time::MAX));
+    /// ```
+    pub const fn set_time(&mut self, time: Time) {
+        self.time = time;
+    }
+
+    /// Returns a `DateTime` with the year of this `DateTime` replaced by
+    /// `year`, keeping the month, the day, and the time.
+    ///
+    /// This only rewrites the Year field of the MS-DOS date, so it is faster
+    /// than [`Date::with_year`].
+    ///
+    /// Returns [`None`] if `year` is out of range for the MS-DOS date, or if
+    /// the day of this `DateTime` is not a valid day of the month in `year`,
+    /// such as February 29 in a non-leap year.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use dos_date_time::{DateTime, time::macros::datetime};
+    /// #
+    /// let dt = DateTime::try_from(datetime!(2000-02-29 12:34:56)).unwrap();
+    /// assert_eq!(
+    ///     dt.checked_set_year(2004),
+    ///     Some(DateTime::try_from(datetime!(2004-02-29 12:34:56)).unwrap())
+    /// );
+    ///
+    /// // `2001` is not a leap year.
+    /// assert_eq!(dt.checked_set_year(2001), None);
+    /// // After `2107-12-31`.
+    /// assert_eq!(dt.checked_set_year(2108), None);
+    /// ```
+    #[must_use]
+    pub const fn checked_set_year(self, year: u16) -> Option<Self> {
+        if year < 1980 || year > 2107 {
+            return None;
+        }
+        let date = self.date().to_raw();
+        let (month, day) = ((date >> 5) & 0x0F, date & 0x1F);
+        if day > Date::days_in_month(year, month) {
+            return None;
+        }
+        let date = ((year - 1980) << 9) | (date & 0x01FF);
+        // SAFETY: `date` is a valid as the MS-DOS date.
+        let date = unsafe { Date::new_unchecked(date) };
+        Some(self.with_date(date))
+    }
+
+    /// Gets the year of this `DateTime`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use dos_date_time::DateTime;
+    /// #
+    /// assert_eq!(DateTime::MIN.year(), 1980);
+    /// assert_eq!(DateTime::MAX.year(), 2107);
+    /// ```
+    #[must_use]
+    pub const fn year(self) -> u16 {
+        self.date().year()
+    }
+
+    /// Gets the month of this `DateTime`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use dos_date_time::{DateTime, time::Month};
+    /// #
+    /// assert_eq!(DateTime::MIN.month(), Month::January);
+    /// assert_eq!(DateTime::MAX.month(), Month::December);
+    /// ```
+    #[must_use]
+    pub fn month(self) -> Month {
+        self.date().month()
+    }
+
+    /// Gets the day of this `DateTime`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use dos_date_time::DateTime;
+    /// #
+    /// assert_eq!(DateTime::MIN.day(), 1);
+    /// assert_eq!(DateTime::MAX.day(), 31);
+    /// ```
+    #[must_use]
+    pub fn day(self) -> u8 {
+        self.date().day()
+    }
+
+    /// Gets the zero-based month of this `DateTime`.
+    ///
+    /// The returned value is in the range `0..=11`. See [`Date::month0`] for
+    /// more details.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use dos_date_time::DateTime;
+    /// #
+    /// assert_eq!(DateTime::MIN.month0(), 0);
+    /// assert_eq!(DateTime::MAX.month0(), 11);
+    /// ```
+    #[must_use]
+    pub const fn month0(self) -> u8 {
+        self.date().month0()
+    }
+
+    /// Gets the zero-based day of the month of this `DateTime`.
+    ///
+    /// The returned value is in the range `0..=30`. See [`Date::day0`] for
+    /// more details.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use dos_date_time::DateTime;
+    /// #
+    /// assert_eq!(DateTime::MIN.day0(), 0);
+    /// assert_eq!(DateTime::MAX.day0(), 30);
+    /// ```
+    #[must_use]
+    pub const fn day0(self) -> u8 {
+        self.date().day0()
+    }
+
+    /// Gets the hour of this `DateTime`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use dos_date_time::DateTime;
+    /// #
+    /// assert_eq!(DateTime::MIN.hour(), 0);
+    /// assert_eq!(DateTime::MAX.hour(), 23);
+    /// ```
+    #[must_use]
+    pub fn hour(self) -> u8 {
+        self.time().hour()
+    }
+
+    /// Gets the minute of this `DateTime`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use dos_date_time::DateTime;
+    /// #
+    /// assert_eq!(DateTime::MIN.minute(), 0);
+    /// assert_eq!(DateTime::MAX.minute(), 59);
+    /// ```
+    #[must_use]
+    pub fn minute(self) -> u8 {
+        self.time().minute()
+    }
+
+    /// Gets the second of this `DateTime`.
+    ///
+    /// The returned value is always even. See [`Time::second`] for details.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use dos_date_time::DateTime;
+    /// #
+    /// assert_eq!(DateTime::MIN.second(), 0);
+    /// assert_eq!(DateTime::MAX.second(), 58);
+    /// ```
+    #[must_use]
+    pub fn second(self) -> u8 {
+        self.time().second()
+    }
+
+    /// Returns [`true`] if the date of this `DateTime` is a weekday, that is,
+    /// from Monday to Friday.
+    ///
+    /// This is the negation of [`Date::is_weekend`], and does not consider
+    /// holidays.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use dos_date_time::DateTime;
+    /// #
+    /// // `1980-01-01` is a Tuesday.
+    /// assert!(DateTime::MIN.is_business_day());
+    ///
+    /// // `2107-12-31` is a Saturday.
+    /// assert!(!DateTime::MAX.is_business_day());
+    /// ```
+    #[must_use]
+    pub fn is_business_day(self) -> bool {
+        !self.date().is_weekend()
+    }
+
+    /// Returns the fields of this `DateTime` as pairs of the field name and
+    /// the value.
+    ///
+    /// The fields are the year, month, day, hour, minute, and second, in that
+    /// order.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use dos_date_time::DateTime;
+    /// #
+    /// assert_eq!(
+    ///     DateTime::MAX.fields(),
+    ///     [
+    ///         ("year", 2107),
+    ///         ("month", 12),
+    ///         ("day", 31),
+    ///         ("hour", 23),
+    ///         ("minute", 59),
+    ///         ("second", 58)
+    ///     ]
+    /// );
+    /// ```
+    #[must_use]
+    pub fn fields(self) -> [(&'static str, u32); 6] {
+        [
+            ("year", self.year().into()),
+            ("month", u8::from(self.month()).into()),
+            ("day", self.day().into()),
+            ("hour", self.hour().into()),
+            ("minute", self.minute().into()),
+            ("second", self.second().into()),
+        ]
+    }
+
+    /// Returns all the components of this `DateTime`, including the day of the
+    /// week and the day of the year.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use dos_date_time::{
+    /// #     DateTime,
+    /// #     time::{Month, Weekday},
+    /// # };
+    /// #
+    /// let breakdown = DateTime::MAX.breakdown();
+    /// assert_eq!(breakdown.year, 2107);
+    /// assert_eq!(breakdown.month, Month::December);
+    /// assert_eq!(breakdown.day, 31);
+    /// assert_eq!(breakdown.hour, 23);
+    /// assert_eq!(breakdown.minute, 59);
+    /// assert_eq!(breakdown.second, 58);
+    /// assert_eq!(breakdown.weekday, Weekday::Saturday);
+    /// assert_eq!(breakdown.ordinal, 365);
+    /// ```
+    #[must_use]
+    pub fn breakdown(self) -> Breakdown {
+        let date = time::Date::from(self.date());
+        let (_, month, day) = date.to_calendar_date();
+        Breakdown {
+            year: self.year(),
+            month,
+            day,
+            hour: self.hour(),
+            minute: self.minute(),
+            second: self.second(),
+            weekday: date.weekday(),
+            ordinal: date.ordinal(),
+        }
+    }
+
+    /// Returns [`true`] if this `DateTime` and `other` are equal to the
+    /// minute, ignoring the seconds.
+    ///
+    /// This is useful when comparing timestamps which only have minute
+    /// granularity.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use dos_date_time::{DateTime, time::macros::datetime};
+    /// #
+    /// let a = DateTime::try_from(datetime!(2018-11-17 10:38:00)).unwrap();
+    /// let b = DateTime::try_from(datetime!(2018-11-17 10:38:30)).unwrap();
+    /// assert!(a.eq_to_the_minute(b));
+    ///
+    /// let c = DateTime::try_from(datetime!(2018-11-17 10:39:00)).unwrap();
+    /// assert!(!a.eq_to_the_minute(c));
+    /// ```
+    #[must_use]
+    pub const fn eq_to_the_minute(self, other: Self) -> bool {
+        self.date().to_raw() == other.date().to_raw()
+            && (self.time().to_raw() >> 5) == (other.time().to_raw() >> 5)
+    }
+}
+
+impl Default for DateTime {
+    /// Returns the default value of "1980-01-01 00:00:00".
+    ///
+    /// Equivalent to [`DateTime::MIN`] except that it is not callable in const
+    /// contexts.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use dos_date_time::DateTime;
+    /// #
+    /// assert_eq!(DateTime::default(), DateTime::MIN);
+    /// ```
+    fn default() -> Self {
+        Self::MIN
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    #[cfg(feature = "std")]
+    use std::{
+        collections::hash_map::DefaultHasher,
+        hash::{Hash, Hasher},
+    };
+
+    use time::{
+        Weekday,
+        macros::{date, datetime, time},
+    };
+
+    use super::*;
+    use crate::error::{
+        DateErrorKind, DateRangeErrorKind, DateTimeErrorKind, InvalidBitsErrorKind,
+        InvalidDateField, InvalidTimeField, TimePrecisionErrorKind,
+    };
+
+    #[test]
+    fn clone() {
+        assert_eq!(DateTime::MIN.clone(), DateTime::MIN);
+    }
+
+    #[test]
+    fn copy() {
+        let a = DateTime::MIN;
+        let b = a;
+        assert_eq!(a, b);
+    }
+
+    #[cfg(feature = "std")]
+    #[test]
+    fn hash() {
+        assert_ne!(
+            {
+                let mut hasher = DefaultHasher::new();
+                DateTime::MIN.hash(&mut hasher);
+                hasher.finish()
+            },
+            {
+                let mut hasher = DefaultHasher::new();
+                DateTime::MAX.hash(&mut hasher);
+                hasher.finish()
+            }
+        );
+    }
+
+    #[cfg(feature = "std")]
+    #[test]
+    fn hash_agrees_with_as_tuple() {
+        fn hash_of(value: impl Hash) -> u64 {
+            let mut hasher = DefaultHasher::new();
+            value.hash(&mut hasher);
+            hasher.finish()
+        }
+
+        for dt in [
+            DateTime::MIN,
+            // <https://devblogs.microsoft.com/oldnewthing/20030905-02/?p=42653>.
+            DateTime::new(
+                Date::new(0b0010_1101_0111_1010).unwrap(),
+                Time::new(0b1001_1011_0010_0000).unwrap(),
+            ),
+            // <https://github.com/zip-rs/zip/blob/v0.6.4/src/types.rs#L553-L569>.
+            DateTime::new(
+                Date::new(0b0100_1101_0111_0001).unwrap(),
+                Time::new(0b0101_0100_1100_1111).unwrap(),
+            ),
+            DateTime::MAX,
+        ] {
+            assert_eq!(hash_of(dt), hash_of(dt.as_tuple()));
+        }
+    }
+
+    #[test]
+    fn new() {
+        assert_eq!(DateTime::new(Date::MIN, Time::MIN), DateTime::MIN);
+        assert_eq!(DateTime::new(Date::MAX, Time::MAX), DateTime::MAX);
+    }
+
+    #[test]
+    fn new_with_policy() {
+        for policy in [ValidationPolicy::Strict, ValidationPolicy::Lenient] {
+            assert_eq!(
+                DateTime::new_with_policy(0b0000_0000_0010_0001, u16::MIN, policy).unwrap(),
+                DateTime::MIN
+            );
+            // <https://github.com/zip-rs/zip/blob/v0.6.4/src/types.rs#L553-L569>.
+            assert_eq!(
+                DateTime::new_with_policy(0b0100_1101_0111_0001, 0b0101_0100_1100_1111, policy)
+                    .unwrap(),
+                DateTime::new(
+                    Date::new(0b0100_1101_0111_0001).unwrap(),
+                    Time::new(0b0101_0100_1100_1111).unwrap()
+                )
+            );
+            assert_eq!(
+                DateTime::new_with_policy(0b1111_1111_1001_1111, 0b1011_1111_0111_1101, policy)
+                    .unwrap(),
+                DateTime::MAX
+            );
+        }
+    }
+
+    #[test]
+    fn new_with_policy_with_day_zero() {
+        assert_eq!(
+            DateTime::new_with_policy(
+                0b0100_1101_0110_0000,
+                0b0101_0100_1100_1111,
+                ValidationPolicy::Strict
+            )
+            .unwrap_err(),
+            InvalidDateField::DayZero.into()
+        );
+        assert_eq!(
+            DateTime::new_with_policy(
+                0b0100_1101_0110_0000,
+                0b0101_0100_1100_1111,
+                ValidationPolicy::Lenient
+            )
+            .unwrap(),
+            DateTime::try_from(datetime!(2018-11-01 10:38:30)).unwrap()
+        );
+    }
+
+    #[test]
+    fn new_with_policy_with_month_zero() {
+        assert_eq!(
+            DateTime::new_with_policy(0b0000_0000_0000_0000, u16::MIN, ValidationPolicy::Strict)
+                .unwrap_err(),
+            InvalidDateField::MonthZero.into()
+        );
+        assert_eq!(
+            DateTime::new_with_policy(0b0000_0000_0000_0000, u16::MIN, ValidationPolicy::Lenient)
+                .unwrap(),
+            DateTime::MIN
+        );
+        assert_eq!(
+            DateTime::new_with_policy(0b0100_1100_0001_0001, u16::MIN, ValidationPolicy::Lenient)
+                .unwrap(),
+            DateTime::try_from(datetime!(2018-01-17 00:00:00)).unwrap()
+        );
+    }
+
+    #[test]
+    fn new_with_policy_with_invalid_bits() {
+        for policy in [ValidationPolicy::Strict, ValidationPolicy::Lenient] {
+            // The Month field is 13.
+            assert_eq!(
+                DateTime::new_with_policy(0b0000_0001_1010_0001, u16::MIN, policy).unwrap_err(),
+                InvalidDateField::MonthTooLarge(13).into()
+            );
+            // The Day field is 30, which is after the last day of February.
+            assert_eq!(
+                DateTime::new_with_policy(0b0000_0000_0101_1110, u16::MIN, policy).unwrap_err(),
+                InvalidDateField::DayTooLarge { day: 30, max: 29 }.into()
+            );
+            // The Hour field is 24.
+            assert_eq!(
+                DateTime::new_with_policy(0b0000_0000_0010_0001, 0b1100_0000_0000_0000, policy)
+                    .unwrap_err(),
+                InvalidTimeField::HourTooLarge(24).into()
+            );
+        }
+    }
+
+    #[test]
+    fn new_with_policy_with_invalid_date_field() {
+        // The Month field is 0.
+        assert_eq!(
+            DateTime::new_with_policy(0b0000_0000_0000_0001, u16::MIN, ValidationPolicy::Strict)
+                .unwrap_err()
+                .kind(),
+            InvalidBitsErrorKind::Date(InvalidDateField::MonthZero)
+        );
+        // The Month field is 13.
+        assert_eq!(
+            DateTime::new_with_policy(0b0000_0001_1010_0001, u16::MIN, ValidationPolicy::Strict)
+                .unwrap_err()
+                .kind(),
+            InvalidBitsErrorKind::Date(InvalidDateField::MonthTooLarge(13))
+        );
+        // The Day field is 0.
+        assert_eq!(
+            DateTime::new_with_policy(0b0000_0000_0010_0000, u16::MIN, ValidationPolicy::Strict)
+                .unwrap_err()
+                .kind(),
+            InvalidBitsErrorKind::Date(InvalidDateField::DayZero)
+        );
+        // The Day field is 31, which is after the last day of April.
+        assert_eq!(
+            DateTime::new_with_policy(0b0000_0000_1001_1111, u16::MIN, ValidationPolicy::Strict)
+                .unwrap_err()
+                .kind(),
+            InvalidBitsErrorKind::Date(InvalidDateField::DayTooLarge { day: 31, max: 30 })
+        );
+    }
+
+    #[test]
+    fn new_with_policy_with_invalid_time_field() {
+        // The Hour field is 24.
+        assert_eq!(
+            DateTime::new_with_policy(
+                0b0000_0000_0010_0001,
+                0b1100_0000_0000_0000,
+                ValidationPolicy::Strict
+            )
+            .unwrap_err()
+            .kind(),
+            InvalidBitsErrorKind::Time(InvalidTimeField::HourTooLarge(24))
+        );
+        // The Minute field is 60.
+        assert_eq!(
+            DateTime::new_with_policy(
+                0b0000_0000_0010_0001,
+                0b0000_0111_1000_0000,
+                ValidationPolicy::Strict
+            )
+            .unwrap_err()
+            .kind(),
+            InvalidBitsErrorKind::Time(InvalidTimeField::MinuteTooLarge(60))
+        );
+        // The DoubleSeconds field is 30.
+        assert_eq!(
+            DateTime::new_with_policy(
+                0b0000_0000_0010_0001,
+                0b0000_0000_0001_1110,
+                ValidationPolicy::Strict
+            )
+            .unwrap_err()
+            .kind(),
+            InvalidBitsErrorKind::Time(InvalidTimeField::DoubleSecondsTooLarge(30))
+        );
+    }
+
+    #[test]
+    fn try_from_parts() {
+        assert_eq!(
+            DateTime::try_from_parts(1980, 1, 1, 0, 0, 0).unwrap(),
+            DateTime::MIN
+        );
+        // <https://devblogs.microsoft.com/oldnewthing/20030905-02/?p=42653>.
+        assert_eq!(
+            DateTime::try_from_parts(2002, 11, 26, 19, 25, 0).unwrap(),
+            DateTime::try_from(datetime!(2002-11-26 19:25:00)).unwrap()
+        );
+        // <https://github.com/zip-rs/zip/blob/v0.6.4/src/types.rs#L553-L569>.
+        assert_eq!(
+            DateTime::try_from_parts(2018, 11, 17, 10, 38, 30).unwrap(),
+            DateTime::try_from(datetime!(2018-11-17 10:38:30)).unwrap()
+        );
+        assert_eq!(
+            DateTime::try_from_parts(2000, 2, 29, 0, 0, 0).unwrap(),
+            DateTime::try_from(datetime!(2000-02-29 00:00:00)).unwrap()
+        );
+        assert_eq!(
+            DateTime::try_from_parts(2107, 12, 31, 23, 59, 58).unwrap(),
+            DateTime::MAX
+        );
+    }
+
+    #[test]
+    fn try_from_parts_with_odd_second() {
+        assert_eq!(
+            DateTime::try_from_parts(1980, 1, 1, 0, 0, 1).unwrap(),
+            DateTime::MIN
+        );
+        assert_eq!(
+            DateTime::try_from_parts(2107, 12, 31, 23, 59, 59).unwrap(),
+            DateTime::MAX
+        );
+    }
+
+    #[test]
+    fn try_from_parts_with_invalid_parts() {
+        assert!(DateTime::try_from_parts(1979, 12, 31, 23, 59, 58).is_none());
+        assert!(DateTime::try_from_parts(2108, 1, 1, 0, 0, 0).is_none());
+        assert!(DateTime::try_from_parts(1980, 0, 1, 0, 0, 0).is_none());
+        assert!(DateTime::try_from_parts(1980, 13, 1, 0, 0, 0).is_none());
+        assert!(DateTime::try_from_parts(1980, 1, 0, 0, 0, 0).is_none());
+        assert!(DateTime::try_from_parts(1980, 1, 32, 0, 0, 0).is_none());
+        // `2100` is not a leap year.
+        assert!(DateTime::try_from_parts(2100, 2, 29, 0, 0, 0).is_none());
+        assert!(DateTime::try_from_parts(1980, 1, 1, 24, 0, 0).is_none());
+        assert!(DateTime::try_from_parts(1980, 1, 1, 0, 60, 0).is_none());
+        assert!(DateTime::try_from_parts(1980, 1, 1, 0, 0, 60).is_none());
+        assert!(
+            DateTime::try_from_parts(u16::MAX, u8::MAX, u8::MAX, u8::MAX, u8::MAX, u8::MAX)
+                .is_none()
+        );
+    }
+
+    #[test]
+    const fn try_from_parts_is_const_fn() {
+        const MIN: Option<DateTime> = DateTime::try_from_parts(1980, 1, 1, 0, 0, 0);
+        const INVALID: Option<DateTime> = DateTime::try_from_parts(2001, 2, 29, 0, 0, 0);
+        assert!(MIN.is_some());
+        assert!(INVALID.is_none());
+    }
+
+    #[test]
+    fn try_from_parts_agrees_with_from_date_time() {
+        for date in Date::all_valid().step_by(97) {
+            let dt = DateTime::new(date, Time::MAX);
+            assert_eq!(
+                DateTime::try_from_parts(
+                    dt.year(),
+                    u8::from(dt.month()),
+                    dt.day(),
+                    dt.hour(),
+                    dt.minute(),
+                    dt.second()
+                ),
+                Some(dt)
+            );
+        }
+    }
+
+    #[test]
+    const fn new_is_const_fn() {
+        const _: DateTime = DateTime::new(Date::MIN, Time::MIN);
+    }
+
+    #[test]
+    fn from_date_time_before_dos_date_time_epoch() {
+        assert_eq!(
+            DateTime::from_date_time(date!(1979-12-31), time!(23:59:58)).unwrap_err(),
+            DateTimeRangeErrorKind::Negative.into()
+        );
+        assert_eq!(
+            DateTime::from_date_time(date!(1979-12-31), time!(23:59:59)).unwrap_err(),
+            DateTimeRangeErrorKind::Negative.into()
+        );
+    }
+
+    #[test]
+    fn from_date_time() {
+        assert_eq!(
+            DateTime::from_date_time(date!(1980-01-01), time::Time::MIDNIGHT).unwrap(),
+            DateTime::MIN
+        );
+        assert_eq!(
+            DateTime::from_date_time(date!(1980-01-01), time!(00:00:01)).unwrap(),
+            DateTime::MIN
+        );
+        // <https://devblogs.microsoft.com/oldnewthing/20030905-02/?p=42653>.
+        assert_eq!(
+            DateTime::from_date_time(date!(2002-11-26), time!(19:25:00)).unwrap(),
+            DateTime::new(
+                Date::new(0b0010_1101_0111_1010).unwrap(),
+                Time::new(0b1001_1011_0010_0000).unwrap()
+            )
+        );
+        // <https://github.com/zip-rs/zip/blob/v0.6.4/src/types.rs#L553-L569>.
+        assert_eq!(
+            DateTime::from_date_time(date!(2018-11-17), time!(10:38:30)).unwrap(),
+            DateTime::new(
+                Date::new(0b0100_1101_0111_0001).unwrap(),
+                Time::new(0b0101_0100_1100_1111).unwrap()
+            )
+        );
+        assert_eq!(
+            DateTime::from_date_time(date!(2107-12-31), time!(23:59:58)).unwrap(),
+            DateTime::MAX
+        );
+        assert_eq!(
+            DateTime::from_date_time(date!(2107-12-31), time!(23:59:59)).unwrap(),
+            DateTime::MAX
+        );
+    }
+
+    #[test]
+    fn from_date_time_with_too_big_date_time() {
+        assert_eq!(
+            DateTime::from_date_time(date!(2108-01-01), time::Time::MIDNIGHT).unwrap_err(),
+            DateTimeRangeErrorKind::Overflow.into()
+        );
+    }
+
+    #[test]
+    fn from_date_time_exact() {
+        assert_eq!(
+            DateTime::from_date_time_exact(date!(1980-01-01), time::Time::MIDNIGHT).unwrap(),
+            DateTime::MIN
+        );
+        // <https://devblogs.microsoft.com/oldnewthing/20030905-02/?p=42653>.
+        assert_eq!(
+            DateTime::from_date_time_exact(date!(2002-11-26), time!(19:25:00)).unwrap(),
+            DateTime::new(
+                Date::new(0b0010_1101_0111_1010).unwrap(),
+                Time::new(0b1001_1011_0010_0000).unwrap()
+            )
+        );
+        assert_eq!(
+            DateTime::from_date_time_exact(date!(2107-12-31), time!(23:59:58)).unwrap(),
+            DateTime::MAX
+        );
+    }
+
+    #[test]
+    fn from_date_time_exact_with_out_of_range_date() {
+        assert_eq!(
+            DateTime::from_date_time_exact(date!(1979-12-31), time!(23:59:58)).unwrap_err(),
+            DateTimeErrorKind::Range(DateTimeRangeErrorKind::Negative).into()
+        );
+        assert_eq!(
+            DateTime::from_date_time_exact(date!(2108-01-01), time::Time::MIDNIGHT).unwrap_err(),
+            DateTimeErrorKind::Range(DateTimeRangeErrorKind::Overflow).into()
+        );
+    }
+
+    #[test]
+    fn from_date_time_exact_with_inexact_time() {
+        assert_eq!(
+            DateTime::from_date_time_exact(date!(1980-01-01), time!(00:00:01)).unwrap_err(),
+            DateTimeErrorKind::Precision(TimePrecisionErrorKind::OddSecond).into()
+        );
+        assert_eq!(
+            DateTime::from_date_time_exact(date!(1980-01-01), time!(00:00:00.5)).unwrap_err(),
+            DateTimeErrorKind::Precision(TimePrecisionErrorKind::Subsecond).into()
+        );
+    }
+
+    #[test]
+    fn try_from_utc() {
+        assert_eq!(
+            DateTime::try_from_utc(datetime!(1980-01-01 00:00:00 UTC)).unwrap(),
+            DateTime::MIN
+        );
+        assert_eq!(
+            DateTime::try_from_utc(datetime!(1980-01-01 00:00:01 UTC)).unwrap(),
+            DateTime::MIN
+        );
+        // <https://github.com/zip-rs/zip/blob/v0.6.4/src/types.rs#L553-L569>.
+        assert_eq!(
+            DateTime::try_from_utc(datetime!(2018-11-17 10:38:30 UTC)).unwrap(),
+            DateTime::new(
+                Date::new(0b0100_1101_0111_0001).unwrap(),
+                Time::new(0b0101_0100_1100_1111).unwrap()
+            )
+        );
+        assert_eq!(
+            DateTime::try_from_utc(datetime!(2107-12-31 23:59:59 UTC)).unwrap(),
+            DateTime::MAX
+        );
+    }
+
+    #[test]
+    fn try_from_utc_with_non_utc_offset() {
+        assert_eq!(
+            DateTime::try_from_utc(datetime!(1980-01-01 01:00:00 +01:00)).unwrap_err(),
+            ConvertErrorKind::NotUtc.into()
+        );
+        assert_eq!(
+            DateTime::try_from_utc(datetime!(1980-01-01 00:00:00 -00:00:01)).unwrap_err(),
+            ConvertErrorKind::NotUtc.into()
+        );
+        // The offset is checked before the range.
+        assert_eq!(
+            DateTime::try_from_utc(datetime!(1979-12-31 23:59:59 +01:00)).unwrap_err(),
+            ConvertErrorKind::NotUtc.into()
+        );
+    }
+
+    #[test]
+    fn try_from_utc_with_out_of_range_date_time() {
+        assert_eq!(
+            DateTime::try_from_utc(datetime!(1979-12-31 23:59:59 UTC)).unwrap_err(),
+            ConvertErrorKind::Range(DateTimeRangeErrorKind::Negative).into()
+        );
+        assert_eq!(
+            DateTime::try_from_utc(datetime!(2108-01-01 00:00:00 UTC)).unwrap_err(),
+            ConvertErrorKind::Range(DateTimeRangeErrorKind::Overflow).into()
+        );
+    }
+
+    #[test]
+    fn saturating_from_primitive() {
+        assert_eq!(
+            DateTime::saturating_from_primitive(PrimitiveDateTime::MIN),
+            DateTime::MIN
+        );
+        assert_eq!(
+            DateTime::saturating_from_primitive(datetime!(1900-01-01 00:00:00)),
+            DateTime::MIN
+        );
+        assert_eq!(
+            DateTime::saturating_from_primitive(datetime!(1979-12-31 23:59:59.999_999_999)),
+            DateTime::MIN
+        );
+        assert_eq!(
+            DateTime::saturating_from_primitive(datetime!(1980-01-01 00:00:00)),
+            DateTime::MIN
+        );
+        // <https://github.com/zip-rs/zip/blob/v0.6.4/src/types.rs#L553-L569>.
+        assert_eq!(
+            DateTime::saturating_from_primitive(datetime!(2018-11-17 10:38:31)),
+            DateTime::new(
+                Date::new(0b0100_1101_0111_0001).unwrap(),
+                Time::new(0b0101_0100_1100_1111).unwrap()
+            )
+        );
+        assert_eq!(
+            DateTime::saturating_from_primitive(datetime!(2107-12-31 23:59:59)),
+            DateTime::MAX
+        );
+        assert_eq!(
+            DateTime::saturating_from_primitive(datetime!(2108-01-01 00:00:00)),
+            DateTime::MAX
+        );
+        assert_eq!(
+            DateTime::saturating_from_primitive(datetime!(2200-01-01 00:00:00)),
+            DateTime::MAX
+        );
+        assert_eq!(
+            DateTime::saturating_from_primitive(PrimitiveDateTime::MAX),
+            DateTime::MAX
+        );
+    }
+
+    #[test]
+    fn from_unix_time_f64() {
+        assert_eq!(
+            DateTime::from_unix_time_f64(315_532_800.0).unwrap(),
+            DateTime::MIN
+        );
+        assert_eq!(
+            DateTime::from_unix_time_f64(315_532_801.999).unwrap(),
+            DateTime::MIN
+        );
+        // <https://github.com/zip-rs/zip/blob/v0.6.4/src/types.rs#L553-L569>.
+        assert_eq!(
+            DateTime::from_unix_time_f64(1_542_451_110.0).unwrap(),
+            DateTime::new(
+                Date::new(0b0100_1101_0111_0001).unwrap(),
+                Time::new(0b0101_0100_1100_1111).unwrap()
+            )
+        );
+        assert_eq!(
+            DateTime::from_unix_time_f64(1_542_451_111.75).unwrap(),
+            DateTime::new(
+                Date::new(0b0100_1101_0111_0001).unwrap(),
+                Time::new(0b0101_0100_1100_1111).unwrap()
+            )
+        );
+        assert_eq!(
+            DateTime::from_unix_time_f64(4_354_819_198.0).unwrap(),
+            DateTime::MAX
+        );
+        assert_eq!(
+            DateTime::from_unix_time_f64(4_354_819_199.9).unwrap(),
+            DateTime::MAX
+        );
+    }
+
+    #[test]
+    fn from_unix_time_f64_with_non_finite_value() {
+        assert_eq!(
+            DateTime::from_unix_time_f64(f64::NAN).unwrap_err(),
+            ConvertErrorKind::NotFinite.into()
+        );
+        assert_eq!(
+            DateTime::from_unix_time_f64(f64::INFINITY).unwrap_err(),
+            ConvertErrorKind::NotFinite.into()
+        );
+        assert_eq!(
+            DateTime::from_unix_time_f64(f64::NEG_INFINITY).unwrap_err(),
+            ConvertErrorKind::NotFinite.into()
+        );
+    }
+
+    #[test]
+    fn from_unix_time_f64_with_out_of_range_value() {
+        assert_eq!(
+            DateTime::from_unix_time_f64(315_532_799.9).unwrap_err(),
+            ConvertErrorKind::Range(DateTimeRangeErrorKind::Negative).into()
+        );
+        assert_eq!(
+            DateTime::from_unix_time_f64(-1.0).unwrap_err(),
+            ConvertErrorKind::Range(DateTimeRangeErrorKind::Negative).into()
+        );
+        assert_eq!(
+            DateTime::from_unix_time_f64(f64::MIN).unwrap_err(),
+            ConvertErrorKind::Range(DateTimeRangeErrorKind::Negative).into()
+        );
+        assert_eq!(
+            DateTime::from_unix_time_f64(4_354_819_200.0).unwrap_err(),
+            ConvertErrorKind::Range(DateTimeRangeErrorKind::Overflow).into()
+        );
+        assert_eq!(
+            DateTime::from_unix_time_f64(f64::MAX).unwrap_err(),
+            ConvertErrorKind::Range(DateTimeRangeErrorKind::Overflow).into()
+        );
+    }
+
+    #[cfg(feature = "std")]
+    #[test]
+    fn from_system_time() {
+        use std::time::{Duration, SystemTime};
+
+        assert_eq!(
+            DateTime::from_system_time(SystemTime::UNIX_EPOCH + Duration::from_secs(315_532_800))
+                .unwrap(),
+            DateTime::MIN
+        );
+        assert_eq!(
+            DateTime::from_system_time(
+                SystemTime::UNIX_EPOCH + Duration::from_millis(315_532_801_999)
+            )
+            .unwrap(),
+            DateTime::MIN
+        );
+        // <https://github.com/zip-rs/zip/blob/v0.6.4/src/types.rs#L553-L569>.
+        assert_eq!(
+            DateTime::from_system_time(SystemTime::UNIX_EPOCH + Duration::from_secs(1_542_451_110))
+                .unwrap(),
+            DateTime::try_from(datetime!(2018-11-17 10:38:30)).unwrap()
+        );
+        assert_eq!(
+            DateTime::from_system_time(SystemTime::UNIX_EPOCH + Duration::from_secs(4_354_819_199))
+                .unwrap(),
+            DateTime::MAX
+        );
+    }
+
+    #[cfg(feature = "std")]
+    #[test]
+    fn from_system_time_with_out_of_range() {
+        use std::time::{Duration, SystemTime};
+
+        assert_eq!(
+            DateTime::from_system_time(SystemTime::UNIX_EPOCH + Duration::from_secs(315_532_799))
+                .unwrap_err(),
+            DateTimeRangeErrorKind::Negative.into()
+        );
+        assert_eq!(
+            DateTime::from_system_time(SystemTime::UNIX_EPOCH - Duration::from_secs(1))
+                .unwrap_err(),
+            DateTimeRangeErrorKind::Negative.into()
+        );
+        assert_eq!(
+            DateTime::from_system_time(SystemTime::UNIX_EPOCH + Duration::from_secs(4_354_819_200))
+                .unwrap_err(),
+            DateTimeRangeErrorKind::Overflow.into()
+        );
+        assert_eq!(
+            DateTime::from_system_time(
+                SystemTime::UNIX_EPOCH + Duration::from_secs(u64::from(u32::MAX) * 1000)
+            )
+            .unwrap_err(),
+            DateTimeRangeErrorKind::Overflow.into()
+        );
+    }
+
+    #[cfg(feature = "jiff")]
+    #[test]
+    fn from_dos_epoch_span() {
+        use jiff::{Span, ToSpan};
+
+        assert_eq!(
+            DateTime::from_dos_epoch_span(Span::new()).unwrap(),
+            DateTime::MIN
+        );
+        assert_eq!(
+            DateTime::from_dos_epoch_span(27.years()).unwrap(),
+            DateTime::try_from(datetime!(2007-01-01 00:00:00)).unwrap()
+        );
+        assert_eq!(
+            DateTime::from_dos_epoch_span(1.month().days(28)).unwrap(),
+            DateTime::try_from(datetime!(1980-02-29 00:00:00)).unwrap()
+        );
+        // <https://github.com/zip-rs/zip/blob/v0.6.4/src/types.rs#L553-L569>.
+        assert_eq!(
+            DateTime::from_dos_epoch_span(
+                38.years()
+                    .months(10)
+                    .days(16)
+                    .hours(10)
+                    .minutes(38)
+                    .seconds(30)
+            )
+            .unwrap(),
+            DateTime::try_from(datetime!(2018-11-17 10:38:30)).unwrap()
+        );
+        assert_eq!(
+            DateTime::from_dos_epoch_span(613_459_155_i64.seconds().checked_mul(2).unwrap())
+                .unwrap(),
+            DateTime::try_from(datetime!(2018-11-17 10:38:30)).unwrap()
+        );
+        assert_eq!(
+            DateTime::from_dos_epoch_span(
+                127.years()
+                    .months(11)
+                    .days(30)
+                    .hours(23)
+                    .minutes(59)
+                    .seconds(59)
+            )
+            .unwrap(),
+            DateTime::MAX
+        );
+    }
+
+    #[cfg(feature = "jiff")]
+    #[test]
+    fn from_dos_epoch_span_with_fractional_second() {
+        use jiff::ToSpan;
+
+        assert_eq!(
+            DateTime::from_dos_epoch_span(3.seconds().milliseconds(999)).unwrap(),
+            DateTime::try_from(datetime!(1980-01-01 00:00:02)).unwrap()
+        );
+    }
+
+    #[cfg(feature = "jiff")]
+    #[test]
+    fn from_dos_epoch_span_with_out_of_range() {
+        use jiff::{Span, ToSpan};
+
+        assert_eq!(
+            DateTime::from_dos_epoch_span(-1.second()).unwrap_err(),
+            DateTimeRangeErrorKind::Negative.into()
+        );
+        assert_eq!(
+            DateTime::from_dos_epoch_span(128.years()).unwrap_err(),
+            DateTimeRangeErrorKind::Overflow.into()
+        );
+        assert_eq!(
+            DateTime::from_dos_epoch_span(Span::new().years(19_998)).unwrap_err(),
+            DateTimeRangeErrorKind::Overflow.into()
+        );
+        assert_eq!(
+            DateTime::from_dos_epoch_span(Span::new().years(-19_998)).unwrap_err(),
+            DateTimeRangeErrorKind::Negative.into()
+        );
+    }
+
+    #[test]
+    fn from_ascii() {
+        assert_eq!(
+            DateTime::from_ascii(b"1980-01-01 00:00:00").unwrap(),
+            DateTime::MIN
+        );
+        assert_eq!(
+            DateTime::from_ascii(b"1980-01-01T00:00:01").unwrap(),
+            DateTime::MIN
+        );
+        // <https://devblogs.microsoft.com/oldnewthing/20030905-02/?p=42653>.
+        assert_eq!(
+            DateTime::from_ascii(b"2002-11-26 19:25:00").unwrap(),
+            DateTime::new(
+                Date::new(0b0010_1101_0111_1010).unwrap(),
+                Time::new(0b1001_1011_0010_0000).unwrap()
+            )
+        );
+        // <https://github.com/zip-rs/zip/blob/v0.6.4/src/types.rs#L553-L569>.
+        assert_eq!(
+            DateTime::from_ascii(b"2018-11-17T10:38:30").unwrap(),
+            DateTime::new(
+                Date::new(0b0100_1101_0111_0001).unwrap(),
+                Time::new(0b0101_0100_1100_1111).unwrap()
+            )
+        );
+        assert_eq!(
+            DateTime::from_ascii(b"2107-12-31 23:59:58").unwrap(),
+            DateTime::MAX
+        );
+        assert_eq!(
+            DateTime::from_ascii(b"2107-12-31T23:59:59").unwrap(),
+            DateTime::MAX
+        );
+    }
+
+    #[test]
+    fn from_ascii_with_invalid_length() {
+        assert_eq!(
+            DateTime::from_ascii(b"").unwrap_err(),
+            ParseErrorKind::InvalidLength.into()
+        );
+        assert_eq!(
+            DateTime::from_ascii(b"1980-01-01").unwrap_err(),
+            ParseErrorKind::InvalidLength.into()
+        );
+        assert_eq!(
+            DateTime::from_ascii(b"1980-01-01 00:00:00Z").unwrap_err(),
+            ParseErrorKind::InvalidLength.into()
+        );
+    }
+
+    #[test]
+    fn from_ascii_with_invalid_separator() {
+        assert_eq!(
+            DateTime::from_ascii(b"1980-01-01_00:00:00").unwrap_err(),
+            ParseErrorKind::InvalidSeparator.into()
+        );
+        assert_eq!(
+            DateTime::from_ascii(b"1980/01/01 00:00:00").unwrap_err(),
+            ParseErrorKind::InvalidSeparator.into()
+        );
+        assert_eq!(
+            DateTime::from_ascii(b"1980-01-01 00.00.00").unwrap_err(),
+            ParseErrorKind::InvalidSeparator.into()
+        );
+    }
+
+    #[test]
+    fn from_ascii_with_invalid_number() {
+        assert_eq!(
+            DateTime::from_ascii(b"198O-01-01 00:00:00").unwrap_err(),
+            ParseErrorKind::InvalidNumber.into()
+        );
+        assert_eq!(
+            DateTime::from_ascii(b"1980-01-01 00:00:+0").unwrap_err(),
+            ParseErrorKind::InvalidNumber.into()
+        );
+    }
+
+    #[test]
+    fn from_ascii_with_invalid_component() {
+        // The month is 13.
+        assert_eq!(
+            DateTime::from_ascii(b"1980-13-01 00:00:00").unwrap_err(),
+            ParseErrorKind::InvalidComponent.into()
+        );
+        // The day is 30, which is after the last day of February.
+        assert_eq!(
+            DateTime::from_ascii(b"1980-02-30 00:00:00").unwrap_err(),
+            ParseErrorKind::InvalidComponent.into()
+        );
+        // The hour is 24.
+        assert_eq!(
+            DateTime::from_ascii(b"1980-01-01 24:00:00").unwrap_err(),
+            ParseErrorKind::InvalidComponent.into()
+        );
+        // The second is 60.
+        assert_eq!(
+            DateTime::from_ascii(b"1980-01-01 00:00:60").unwrap_err(),
+            ParseErrorKind::InvalidComponent.into()
+        );
+    }
+
+    #[test]
+    fn from_ascii_with_out_of_range_date_time() {
+        assert_eq!(
+            DateTime::from_ascii(b"1979-12-31 23:59:59").unwrap_err(),
+            ParseErrorKind::Range(DateTimeRangeErrorKind::Negative).into()
+        );
+        assert_eq!(
+            DateTime::from_ascii(b"2108-01-01 00:00:00").unwrap_err(),
+            ParseErrorKind::Range(DateTimeRangeErrorKind::Overflow).into()
+        );
+    }
+
+    #[test]
+    fn from_raw_pair_str() {
+        assert_eq!(DateTime::from_raw_pair_str("33,0").unwrap(), DateTime::MIN);
+        // <https://devblogs.microsoft.com/oldnewthing/20030905-02/?p=42653>.
+        assert_eq!(
+            DateTime::from_raw_pair_str("11642,39712").unwrap(),
+            DateTime::try_from(datetime!(2002-11-26 19:25:00)).unwrap()
+        );
+        // <https://github.com/zip-rs/zip/blob/v0.6.4/src/types.rs#L553-L569>.
+        assert_eq!(
+            DateTime::from_raw_pair_str("0x4D71,0x54CF").unwrap(),
+            DateTime::try_from(datetime!(2018-11-17 10:38:30)).unwrap()
+        );
+        assert_eq!(
+            DateTime::from_raw_pair_str("0xff9f,0xbf7d").unwrap(),
+            DateTime::MAX
+        );
+    }
+
+    #[test]
+    fn from_raw_pair_str_with_mixed_radix() {
+        assert_eq!(
+            DateTime::from_raw_pair_str("0x0021,0b0").unwrap(),
+            DateTime::MIN
+        );
+        assert_eq!(
+            DateTime::from_raw_pair_str(" 0b100001 , 0x0000 ").unwrap(),
+            DateTime::MIN
+        );
+        assert_eq!(
+            DateTime::from_raw_pair_str("65439,0xBF7D").unwrap(),
+            DateTime::MAX
+        );
+    }
+
+    #[test]
+    fn from_raw_pair_str_with_invalid_separator() {
+        assert_eq!(
+            DateTime::from_raw_pair_str("33").unwrap_err(),
+            ParseErrorKind::InvalidSeparator.into()
+        );
+        assert_eq!(
+            DateTime::from_raw_pair_str("33;0").unwrap_err(),
+            ParseErrorKind::InvalidSeparator.into()
+        );
+        assert_eq!(
+            DateTime::from_raw_pair_str("33,0,0").unwrap_err(),
+            ParseErrorKind::InvalidNumber.into()
+        );
+    }
+
+    #[test]
+    fn from_raw_pair_str_with_invalid_number() {
+        for s in [
+            ",0", "33,", "0x,0", "33,0b", "+33,0", "-1,0", "33,65536", "0x21g,0", "33,0b2",
+        ] {
+            assert_eq!(
+                DateTime::from_raw_pair_str(s).unwrap_err(),
+                ParseErrorKind::InvalidNumber.into()
+            );
+        }
+    }
+
+    #[test]
+    fn from_raw_pair_str_with_invalid_component() {
+        // The Day field is 0.
+        assert_eq!(
+            DateTime::from_raw_pair_str("32,0").unwrap_err(),
+            ParseErrorKind::InvalidComponent.into()
+        );
+        // The Hour field is 24.
+        assert_eq!(
+            DateTime::from_raw_pair_str("33,0xC000").unwrap_err(),
+            ParseErrorKind::InvalidComponent.into()
+        );
+    }
+
+    #[test]
+    fn from_base32_key() {
+        assert_eq!(DateTime::from_base32_key("0022000").unwrap(), DateTime::MIN);
+        // <https://devblogs.microsoft.com/oldnewthing/20030905-02/?p=42653>.
+        assert_eq!(
+            DateTime::from_base32_key("0PQN6S0").unwrap(),
+            DateTime::try_from(datetime!(2002-11-26 19:25:00)).unwrap()
+        );
+        // <https://github.com/zip-rs/zip/blob/v0.6.4/src/types.rs#L553-L569>.
+        assert_eq!(
+            DateTime::from_base32_key("16Q2N6F").unwrap(),
+            DateTime::try_from(datetime!(2018-11-17 10:38:30)).unwrap()
+        );
+        assert_eq!(DateTime::from_base32_key("3ZSZFVX").unwrap(), DateTime::MAX);
+    }
+
+    #[test]
+    fn from_base32_key_with_alternative_characters() {
+        assert_eq!(DateTime::from_base32_key("3zszfvx").unwrap(), DateTime::MAX);
+        assert_eq!(DateTime::from_base32_key("OO22OOO").unwrap(), DateTime::MIN);
+        // <https://github.com/zip-rs/zip/blob/v0.6.4/src/types.rs#L553-L569>.
+        assert_eq!(
+            DateTime::from_base32_key("I6Q2N6F").unwrap(),
+            DateTime::try_from(datetime!(2018-11-17 10:38:30)).unwrap()
+        );
+        assert_eq!(
+            DateTime::from_base32_key("l6q2n6f").unwrap(),
+            DateTime::try_from(datetime!(2018-11-17 10:38:30)).unwrap()
+        );
+    }
+
+    #[test]
+    fn from_base32_key_with_invalid_length() {
+        assert_eq!(
+            DateTime::from_base32_key("").unwrap_err(),
+            ParseErrorKind::InvalidLength.into()
+        );
+        assert_eq!(
+            DateTime::from_base32_key("022000").unwrap_err(),
+            ParseErrorKind::InvalidLength.into()
+        );
+        assert_eq!(
+            DateTime::from_base32_key("00022000").unwrap_err(),
+            ParseErrorKind::InvalidLength.into()
+        );
+    }
+
+    #[test]
+    fn from_base32_key_with_invalid_number() {
+        // `U` is not in the alphabet.
+        assert_eq!(
+            DateTime::from_base32_key("002200U").unwrap_err(),
+            ParseErrorKind::InvalidNumber.into()
+        );
+        assert_eq!(
+            DateTime::from_base32_key("0022-00").unwrap_err(),
+            ParseErrorKind::InvalidNumber.into()
+        );
+        // Greater than `u32::MAX`.
+        assert_eq!(
+            DateTime::from_base32_key("4000000").unwrap_err(),
+            ParseErrorKind::InvalidNumber.into()
+        );
+    }
+
+    #[test]
+    fn from_base32_key_with_invalid_component() {
+        // The Day field is 0.
+        assert_eq!(
+            DateTime::from_base32_key("0020000").unwrap_err(),
+            ParseErrorKind::InvalidComponent.into()
+        );
+    }
+
+    #[test]
+    fn from_words() {
+        assert_eq!(
+            DateTime::from_words(DosWords {
+                date: 0b0000_0000_0010_0001,
+                time: u16::MIN
+            })
+            .unwrap(),
+            DateTime::MIN
+        );
+        // <https://github.com/zip-rs/zip/blob/v0.6.4/src/types.rs#L553-L569>.
+        assert_eq!(
+            DateTime::from_words(DosWords {
+                date: 0b0100_1101_0111_0001,
+                time: 0b0101_0100_1100_1111
+            })
+            .unwrap(),
+            DateTime::try_from(datetime!(2018-11-17 10:38:30)).unwrap()
+        );
+        assert_eq!(
+            DateTime::from_words(DosWords {
+                date: 0b1111_1111_1001_1111,
+                time: 0b1011_1111_0111_1101
+            })
+            .unwrap(),
+            DateTime::MAX
+        );
+    }
+
+    #[test]
+    fn from_words_with_invalid_words() {
+        // The Day field is 0.
+        assert!(
+            DateTime::from_words(DosWords {
+                date: 0b0000_0000_0010_0000,
+                time: u16::MIN
+            })
+            .is_none()
+        );
+        // The Hour field is 24.
+        assert!(
+            DateTime::from_words(DosWords {
+                date: 0b0000_0000_0010_0001,
+                time: 0b1100_0000_0000_0000
+            })
+            .is_none()
+        );
+        // The words of "2002-11-26 19:25:00" are swapped.
+        assert!(
+            DateTime::from_words(DosWords {
+                date: 0b1001_1011_0010_0000,
+                time: 0b0010_1101_0111_1010
+            })
+            .is_none()
+        );
+    }
+
+    #[test]
+    fn from_zip_u32() {
+        assert_eq!(DateTime::from_zip_u32(0x0021_0000).unwrap(), DateTime::MIN);
+        // <https://devblogs.microsoft.com/oldnewthing/20030905-02/?p=42653>.
+        assert_eq!(
+            DateTime::from_zip_u32(0b0010_1101_0111_1010_1001_1011_0010_0000).unwrap(),
+            DateTime::try_from(datetime!(2002-11-26 19:25:00)).unwrap()
+        );
+        // <https://github.com/zip-rs/zip/blob/v0.6.4/src/types.rs#L553-L569>.
+        assert_eq!(
+            DateTime::from_zip_u32(0b0100_1101_0111_0001_0101_0100_1100_1111).unwrap(),
+            DateTime::try_from(datetime!(2018-11-17 10:38:30)).unwrap()
+        );
+        assert_eq!(DateTime::from_zip_u32(0xFF9F_BF7D).unwrap(), DateTime::MAX);
+    }
+
+    #[test]
+    const fn from_zip_u32_is_const_fn() {
+        const _: Option<DateTime> = DateTime::from_zip_u32(0x0021_0000);
+    }
+
+    #[test]
+    fn from_zip_u32_agrees_with_zip_bytes() {
+        for dt in [
+            DateTime::MIN,
+            DateTime::try_from(datetime!(2002-11-26 19:25:00)).unwrap(),
+            DateTime::try_from(datetime!(2018-11-17 10:38:30)).unwrap(),
+            DateTime::MAX,
+        ] {
+            let value = u32::from_le_bytes(dt.to_zip_bytes());
+            assert_eq!(DateTime::from_zip_u32(value).unwrap(), dt);
+        }
+    }
+
+    #[test]
+    fn from_zip_u32_with_invalid_value() {
+        // The Day field is 0.
+        assert!(DateTime::from_zip_u32(0x0020_0000).is_none());
+        // The Hour field is 24.
+        assert!(DateTime::from_zip_u32(0x0021_C000).is_none());
+        // The words of "2002-11-26 19:25:00" are swapped.
+        assert!(DateTime::from_zip_u32(0b1001_1011_0010_0000_0010_1101_0111_1010).is_none());
+    }
+
+    #[test]
+    fn from_zip_u32_agrees_with_from_words() {
+        for value in (u32::MIN..=u32::MAX).step_by(65_521) {
+            let words = DosWords {
+                date: u16::try_from(value >> 16).unwrap(),
+                time: u16::try_from(value & 0xFFFF).unwrap(),
+            };
+            assert_eq!(DateTime::from_zip_u32(value), DateTime::from_words(words));
+        }
+        for time in u16::MIN..=u16::MAX {
+            let value = (0x0021 << 16) | u32::from(time);
+            let words = DosWords { date: 0x0021, time };
+            assert_eq!(DateTime::from_zip_u32(value), DateTime::from_words(words));
+        }
+    }
+
+    #[test]
+    fn from_index() {
+        assert_eq!(DateTime::from_index(0).unwrap(), DateTime::MIN);
+        assert_eq!(
+            DateTime::from_index(43199).unwrap(),
+            DateTime::try_from(datetime!(1980-01-01 23:59:58)).unwrap()
+        );
+        assert_eq!(
+            DateTime::from_index(43200).unwrap(),
+            DateTime::try_from(datetime!(1980-01-02 00:00:00)).unwrap()
+        );
+        // <https://devblogs.microsoft.com/oldnewthing/20030905-02/?p=42653>.
+        assert_eq!(
+            DateTime::from_index(361_402_950).unwrap(),
+            DateTime::new(
+                Date::new(0b0010_1101_0111_1010).unwrap(),
+                Time::new(0b1001_1011_0010_0000).unwrap()
+            )
+        );
+        // <https://github.com/zip-rs/zip/blob/v0.6.4/src/types.rs#L553-L569>.
+        assert_eq!(
+            DateTime::from_index(613_459_155).unwrap(),
+            DateTime::new(
+                Date::new(0b0100_1101_0111_0001).unwrap(),
+                Time::new(0b0101_0100_1100_1111).unwrap()
+            )
+        );
+        assert_eq!(
+            DateTime::from_index(DateTime::INDEX_COUNT - 1).unwrap(),
+            DateTime::MAX
+        );
+    }
+
+    #[test]
+    fn from_index_with_out_of_range() {
+        assert!(DateTime::from_index(DateTime::INDEX_COUNT).is_none());
+        assert!(DateTime::from_index(u32::MAX).is_none());
+    }
+
+    #[test]
+    fn from_index_is_contiguous_and_ordered() {
+        // Around the start, the leap day in 1980, and the end.
+        for index in (0..=43200)
+            .chain((59 * 43200)..=(61 * 43200))
+            .chain((DateTime::INDEX_COUNT - 43201)..(DateTime::INDEX_COUNT - 1))
+        {
+            let (dt, next) = (
+                DateTime::from_index(index).unwrap(),
+                DateTime::from_index(index + 1).unwrap(),
+            );
+            assert!(dt < next);
+            assert_eq!(dt.checked_add(time::Duration::seconds(2)).unwrap(), next);
+        }
+    }
+
+    #[test]
+    fn read_le() {
+        let mut cursor = [0x00, 0x00, 0x21, 0x00].as_slice();
+        assert_eq!(DateTime::read_le(&mut cursor).unwrap(), DateTime::MIN);
+        assert!(cursor.is_empty());
+
+        // <https://github.com/zip-rs/zip/blob/v0.6.4/src/types.rs#L553-L569>.
+        let mut cursor = [0xCF, 0x54, 0x71, 0x4D, 0x7D, 0xBF, 0x9F, 0xFF, 0x01].as_slice();
+        assert_eq!(
+            DateTime::read_le(&mut cursor).unwrap(),
+            DateTime::new(
+                Date::new(0b0100_1101_0111_0001).unwrap(),
+                Time::new(0b0101_0100_1100_1111).unwrap()
+            )
+        );
+        assert_eq!(cursor, [0x7D, 0xBF, 0x9F, 0xFF, 0x01]);
+        assert_eq!(DateTime::read_le(&mut cursor).unwrap(), DateTime::MAX);
+        assert_eq!(cursor, [0x01]);
+    }
+
+    #[test]
+    fn read_le_with_unexpected_eof() {
+        let mut cursor = [].as_slice();
+        assert_eq!(
+            DateTime::read_le(&mut cursor).unwrap_err(),
+            ReadErrorKind::UnexpectedEof.into()
+        );
+        assert!(cursor.is_empty());
+
+        let mut cursor = [0x00, 0x00, 0x21].as_slice();
+        assert_eq!(
+            DateTime::read_le(&mut cursor).unwrap_err(),
+            ReadErrorKind::UnexpectedEof.into()
+        );
+        assert_eq!(cursor, [0x00, 0x00, 0x21]);
+    }
+
+    #[test]
+    fn read_le_with_invalid_bits() {
+        // The Day field is 0.
+        let mut cursor = [0x00, 0x00, 0x20, 0x00, 0xFF].as_slice();
+        assert_eq!(
+            DateTime::read_le(&mut cursor).unwrap_err(),
+            ReadErrorKind::InvalidBits.into()
+        );
+        assert_eq!(cursor, [0x00, 0x00, 0x20, 0x00, 0xFF]);
+
+        // The DoubleSeconds field is 30.
+        let mut cursor = [0x1E, 0x00, 0x21, 0x00].as_slice();
+        assert_eq!(
+            DateTime::read_le(&mut cursor).unwrap_err(),
+            ReadErrorKind::InvalidBits.into()
+        );
+        assert_eq!(cursor, [0x1E, 0x00, 0x21, 0x00]);
+    }
+
+    #[cfg(feature = "std")]
+    #[test]
+    fn read_all_zip() {
+        let mut reader = [
+            0x00, 0x00, 0x21, 0x00, // 1980-01-01 00:00:00
+            0xCF, 0x54, 0x71, 0x4D, // 2018-11-17 10:38:30
+            0x00, 0x00, 0x20, 0x00, // The Day field is 0.
+            0x7D, 0xBF, 0x9F, 0xFF, // 2107-12-31 23:59:58
+            0xFF,
+        ]
+        .as_slice();
+        assert_eq!(
+            DateTime::read_all_zip(&mut reader, 4).unwrap(),
+            [
+                Ok(DateTime::MIN),
+                // <https://github.com/zip-rs/zip/blob/v0.6.4/src/types.rs#L553-L569>.
+                Ok(DateTime::try_from(datetime!(2018-11-17 10:38:30)).unwrap()),
+                Err(InvalidDateField::DayZero.into()),
+                Ok(DateTime::MAX),
+            ]
+        );
+        assert_eq!(reader, [0xFF]);
+    }
+
+    #[cfg(feature = "std")]
+    #[test]
+    fn read_all_zip_with_zero_count() {
+        let mut reader = [0x00, 0x00, 0x21, 0x00].as_slice();
+        assert!(DateTime::read_all_zip(&mut reader, 0).unwrap().is_empty());
+        assert_eq!(reader, [0x00, 0x00, 0x21, 0x00]);
+    }
+
+    #[cfg(feature = "std")]
+    #[test]
+    fn read_all_zip_with_unexpected_eof() {
+        let mut reader = [0x00, 0x00, 0x21, 0x00, 0x00, 0x00].as_slice();
+        assert_eq!(
+            DateTime::read_all_zip(&mut reader, 2).unwrap_err().kind(),
+            io::ErrorKind::UnexpectedEof
+        );
+    }
+
+    #[test]
+    fn write_le() {
+        let mut buf = [u8::MAX; 4];
+        assert_eq!(DateTime::MIN.write_le(&mut buf).unwrap(), 4);
+        assert_eq!(buf, [0x00, 0x00, 0x21, 0x00]);
+
+        // <https://github.com/zip-rs/zip/blob/v0.6.4/src/types.rs#L553-L569>.
+        let mut buf = [u8::MAX; 6];
+        assert_eq!(
+            DateTime::new(
+                Date::new(0b0100_1101_0111_0001).unwrap(),
+                Time::new(0b0101_0100_1100_1111).unwrap()
+            )
+            .write_le(&mut buf)
+            .unwrap(),
+            4
+        );
+        assert_eq!(buf, [0xCF, 0x54, 0x71, 0x4D, 0xFF, 0xFF]);
+
+        let mut buf = [u8::MIN; 4];
+        assert_eq!(DateTime::MAX.write_le(&mut buf).unwrap(), 4);
+        assert_eq!(buf, [0x7D, 0xBF, 0x9F, 0xFF]);
+    }
+
+    #[test]
+    fn write_le_with_too_small_buffer() {
+        let mut buf = [u8::MAX; 3];
+        assert_eq!(
+            DateTime::MIN.write_le(&mut buf).unwrap_err(),
+            BufferTooSmallError::new(4, 3)
+        );
+        assert_eq!(buf, [u8::MAX; 3]);
+
+        assert_eq!(
+            DateTime::MIN.write_le(&mut []).unwrap_err(),
+            BufferTooSmallError::new(4, 0)
+        );
+    }
+
+    #[test]
+    fn write_le_roundtrip() {
+        // <https://github.com/zip-rs/zip/blob/v0.6.4/src/types.rs#L553-L569>.
+        let dt = DateTime::new(
+            Date::new(0b0100_1101_0111_0001).unwrap(),
+            Time::new(0b0101_0100_1100_1111).unwrap(),
+        );
+        let mut buf = [u8::MIN; 4];
+        dt.write_le(&mut buf).unwrap();
+        assert_eq!(DateTime::read_le(&mut buf.as_slice()).unwrap(), dt);
+    }
+
+    #[test]
+    fn to_zip_bytes() {
+        assert_eq!(DateTime::MIN.to_zip_bytes(), [0x00, 0x00, 0x21, 0x00]);
+        // <https://github.com/zip-rs/zip/blob/v0.6.4/src/types.rs#L553-L569>.
+        assert_eq!(
+            DateTime::new(
+                Date::new(0b0100_1101_0111_0001).unwrap(),
+                Time::new(0b0101_0100_1100_1111).unwrap()
+            )
+            .to_zip_bytes(),
+            [0xCF, 0x54, 0x71, 0x4D]
+        );
+        assert_eq!(DateTime::MAX.to_zip_bytes(), [0x7D, 0xBF, 0x9F, 0xFF]);
+    }
+
+    #[test]
+    const fn to_zip_bytes_is_const_fn() {
+        const _: [u8; 4] = DateTime::MIN.to_zip_bytes();
+    }
+
+    #[test]
+    fn to_words() {
+        // <https://devblogs.microsoft.com/oldnewthing/20030905-02/?p=42653>.
+        let dt = DateTime::new(
+            Date::new(0b0010_1101_0111_1010).unwrap(),
+            Time::new(0b1001_1011_0010_0000).unwrap(),
+        );
+        assert_eq!(
+            dt.to_words(),
+            DosWords {
+                date: 0b0010_1101_0111_1010,
+                time: 0b1001_1011_0010_0000
+            }
+        );
+        for dt in [DateTime::MIN, dt, DateTime::MAX] {
+            let words = dt.to_words();
+            assert_eq!(words.date, dt.date().to_raw());
+            assert_eq!(words.time, dt.time().to_raw());
+        }
+    }
+
+    #[test]
+    const fn to_words_is_const_fn() {
+        const _: DosWords = DateTime::MIN.to_words();
+    }
+
+    #[test]
+    fn words_round_trip() {
+        for index in (0..DateTime::INDEX_COUNT).step_by(999_983) {
+            let dt = DateTime::from_index(index).unwrap();
+            assert_eq!(DateTime::from_words(dt.to_words()), Some(dt));
+        }
+    }
+
+    #[test]
+    fn to_sort_key() {
+        assert_eq!(DateTime::MIN.to_sort_key(), 0x0021_0000);
+        // <https://devblogs.microsoft.com/oldnewthing/20030905-02/?p=42653>.
+        assert_eq!(
+            DateTime::try_from(datetime!(2002-11-26 19:25:00))
+                .unwrap()
+                .to_sort_key(),
+            0b0010_1101_0111_1010_1001_1011_0010_0000
+        );
+        // <https://github.com/zip-rs/zip/blob/v0.6.4/src/types.rs#L553-L569>.
+        assert_eq!(
+            DateTime::try_from(datetime!(2018-11-17 10:38:30))
+                .unwrap()
+                .to_sort_key(),
+            0b0100_1101_0111_0001_0101_0100_1100_1111
+        );
+        assert_eq!(DateTime::MAX.to_sort_key(), 0xFF9F_BF7D);
+    }
+
+    #[test]
+    fn to_sort_key_is_monotonic() {
+        for i in (0..DateTime::INDEX_COUNT - 1).step_by(99_991) {
+            let (dt, next) = (
+                DateTime::from_index(i).unwrap(),
+                DateTime::from_index(i + 1).unwrap(),
+            );
+            assert!(dt.to_sort_key() < next.to_sort_key());
+        }
+    }
+
+    #[test]
+    fn sort_key_round_trip() {
+        for index in (0..DateTime::INDEX_COUNT).step_by(999_983) {
+            let dt = DateTime::from_index(index).unwrap();
+            assert_eq!(DateTime::from_zip_u32(dt.to_sort_key()), Some(dt));
+        }
+    }
+
+    #[test]
+    const fn to_sort_key_is_const_fn() {
+        const _: u32 = DateTime::MIN.to_sort_key();
+    }
+
+    #[test]
+    fn to_index() {
+        assert_eq!(DateTime::MIN.to_index(), 0);
+        // <https://devblogs.microsoft.com/oldnewthing/20030905-02/?p=42653>.
+        assert_eq!(
+            DateTime::new(
+                Date::new(0b0010_1101_0111_1010).unwrap(),
+                Time::new(0b1001_1011_0010_0000).unwrap()
+            )
+            .to_index(),
+            361_402_950
+        );
+        // <https://github.com/zip-rs/zip/blob/v0.6.4/src/types.rs#L553-L569>.
+        assert_eq!(
+            DateTime::new(
+                Date::new(0b0100_1101_0111_0001).unwrap(),
+                Time::new(0b0101_0100_1100_1111).unwrap()
+            )
+            .to_index(),
+            613_459_155
+        );
+        assert_eq!(DateTime::MAX.to_index(), DateTime::INDEX_COUNT - 1);
+    }
+
+    #[test]
+    fn index_round_trip() {
+        for dt in [
+            DateTime::MIN,
+            DateTime::try_from(datetime!(1980-02-29 12:34:56)).unwrap(),
+            DateTime::try_from(datetime!(2018-11-17 10:38:30)).unwrap(),
+            DateTime::try_from(datetime!(2100-03-01 00:00:00)).unwrap(),
+            DateTime::MAX,
+        ] {
+            assert_eq!(DateTime::from_index(dt.to_index()), Some(dt));
+        }
+        for index in (0..DateTime::INDEX_COUNT).step_by(999_983) {
+            assert_eq!(DateTime::from_index(index).unwrap().to_index(), index);
+        }
+    }
+
+    #[test]
+    fn to_primitive() {
+        assert_eq!(DateTime::MIN.to_primitive(), datetime!(1980-01-01 00:00:00));
+        // <https://devblogs.microsoft.com/oldnewthing/20030905-02/?p=42653>.
+        assert_eq!(
+            DateTime::new(
+                Date::new(0b0010_1101_0111_1010).unwrap(),
+                Time::new(0b1001_1011_0010_0000).unwrap()
+            )
+            .to_primitive(),
+            datetime!(2002-11-26 19:25:00)
+        );
+        // <https://github.com/zip-rs/zip/blob/v0.6.4/src/types.rs#L553-L569>.
+        assert_eq!(
+            DateTime::new(
+                Date::new(0b0100_1101_0111_0001).unwrap(),
+                Time::new(0b0101_0100_1100_1111).unwrap()
+            )
+            .to_primitive(),
+            datetime!(2018-11-17 10:38:30)
+        );
+        assert_eq!(DateTime::MAX.to_primitive(), datetime!(2107-12-31 23:59:58));
+    }
+
+    #[test]
+    const fn to_primitive_is_const_fn() {
+        const _: PrimitiveDateTime = DateTime::MIN.to_primitive();
+    }
+
+    #[test]
+    fn step_by() {
+        let mut iter = DateTime::step_by(DateTime::MIN, Duration::from_secs(3600));
+        assert_eq!(iter.next().unwrap(), DateTime::MIN);
+        assert_eq!(
+            iter.next().unwrap(),
+            DateTime::from_date_time(date!(1980-01-01), time!(01:00:00)).unwrap()
+        );
+        assert_eq!(
+            iter.next().unwrap(),
+            DateTime::from_date_time(date!(1980-01-01), time!(02:00:00)).unwrap()
+        );
+
+        let (count, last) = DateTime::step_by(DateTime::MIN, Duration::from_secs(3600))
+            .fold((0, DateTime::MIN), |(count, _), dt| (count + 1, dt));
+        assert_eq!(count, 46751 * 24);
+        assert_eq!(
+            last,
+            DateTime::from_date_time(date!(2107-12-31), time!(23:00:00)).unwrap()
+        );
+    }
+
+    #[test]
+    fn step_by_with_odd_seconds() {
+        let mut iter = DateTime::step_by(DateTime::MIN, Duration::from_secs(3));
+        assert_eq!(iter.next().unwrap(), DateTime::MIN);
+        assert_eq!(
+            iter.next().unwrap(),
+            DateTime::from_date_time(date!(1980-01-01), time!(00:00:02)).unwrap()
+        );
+        assert_eq!(
+            iter.next().unwrap(),
+            DateTime::from_date_time(date!(1980-01-01), time!(00:00:06)).unwrap()
+        );
+        assert_eq!(
+            iter.next().unwrap(),
+            DateTime::from_date_time(date!(1980-01-01), time!(00:00:08)).unwrap()
+        );
+    }
+
+    #[test]
+    fn step_by_with_too_small_step() {
+        for step in [Duration::ZERO, Duration::from_millis(1999)] {
+            let mut iter = DateTime::step_by(DateTime::MIN, step);
+            assert_eq!(iter.next().unwrap(), DateTime::MIN);
+            assert_eq!(
+                iter.next().unwrap(),
+                DateTime::from_date_time(date!(1980-01-01), time!(00:00:02)).unwrap()
+            );
+            assert_eq!(
+                iter.next().unwrap(),
+                DateTime::from_date_time(date!(1980-01-01), time!(00:00:04)).unwrap()
+            );
+        }
+    }
+
+    #[test]
+    fn step_by_with_too_big_step() {
+        let mut iter = DateTime::step_by(DateTime::MIN, Duration::MAX);
+        assert_eq!(iter.next().unwrap(), DateTime::MIN);
+        assert!(iter.next().is_none());
+
+        let mut iter = DateTime::step_by(DateTime::MAX, Duration::from_secs(2));
+        assert_eq!(iter.next().unwrap(), DateTime::MAX);
+        assert!(iter.next().is_none());
+    }
+
+    #[test]
+    fn is_valid() {
+        assert!(DateTime::MIN.is_valid());
+        // <https://devblogs.microsoft.com/oldnewthing/20030905-02/?p=42653>.
+        assert!(
+            DateTime::new(
+                Date::new(0b0010_1101_0111_1010).unwrap(),
+                Time::new(0b1001_1011_0010_0000).unwrap()
+            )
+            .is_valid()
+        );
+        // <https://github.com/zip-rs/zip/blob/v0.6.4/src/types.rs#L553-L569>.
+        assert!(
+            DateTime::new(
+                Date::new(0b0100_1101_0111_0001).unwrap(),
+                Time::new(0b0101_0100_1100_1111).unwrap()
+            )
+            .is_valid()
+        );
+        assert!(DateTime::MAX.is_valid());
+    }
+
+    #[test]
+    fn is_valid_with_invalid_date() {
+        // The Day field is 0.
+        assert!(
+            !DateTime::new(
+                unsafe { Date::new_unchecked(0b0000_0000_0010_0000) },
+                Time::MIN
+            )
+            .is_valid()
+        );
+        // The Day field is 30, which is after the last day of February.
+        assert!(
+            !DateTime::new(
+                unsafe { Date::new_unchecked(0b0000_0000_0101_1110) },
+                Time::MIN
+            )
+            .is_valid()
+        );
+        // The Month field is 0.
+        assert!(
+            !DateTime::new(
+                unsafe { Date::new_unchecked(0b0000_0000_0000_0001) },
+                Time::MIN
+            )
+            .is_valid()
+        );
+        // The Month field is 13.
+        assert!(
+            !DateTime::new(
+                unsafe { Date::new_unchecked(0b0000_0001_1010_0001) },
+                Time::MIN
+            )
+            .is_valid()
+        );
+    }
+
+    #[test]
+    fn is_valid_with_invalid_time() {
+        // The DoubleSeconds field is 30.
+        assert!(
+            !DateTime::new(Date::MIN, unsafe {
+                Time::new_unchecked(0b0000_0000_0001_1110)
+            })
+            .is_valid()
+        );
+        // The Minute field is 60.
+        assert!(
+            !DateTime::new(Date::MIN, unsafe {
+                Time::new_unchecked(0b0000_0111_1000_0000)
+            })
+            .is_valid()
+        );
+        // The Hour field is 24.
+        assert!(
+            !DateTime::new(Date::MIN, unsafe {
+                Time::new_unchecked(0b1100_0000_0000_0000)
+            })
+            .is_valid()
+        );
+    }
+
+    #[test]
+    fn is_valid_with_invalid_date_time() {
+        assert!(
+            !DateTime::new(unsafe { Date::new_unchecked(u16::MAX) }, unsafe {
+                Time::new_unchecked(u16::MAX)
+            })
+            .is_valid()
+        );
+    }
+
+    #[test]
+    fn normalized() {
+        assert_eq!(DateTime::MIN.normalized(), Some(DateTime::MIN));
+        // <https://devblogs.microsoft.com/oldnewthing/20030905-02/?p=42653>.
+        assert_eq!(
+            DateTime::try_from(datetime!(2002-11-26 19:25:00))
+                .unwrap()
+                .normalized(),
+            Some(DateTime::try_from(datetime!(2002-11-26 19:25:00)).unwrap())
+        );
+        assert_eq!(DateTime::MAX.normalized(), Some(DateTime::MAX));
+    }
+
+    #[test]
+    fn normalized_with_invalid_date_time() {
+        // The Day field is 0.
+        assert!(
+            DateTime::new(
+                unsafe { Date::new_unchecked(0b0000_0000_0010_0000) },
+                Time::MIN
+            )
+            .normalized()
+            .is_none()
+        );
+        // The DoubleSeconds field is 30.
+        assert!(
+            DateTime::new(Date::MIN, unsafe {
+                Time::new_unchecked(0b0000_0000_0001_1110)
+            })
+            .normalized()
+            .is_none()
+        );
+        assert!(
+            DateTime::new(unsafe { Date::new_unchecked(u16::MAX) }, unsafe {
+                Time::new_unchecked(u16::MAX)
+            })
+            .normalized()
+            .is_none()
+        );
+    }
+
+    #[test]
+    fn invalid_reason() {
+        assert!(DateTime::MIN.invalid_reason().is_none());
+        // <https://devblogs.microsoft.com/oldnewthing/20030905-02/?p=42653>.
+        assert!(
+            DateTime::try_from(datetime!(2002-11-26 19:25:00))
+                .unwrap()
+                .invalid_reason()
+                .is_none()
+        );
+        assert!(DateTime::MAX.invalid_reason().is_none());
+    }
+
+    #[test]
+    fn invalid_reason_with_invalid_date() {
+        // The Month field is 0.
+        assert_eq!(
+            DateTime::new(
+                unsafe { Date::new_unchecked(0b0000_0000_0000_0001) },
+                Time::MIN
+            )
+            .invalid_reason(),
+            Some(InvalidDateField::MonthZero.into())
+        );
+        // The Month field is 13.
+        assert_eq!(
+            DateTime::new(
+                unsafe { Date::new_unchecked(0b0000_0001_1010_0001) },
+                Time::MIN
+            )
+            .invalid_reason(),
+            Some(InvalidDateField::MonthTooLarge(13).into())
+        );
+        // The Day field is 0.
+        assert_eq!(
+            DateTime::new(
+                unsafe { Date::new_unchecked(0b0000_0000_0010_0000) },
+                Time::MIN
+            )
+            .invalid_reason(),
+            Some(InvalidDateField::DayZero.into())
+        );
+        // The Day field is 30, which is after the last day of February.
+        assert_eq!(
+            DateTime::new(
+                unsafe { Date::new_unchecked(0b0000_0000_0101_1110) },
+                Time::MIN
+            )
+            .invalid_reason(),
+            Some(InvalidDateField::DayTooLarge { day: 30, max: 29 }.into())
+        );
+    }
+
+    #[test]
+    fn invalid_reason_with_invalid_time() {
+        // The Hour field is 24.
+        assert_eq!(
+            DateTime::new(Date::MIN, unsafe {
+                Time::new_unchecked(0b1100_0000_0000_0000)
+            })
+            .invalid_reason(),
+            Some(InvalidTimeField::HourTooLarge(24).into())
+        );
+        // The Minute field is 60.
+        assert_eq!(
+            DateTime::new(Date::MIN, unsafe {
+                Time::new_unchecked(0b0000_0111_1000_0000)
+            })
+            .invalid_reason(),
+            Some(InvalidTimeField::MinuteTooLarge(60).into())
+        );
+        // The DoubleSeconds field is 30.
+        assert_eq!(
+            DateTime::new(Date::MIN, unsafe {
+                Time::new_unchecked(0b0000_0000_0001_1110)
+            })
+            .invalid_reason(),
+            Some(InvalidTimeField::DoubleSecondsTooLarge(30).into())
+        );
+    }
+
+    #[test]
+    fn invalid_reason_with_invalid_date_time() {
+        assert_eq!(
+            DateTime::new(unsafe { Date::new_unchecked(u16::MAX) }, unsafe {
+                Time::new_unchecked(u16::MAX)
+            })
+            .invalid_reason(),
+            Some(InvalidDateField::MonthTooLarge(15).into())
+        );
+    }
+
+    #[test]
+    fn invalid_reason_agrees_with_is_valid() {
+        for date in (u16::MIN..=u16::MAX).step_by(7) {
+            let dt = DateTime::new(unsafe { Date::new_unchecked(date) }, Time::MIN);
+            assert_eq!(dt.invalid_reason().is_none(), dt.is_valid());
+        }
+    }
+
+    #[test]
+    fn clamp() {
+        let (min, max) = (
+            DateTime::try_from(datetime!(2002-11-26 19:25:00)).unwrap(),
+            DateTime::try_from(datetime!(2107-01-01 00:00:00)).unwrap(),
+        );
+        assert_eq!(DateTime::MIN.clamp(min, max), min);
+        assert_eq!(min.clamp(min, max), min);
+        assert_eq!(
+            DateTime::try_from(datetime!(2018-11-17 10:38:30))
+                .unwrap()
+                .clamp(min, max),
+            DateTime::try_from(datetime!(2018-11-17 10:38:30)).unwrap()
+        );
+        assert_eq!(max.clamp(min, max), max);
+        assert_eq!(DateTime::MAX.clamp(min, max), max);
+        assert_eq!(
+            DateTime::MIN.clamp(DateTime::MIN, DateTime::MAX),
+            DateTime::MIN
+        );
+        assert_eq!(
+            DateTime::MAX.clamp(DateTime::MIN, DateTime::MAX),
+            DateTime::MAX
+        );
+    }
+
+    #[test]
+    #[should_panic(expected = "`min` should be less than or equal to `max`")]
+    fn clamp_with_min_greater_than_max() {
+        let _ = DateTime::MIN.clamp(DateTime::MAX, DateTime::MIN);
+    }
+
+    #[test]
+    fn clamp_year() {
+        // <https://github.com/zip-rs/zip/blob/v0.6.4/src/types.rs#L553-L569>.
+        let dt = DateTime::try_from(datetime!(2018-11-17 10:38:30)).unwrap();
+        assert_eq!(dt.clamp_year(1980, 2107), dt);
+        assert_eq!(dt.clamp_year(2018, 2018), dt);
+        assert_eq!(
+            dt.clamp_year(2020, 2030),
+            DateTime::try_from(datetime!(2020-11-17 10:38:30)).unwrap()
+        );
+        assert_eq!(
+            dt.clamp_year(1990, 1999),
+            DateTime::try_from(datetime!(1999-11-17 10:38:30)).unwrap()
+        );
+        assert_eq!(
+            DateTime::MIN.clamp_year(2107, 2107),
+            DateTime::try_from(datetime!(2107-01-01 00:00:00)).unwrap()
+        );
+    }
+
+    #[test]
+    fn clamp_year_with_leap_day() {
+        let dt = DateTime::try_from(datetime!(2000-02-29 12:34:56)).unwrap();
+        assert_eq!(dt.clamp_year(1990, 2010), dt);
+        // `1999` is not a leap year.
+        assert_eq!(
+            dt.clamp_year(1990, 1999),
+            DateTime::try_from(datetime!(1999-02-28 12:34:56)).unwrap()
+        );
+        // `1996` is a leap year.
+        assert_eq!(
+            dt.clamp_year(1990, 1996),
+            DateTime::try_from(datetime!(1996-02-29 12:34:56)).unwrap()
+        );
+        // `2100` is not a leap year.
+        assert_eq!(
+            dt.clamp_year(2100, 2107),
+            DateTime::try_from(datetime!(2100-02-28 12:34:56)).unwrap()
+        );
+    }
+
+    #[test]
+    fn clamp_year_with_out_of_range() {
+        // <https://github.com/zip-rs/zip/blob/v0.6.4/src/types.rs#L553-L569>.
+        let dt = DateTime::try_from(datetime!(2018-11-17 10:38:30)).unwrap();
+        assert_eq!(
+            dt.clamp_year(u16::MIN, 1970),
+            DateTime::try_from(datetime!(1980-11-17 10:38:30)).unwrap()
+        );
+        assert_eq!(
+            dt.clamp_year(2200, u16::MAX),
+            DateTime::try_from(datetime!(2107-11-17 10:38:30)).unwrap()
+        );
+    }
+
+    #[test]
+    #[should_panic(expected = "`min_year` should be less than or equal to `max_year`")]
+    fn clamp_year_with_invalid_range() {
+        let _ = DateTime::MIN.clamp_year(2107, 1980);
+    }
+
+    #[test]
+    fn envelope() {
+        let dts = [
+            // <https://github.com/zip-rs/zip/blob/v0.6.4/src/types.rs#L553-L569>.
+            DateTime::try_from(datetime!(2018-11-17 10:38:30)).unwrap(),
+            DateTime::MAX,
+            // <https://devblogs.microsoft.com/oldnewthing/20030905-02/?p=42653>.
+            DateTime::try_from(datetime!(2002-11-26 19:25:00)).unwrap(),
+            DateTime::MIN,
+        ];
+        assert_eq!(
+            DateTime::envelope(dts),
+            Some((DateTime::MIN, DateTime::MAX))
+        );
+        assert_eq!(
+            DateTime::envelope(dts[..1].iter().copied()),
+            Some((dts[0], dts[0]))
+        );
+        assert_eq!(
+            DateTime::envelope(dts[..3].iter().copied()),
+            Some((dts[2], dts[1]))
+        );
+    }
+
+    #[test]
+    fn envelope_with_empty() {
+        assert_eq!(DateTime::envelope([]), None);
+        assert_eq!(DateTime::envelope(iter::empty()), None);
+    }
+
+    #[test]
+    fn bisect() {
+        let sorted = [
+            DateTime::MIN,
+            // <https://devblogs.microsoft.com/oldnewthing/20030905-02/?p=42653>.
+            DateTime::try_from(datetime!(2002-11-26 19:25:00)).unwrap(),
+            // <https://github.com/zip-rs/zip/blob/v0.6.4/src/types.rs#L553-L569>.
+            DateTime::try_from(datetime!(2018-11-17 10:38:30)).unwrap(),
+            DateTime::MAX,
+        ];
+        for (i, dt) in sorted.into_iter().enumerate() {
+            assert_eq!(DateTime::bisect(&sorted, dt), Ok(i));
+        }
+    }
+
+    #[test]
+    fn bisect_with_absent_target() {
+        let sorted = [
+            // <https://devblogs.microsoft.com/oldnewthing/20030905-02/?p=42653>.
+            DateTime::try_from(datetime!(2002-11-26 19:25:00)).unwrap(),
+            // <https://github.com/zip-rs/zip/blob/v0.6.4/src/types.rs#L553-L569>.
+            DateTime::try_from(datetime!(2018-11-17 10:38:30)).unwrap(),
+        ];
+        assert_eq!(DateTime::bisect(&sorted, DateTime::MIN), Err(0));
+        assert_eq!(
+            DateTime::bisect(
+                &sorted,
+                DateTime::try_from(datetime!(2002-11-26 19:25:02)).unwrap()
+            ),
+            Err(1)
+        );
+        assert_eq!(DateTime::bisect(&sorted, DateTime::MAX), Err(2));
+        assert_eq!(DateTime::bisect(&[], DateTime::MIN), Err(0));
+    }
+
+    #[test]
+    fn bisect_agrees_with_chronological_order() {
+        let mut dts = [
+            DateTime::MAX,
+            // <https://github.com/zip-rs/zip/blob/v0.6.4/src/types.rs#L553-L569>.
+            DateTime::try_from(datetime!(2018-11-17 10:38:30)).unwrap(),
+            DateTime::MIN,
+            // <https://devblogs.microsoft.com/oldnewthing/20030905-02/?p=42653>.
+            DateTime::try_from(datetime!(2002-11-26 19:25:00)).unwrap(),
+        ];
+        dts.sort_unstable();
+        assert!(
+            dts.windows(2)
+                .all(|w| PrimitiveDateTime::from(w[0]) < PrimitiveDateTime::from(w[1]))
+        );
+        assert_eq!(DateTime::bisect(&dts, DateTime::MAX), Ok(3));
+    }
+
+    #[test]
+    fn duration_until() {
+        assert_eq!(
+            DateTime::MIN.duration_until(datetime!(1980-01-01 00:00:00)),
+            time::Duration::ZERO
+        );
+        assert_eq!(
+            DateTime::MIN.duration_until(datetime!(1980-01-01 00:00:01)),
+            time::Duration::SECOND
+        );
+        // <https://devblogs.microsoft.com/oldnewthing/20030905-02/?p=42653>.
+        assert_eq!(
+            DateTime::MIN.duration_until(datetime!(2002-11-26 19:25:00)),
+            time::Duration::seconds(722_805_900)
+        );
+        assert_eq!(
+            DateTime::MAX.duration_until(datetime!(2108-01-01 00:00:00)),
+            time::Duration::seconds(2)
+        );
+    }
+
+    #[test]
+    fn duration_until_with_earlier_date_time() {
+        assert_eq!(
+            DateTime::MIN.duration_until(datetime!(1979-12-31 23:59:59)),
+            -time::Duration::SECOND
+        );
+        // <https://devblogs.microsoft.com/oldnewthing/20030905-02/?p=42653>.
+        assert_eq!(
+            DateTime::try_from(datetime!(2002-11-26 19:25:00))
+                .unwrap()
+                .duration_until(datetime!(1980-01-01 00:00:00)),
+            time::Duration::seconds(-722_805_900)
+        );
+        assert_eq!(
+            DateTime::MAX.duration_until(datetime!(2107-12-31 00:00:00)),
+            -time::Duration::seconds(86398)
+        );
+    }
+
+    #[test]
+    fn age_from() {
+        assert_eq!(DateTime::MIN.age_from(DateTime::MIN), time::Duration::ZERO);
+        assert_eq!(
+            DateTime::MIN.age_from(DateTime::MAX),
+            time::Duration::seconds(4_039_286_398)
+        );
+        assert_eq!(
+            DateTime::MAX.age_from(DateTime::MIN),
+            time::Duration::seconds(-4_039_286_398)
+        );
+        // <https://github.com/zip-rs/zip/blob/v0.6.4/src/types.rs#L553-L569>.
+        let dt = DateTime::try_from(datetime!(2018-11-17 10:38:30)).unwrap();
+        assert_eq!(
+            dt.age_from(DateTime::try_from(datetime!(2018-11-20 10:38:30)).unwrap()),
+            time::Duration::days(3)
+        );
+        assert_eq!(
+            dt.age_from(DateTime::try_from(datetime!(2018-11-17 10:38:28)).unwrap()),
+            time::Duration::seconds(-2)
+        );
+    }
+
+    #[test]
+    fn approx_eq() {
+        // <https://github.com/zip-rs/zip/blob/v0.6.4/src/types.rs#L553-L569>.
+        let dt = DateTime::try_from(datetime!(2018-11-17 10:38:30)).unwrap();
+        let other = DateTime::try_from(datetime!(2018-11-17 10:38:32)).unwrap();
+        assert!(dt.approx_eq(other, Duration::from_secs(3)));
+        assert!(other.approx_eq(dt, Duration::from_secs(3)));
+        assert!(dt.approx_eq(other, Duration::from_secs(2)));
+        assert!(!dt.approx_eq(other, Duration::from_secs(1)));
+        assert!(!other.approx_eq(dt, Duration::from_secs(1)));
+        assert!(!dt.approx_eq(other, Duration::from_millis(1999)));
+    }
+
+    #[test]
+    fn approx_eq_with_zero_tolerance() {
+        assert!(DateTime::MIN.approx_eq(DateTime::MIN, Duration::ZERO));
+        assert!(DateTime::MAX.approx_eq(DateTime::MAX, Duration::ZERO));
+        assert!(!DateTime::MIN.approx_eq(DateTime::MAX, Duration::ZERO));
+    }
+
+    #[test]
+    fn approx_eq_with_large_tolerance() {
+        assert!(DateTime::MIN.approx_eq(DateTime::MAX, Duration::from_secs(4_039_286_398)));
+        assert!(!DateTime::MIN.approx_eq(DateTime::MAX, Duration::from_secs(4_039_286_397)));
+        assert!(DateTime::MAX.approx_eq(DateTime::MIN, Duration::MAX));
+    }
+
+    #[test]
+    fn checked_add() {
+        // <https://github.com/zip-rs/zip/blob/v0.6.4/src/types.rs#L553-L569>.
+        let dt = DateTime::try_from(datetime!(2018-11-17 10:38:30)).unwrap();
+        assert_eq!(dt.checked_add(time::Duration::ZERO).unwrap(), dt);
+        assert_eq!(
+            dt.checked_add(time::Duration::hours(1)).unwrap(),
+            DateTime::try_from(datetime!(2018-11-17 11:38:30)).unwrap()
+        );
+        assert_eq!(
+            dt.checked_add(-time::Duration::hours(1)).unwrap(),
+            DateTime::try_from(datetime!(2018-11-17 09:38:30)).unwrap()
+        );
+        assert_eq!(
+            dt.checked_add(-time::Duration::SECOND).unwrap(),
+            DateTime::try_from(datetime!(2018-11-17 10:38:28)).unwrap()
+        );
+        assert_eq!(
+            DateTime::MIN
+                .checked_add(time::Duration::seconds(4_039_286_398))
+                .unwrap(),
+            DateTime::MAX
+        );
+        assert_eq!(
+            DateTime::MAX
+                .checked_add(time::Duration::seconds(-4_039_286_398))
+                .unwrap(),
+            DateTime::MIN
+        );
+    }
+
+    #[test]
+    fn checked_add_with_out_of_range() {
+        assert!(
+            DateTime::MAX
+                .checked_add(time::Duration::seconds(2))
+                .is_none()
+        );
+        assert!(DateTime::MIN.checked_add(-time::Duration::SECOND).is_none());
+        assert!(DateTime::MIN.checked_add(time::Duration::MIN).is_none());
+        assert!(DateTime::MAX.checked_add(time::Duration::MAX).is_none());
+    }
+
+    #[test]
+    fn checked_sub() {
+        // <https://github.com/zip-rs/zip/blob/v0.6.4/src/types.rs#L553-L569>.
+        let dt = DateTime::try_from(datetime!(2018-11-17 10:38:30)).unwrap();
+        assert_eq!(dt.checked_sub(time::Duration::ZERO).unwrap(), dt);
+        assert_eq!(
+            dt.checked_sub(time::Duration::hours(1)).unwrap(),
+            DateTime::try_from(datetime!(2018-11-17 09:38:30)).unwrap()
+        );
+        assert_eq!(
+            dt.checked_sub(-time::Duration::hours(1)).unwrap(),
+            DateTime::try_from(datetime!(2018-11-17 11:38:30)).unwrap()
+        );
+        assert_eq!(
+            DateTime::MAX
+                .checked_sub(time::Duration::seconds(4_039_286_398))
+                .unwrap(),
+            DateTime::MIN
+        );
+    }
+
+    #[test]
+    fn checked_sub_with_out_of_range() {
+        assert!(
+            DateTime::MIN
+                .checked_sub(time::Duration::seconds(2))
+                .is_none()
+        );
+        assert!(
+            DateTime::MAX
+                .checked_sub(-time::Duration::seconds(2))
+                .is_none()
+        );
+        assert!(DateTime::MIN.checked_sub(time::Duration::MAX).is_none());
+        assert!(DateTime::MAX.checked_sub(time::Duration::MIN).is_none());
+    }
+
+    #[test]
+    fn saturating_add() {
+        // <https://github.com/zip-rs/zip/blob/v0.6.4/src/types.rs#L553-L569>.
+        let dt = DateTime::try_from(datetime!(2018-11-17 10:38:30)).unwrap();
+        assert_eq!(
+            dt.saturating_add(time::Duration::hours(1)),
+            DateTime::try_from(datetime!(2018-11-17 11:38:30)).unwrap()
+        );
+        assert_eq!(
+            dt.saturating_add(-time::Duration::hours(1)),
+            DateTime::try_from(datetime!(2018-11-17 09:38:30)).unwrap()
+        );
+        assert_eq!(
+            DateTime::MAX.saturating_add(time::Duration::seconds(2)),
+            DateTime::MAX
+        );
+        assert_eq!(
+            DateTime::MIN.saturating_add(-time::Duration::SECOND),
+            DateTime::MIN
+        );
+        assert_eq!(
+            DateTime::MIN.saturating_add(time::Duration::MAX),
+            DateTime::MAX
+        );
+        assert_eq!(
+            DateTime::MAX.saturating_add(time::Duration::MIN),
+            DateTime::MIN
+        );
+    }
+
+    #[test]
+    fn saturating_sub() {
+        // <https://github.com/zip-rs/zip/blob/v0.6.4/src/types.rs#L553-L569>.
+        let dt = DateTime::try_from(datetime!(2018-11-17 10:38:30)).unwrap();
+        assert_eq!(
+            dt.saturating_sub(time::Duration::hours(1)),
+            DateTime::try_from(datetime!(2018-11-17 09:38:30)).unwrap()
+        );
+        assert_eq!(
+            dt.saturating_sub(-time::Duration::hours(1)),
+            DateTime::try_from(datetime!(2018-11-17 11:38:30)).unwrap()
+        );
+        assert_eq!(
+            DateTime::MIN.saturating_sub(time::Duration::seconds(2)),
+            DateTime::MIN
+        );
+        assert_eq!(
+            DateTime::MAX.saturating_sub(-time::Duration::seconds(2)),
+            DateTime::MAX
+        );
+        assert_eq!(
+            DateTime::MIN.saturating_sub(time::Duration::MAX),
+            DateTime::MIN
+        );
+        assert_eq!(
+            DateTime::MAX.saturating_sub(time::Duration::MIN),
+            DateTime::MAX
+        );
+    }
 
-    use time::macros::{date, time};
+    #[test]
+    fn checked_next() {
+        assert_eq!(
+            DateTime::MIN.checked_next(),
+            Some(DateTime::try_from(datetime!(1980-01-01 00:00:02)).unwrap())
+        );
+        // <https://github.com/zip-rs/zip/blob/v0.6.4/src/types.rs#L553-L569>.
+        assert_eq!(
+            DateTime::try_from(datetime!(2018-11-17 10:38:30))
+                .unwrap()
+                .checked_next(),
+            Some(DateTime::try_from(datetime!(2018-11-17 10:38:32)).unwrap())
+        );
+        assert_eq!(
+            DateTime::try_from(datetime!(2018-11-17 10:38:58))
+                .unwrap()
+                .checked_next(),
+            Some(DateTime::try_from(datetime!(2018-11-17 10:39:00)).unwrap())
+        );
+        assert_eq!(
+            DateTime::try_from(datetime!(2018-11-17 10:59:58))
+                .unwrap()
+                .checked_next(),
+            Some(DateTime::try_from(datetime!(2018-11-17 11:00:00)).unwrap())
+        );
+        assert_eq!(
+            DateTime::try_from(datetime!(2000-02-28 23:59:58))
+                .unwrap()
+                .checked_next(),
+            Some(DateTime::try_from(datetime!(2000-02-29 00:00:00)).unwrap())
+        );
+        assert_eq!(
+            DateTime::try_from(datetime!(2001-12-31 23:59:58))
+                .unwrap()
+                .checked_next(),
+            Some(DateTime::try_from(datetime!(2002-01-01 00:00:00)).unwrap())
+        );
+        assert_eq!(DateTime::MAX.checked_next(), None);
+    }
 
-    use super::*;
-    use crate::error::DateTimeRangeErrorKind;
+    #[test]
+    fn checked_next_matches_index() {
+        for i in (0..DateTime::INDEX_COUNT - 1).step_by(9973) {
+            let dt = DateTime::from_index(i).unwrap();
+            assert_eq!(dt.checked_next(), DateTime::from_index(i + 1));
+        }
+    }
 
     #[test]
-    fn clone() {
-        assert_eq!(DateTime::MIN.clone(), DateTime::MIN);
+    const fn checked_next_is_const_fn() {
+        const _: Option<DateTime> = DateTime::MIN.checked_next();
     }
 
     #[test]
-    fn copy() {
-        let a = DateTime::MIN;
-        let b = a;
-        assert_eq!(a, b);
+    fn checked_prev() {
+        assert_eq!(DateTime::MIN.checked_prev(), None);
+        // <https://github.com/zip-rs/zip/blob/v0.6.4/src/types.rs#L553-L569>.
+        assert_eq!(
+            DateTime::try_from(datetime!(2018-11-17 10:38:30))
+                .unwrap()
+                .checked_prev(),
+            Some(DateTime::try_from(datetime!(2018-11-17 10:38:28)).unwrap())
+        );
+        assert_eq!(
+            DateTime::try_from(datetime!(2018-11-17 10:39:00))
+                .unwrap()
+                .checked_prev(),
+            Some(DateTime::try_from(datetime!(2018-11-17 10:38:58)).unwrap())
+        );
+        assert_eq!(
+            DateTime::try_from(datetime!(2018-11-17 11:00:00))
+                .unwrap()
+                .checked_prev(),
+            Some(DateTime::try_from(datetime!(2018-11-17 10:59:58)).unwrap())
+        );
+        assert_eq!(
+            DateTime::try_from(datetime!(2000-03-01 00:00:00))
+                .unwrap()
+                .checked_prev(),
+            Some(DateTime::try_from(datetime!(2000-02-29 23:59:58)).unwrap())
+        );
+        assert_eq!(
+            DateTime::try_from(datetime!(2002-01-01 00:00:00))
+                .unwrap()
+                .checked_prev(),
+            Some(DateTime::try_from(datetime!(2001-12-31 23:59:58)).unwrap())
+        );
+        assert_eq!(
+            DateTime::MAX.checked_prev(),
+            Some(DateTime::try_from(datetime!(2107-12-31 23:59:56)).unwrap())
+        );
     }
 
-    #[cfg(feature = "std")]
     #[test]
-    fn hash() {
-        assert_ne!(
-            {
-                let mut hasher = DefaultHasher::new();
-                DateTime::MIN.hash(&mut hasher);
-                hasher.finish()
-            },
-            {
-                let mut hasher = DefaultHasher::new();
-                DateTime::MAX.hash(&mut hasher);
-                hasher.finish()
-            }
+    fn checked_prev_matches_index() {
+        for i in (1..DateTime::INDEX_COUNT).step_by(9973) {
+            let dt = DateTime::from_index(i).unwrap();
+            assert_eq!(dt.checked_prev(), DateTime::from_index(i - 1));
+        }
+    }
+
+    #[test]
+    const fn checked_prev_is_const_fn() {
+        const _: Option<DateTime> = DateTime::MAX.checked_prev();
+    }
+
+    #[test]
+    fn saturating_next() {
+        assert_eq!(
+            DateTime::MIN.saturating_next(),
+            DateTime::try_from(datetime!(1980-01-01 00:00:02)).unwrap()
+        );
+        assert_eq!(
+            DateTime::try_from(datetime!(2001-12-31 23:59:58))
+                .unwrap()
+                .saturating_next(),
+            DateTime::try_from(datetime!(2002-01-01 00:00:00)).unwrap()
         );
+        assert_eq!(DateTime::MAX.saturating_next(), DateTime::MAX);
     }
 
     #[test]
-    fn new() {
-        assert_eq!(DateTime::new(Date::MIN, Time::MIN), DateTime::MIN);
-        assert_eq!(DateTime::new(Date::MAX, Time::MAX), DateTime::MAX);
+    const fn saturating_next_is_const_fn() {
+        const _: DateTime = DateTime::MAX.saturating_next();
     }
 
     #[test]
-    const fn new_is_const_fn() {
-        const _: DateTime = DateTime::new(Date::MIN, Time::MIN);
+    fn saturating_prev() {
+        assert_eq!(
+            DateTime::MAX.saturating_prev(),
+            DateTime::try_from(datetime!(2107-12-31 23:59:56)).unwrap()
+        );
+        assert_eq!(
+            DateTime::try_from(datetime!(2002-01-01 00:00:00))
+                .unwrap()
+                .saturating_prev(),
+            DateTime::try_from(datetime!(2001-12-31 23:59:58)).unwrap()
+        );
+        assert_eq!(DateTime::MIN.saturating_prev(), DateTime::MIN);
     }
 
     #[test]
-    fn from_date_time_before_dos_date_time_epoch() {
+    const fn saturating_prev_is_const_fn() {
+        const _: DateTime = DateTime::MIN.saturating_prev();
+    }
+
+    #[test]
+    fn checked_add_days() {
+        // <https://github.com/zip-rs/zip/blob/v0.6.4/src/types.rs#L553-L569>.
+        let dt = DateTime::try_from(datetime!(2018-11-17 10:38:30)).unwrap();
+        assert_eq!(dt.checked_add_days(0).unwrap(), dt);
         assert_eq!(
-            DateTime::from_date_time(date!(1979-12-31), time!(23:59:58)).unwrap_err(),
-            DateTimeRangeErrorKind::Negative.into()
+            dt.checked_add_days(14).unwrap(),
+            DateTime::try_from(datetime!(2018-12-01 10:38:30)).unwrap()
         );
         assert_eq!(
-            DateTime::from_date_time(date!(1979-12-31), time!(23:59:59)).unwrap_err(),
-            DateTimeRangeErrorKind::Negative.into()
+            dt.checked_add_days(-1).unwrap(),
+            DateTime::try_from(datetime!(2018-11-16 10:38:30)).unwrap()
+        );
+        assert_eq!(
+            DateTime::MIN.checked_add_days(46750).unwrap(),
+            DateTime::try_from(datetime!(2107-12-31 00:00:00)).unwrap()
         );
     }
 
     #[test]
-    fn from_date_time() {
+    fn checked_add_days_with_out_of_range() {
+        assert!(DateTime::MAX.checked_add_days(1).is_none());
+        assert!(DateTime::MIN.checked_add_days(-1).is_none());
+        assert!(DateTime::MIN.checked_add_days(i32::MAX).is_none());
+        assert!(DateTime::MAX.checked_add_days(i32::MIN).is_none());
+    }
+
+    #[test]
+    fn checked_sub_days() {
+        // <https://github.com/zip-rs/zip/blob/v0.6.4/src/types.rs#L553-L569>.
+        let dt = DateTime::try_from(datetime!(2018-11-17 10:38:30)).unwrap();
+        assert_eq!(dt.checked_sub_days(0).unwrap(), dt);
         assert_eq!(
-            DateTime::from_date_time(date!(1980-01-01), time::Time::MIDNIGHT).unwrap(),
-            DateTime::MIN
+            dt.checked_sub_days(17).unwrap(),
+            DateTime::try_from(datetime!(2018-10-31 10:38:30)).unwrap()
         );
         assert_eq!(
-            DateTime::from_date_time(date!(1980-01-01), time!(00:00:01)).unwrap(),
-            DateTime::MIN
+            dt.checked_sub_days(-1).unwrap(),
+            DateTime::try_from(datetime!(2018-11-18 10:38:30)).unwrap()
+        );
+    }
+
+    #[test]
+    fn checked_sub_days_with_out_of_range() {
+        assert!(DateTime::MIN.checked_sub_days(1).is_none());
+        assert!(DateTime::MAX.checked_sub_days(-1).is_none());
+    }
+
+    #[test]
+    fn checked_add_months() {
+        // <https://github.com/zip-rs/zip/blob/v0.6.4/src/types.rs#L553-L569>.
+        let dt = DateTime::try_from(datetime!(2018-11-17 10:38:30)).unwrap();
+        assert_eq!(dt.checked_add_months(0).unwrap(), dt);
+        assert_eq!(
+            dt.checked_add_months(2).unwrap(),
+            DateTime::try_from(datetime!(2019-01-17 10:38:30)).unwrap()
+        );
+        assert_eq!(
+            DateTime::try_from(datetime!(2000-01-31 12:34:56))
+                .unwrap()
+                .checked_add_months(1)
+                .unwrap(),
+            DateTime::try_from(datetime!(2000-02-29 12:34:56)).unwrap()
+        );
+    }
+
+    #[test]
+    fn checked_add_months_with_out_of_range() {
+        assert!(DateTime::MAX.checked_add_months(1).is_none());
+        assert!(DateTime::MIN.checked_add_months(-1).is_none());
+    }
+
+    #[test]
+    fn checked_sub_months() {
+        // <https://github.com/zip-rs/zip/blob/v0.6.4/src/types.rs#L553-L569>.
+        let dt = DateTime::try_from(datetime!(2018-11-17 10:38:30)).unwrap();
+        assert_eq!(dt.checked_sub_months(0).unwrap(), dt);
+        assert_eq!(
+            dt.checked_sub_months(11).unwrap(),
+            DateTime::try_from(datetime!(2017-12-17 10:38:30)).unwrap()
+        );
+        assert_eq!(
+            DateTime::MAX.checked_sub_months(1).unwrap(),
+            DateTime::try_from(datetime!(2107-11-30 23:59:58)).unwrap()
+        );
+    }
+
+    #[test]
+    fn checked_sub_months_with_out_of_range() {
+        assert!(DateTime::MIN.checked_sub_months(1).is_none());
+        assert!(DateTime::MAX.checked_sub_months(-1).is_none());
+    }
+
+    #[test]
+    fn date() {
+        assert_eq!(DateTime::MIN.date(), Date::MIN);
+        // <https://devblogs.microsoft.com/oldnewthing/20030905-02/?p=42653>.
+        assert_eq!(
+            DateTime::new(
+                Date::new(0b0010_1101_0111_1010).unwrap(),
+                Time::new(0b1001_1011_0010_0000).unwrap()
+            )
+            .date(),
+            Date::new(0b0010_1101_0111_1010).unwrap()
+        );
+        // <https://github.com/zip-rs/zip/blob/v0.6.4/src/types.rs#L553-L569>.
+        assert_eq!(
+            DateTime::new(
+                Date::new(0b0100_1101_0111_0001).unwrap(),
+                Time::new(0b0101_0100_1100_1111).unwrap()
+            )
+            .date(),
+            Date::new(0b0100_1101_0111_0001).unwrap()
+        );
+        assert_eq!(DateTime::MAX.date(), Date::MAX);
+    }
+
+    #[test]
+    const fn date_is_const_fn() {
+        const _: Date = DateTime::MIN.date();
+    }
+
+    #[test]
+    fn time() {
+        assert_eq!(DateTime::MIN.time(), Time::MIN);
+        // <https://devblogs.microsoft.com/oldnewthing/20030905-02/?p=42653>.
+        assert_eq!(
+            DateTime::new(
+                Date::new(0b0010_1101_0111_1010).unwrap(),
+                Time::new(0b1001_1011_0010_0000).unwrap()
+            )
+            .time(),
+            Time::new(0b1001_1011_0010_0000).unwrap()
+        );
+        // <https://github.com/zip-rs/zip/blob/v0.6.4/src/types.rs#L553-L569>.
+        assert_eq!(
+            DateTime::new(
+                Date::new(0b0100_1101_0111_0001).unwrap(),
+                Time::new(0b0101_0100_1100_1111).unwrap()
+            )
+            .time(),
+            Time::new(0b0101_0100_1100_1111).unwrap()
         );
+        assert_eq!(DateTime::MAX.time(), Time::MAX);
+    }
+
+    #[test]
+    const fn time_is_const_fn() {
+        const _: Time = DateTime::MIN.time();
+    }
+
+    #[test]
+    fn as_tuple() {
+        assert_eq!(DateTime::MIN.as_tuple(), (Date::MIN, Time::MIN));
         // <https://devblogs.microsoft.com/oldnewthing/20030905-02/?p=42653>.
         assert_eq!(
-            DateTime::from_date_time(date!(2002-11-26), time!(19:25:00)).unwrap(),
             DateTime::new(
                 Date::new(0b0010_1101_0111_1010).unwrap(),
                 Time::new(0b1001_1011_0010_0000).unwrap()
             )
+            .as_tuple(),
+            (
+                Date::new(0b0010_1101_0111_1010).unwrap(),
+                Time::new(0b1001_1011_0010_0000).unwrap()
+            )
+        );
+        assert_eq!(DateTime::MAX.as_tuple(), (Date::MAX, Time::MAX));
+    }
+
+    #[test]
+    const fn as_tuple_is_const_fn() {
+        const _: (Date, Time) = DateTime::MIN.as_tuple();
+    }
+
+    #[test]
+    fn with_date() {
+        assert_eq!(
+            DateTime::MIN.with_date(Date::MAX),
+            DateTime::new(Date::MAX, Time::MIN)
+        );
+        // <https://github.com/zip-rs/zip/blob/v0.6.4/src/types.rs#L553-L569>.
+        assert_eq!(
+            DateTime::MAX.with_date(Date::new(0b0100_1101_0111_0001).unwrap()),
+            DateTime::new(Date::new(0b0100_1101_0111_0001).unwrap(), Time::MAX)
+        );
+        assert_eq!(DateTime::MIN.with_date(Date::MIN), DateTime::MIN);
+    }
+
+    #[test]
+    const fn with_date_is_const_fn() {
+        const _: DateTime = DateTime::MIN.with_date(Date::MAX);
+    }
+
+    #[test]
+    fn with_time() {
+        assert_eq!(
+            DateTime::MIN.with_time(Time::MAX),
+            DateTime::new(Date::MIN, Time::MAX)
+        );
+        // <https://github.com/zip-rs/zip/blob/v0.6.4/src/types.rs#L553-L569>.
+        assert_eq!(
+            DateTime::MAX.with_time(Time::new(0b0101_0100_1100_1111).unwrap()),
+            DateTime::new(Date::MAX, Time::new(0b0101_0100_1100_1111).unwrap())
+        );
+        assert_eq!(DateTime::MIN.with_time(Time::MIN), DateTime::MIN);
+    }
+
+    #[test]
+    fn with_time_from_secs() {
+        assert_eq!(
+            DateTime::MAX.with_time_from_secs(u32::MIN).unwrap(),
+            DateTime::new(Date::MAX, Time::MIN)
+        );
+        assert_eq!(
+            DateTime::MIN.with_time_from_secs(37110).unwrap(),
+            DateTime::try_from(datetime!(1980-01-01 10:18:30)).unwrap()
+        );
+        // <https://github.com/zip-rs/zip/blob/v0.6.4/src/types.rs#L553-L569>.
+        assert_eq!(
+            DateTime::MAX.with_time_from_secs(38310).unwrap(),
+            DateTime::new(Date::MAX, Time::new(0b0101_0100_1100_1111).unwrap())
+        );
+        assert_eq!(
+            DateTime::MIN.with_time_from_secs(86399).unwrap(),
+            DateTime::new(Date::MIN, Time::MAX)
+        );
+    }
+
+    #[test]
+    fn with_time_from_secs_with_odd_second() {
+        assert_eq!(
+            DateTime::MIN.with_time_from_secs(37111).unwrap(),
+            DateTime::try_from(datetime!(1980-01-01 10:18:30)).unwrap()
+        );
+    }
+
+    #[test]
+    fn with_time_from_secs_with_out_of_range() {
+        assert!(DateTime::MIN.with_time_from_secs(86400).is_none());
+        assert!(DateTime::MIN.with_time_from_secs(u32::MAX).is_none());
+    }
+
+    #[test]
+    fn with_date_from_ordinal() {
+        assert_eq!(
+            DateTime::MIN.with_date_from_ordinal(1980, 1).unwrap(),
+            DateTime::MIN
         );
         // <https://github.com/zip-rs/zip/blob/v0.6.4/src/types.rs#L553-L569>.
+        let dt = DateTime::try_from(datetime!(2018-11-17 10:38:30)).unwrap();
+        assert_eq!(
+            DateTime::MIN.with_date_from_ordinal(2018, 321).unwrap(),
+            DateTime::try_from(datetime!(2018-11-17 00:00:00)).unwrap()
+        );
+        assert_eq!(dt.with_date_from_ordinal(2018, 321).unwrap(), dt);
+        assert_eq!(
+            dt.with_date_from_ordinal(2000, 60).unwrap(),
+            DateTime::try_from(datetime!(2000-02-29 10:38:30)).unwrap()
+        );
+        assert_eq!(
+            dt.with_date_from_ordinal(2000, 60).unwrap().time(),
+            dt.time()
+        );
+        assert_eq!(
+            DateTime::MAX.with_date_from_ordinal(2107, 365).unwrap(),
+            DateTime::MAX
+        );
+    }
+
+    #[test]
+    fn with_date_from_ordinal_with_invalid_date() {
+        assert_eq!(
+            DateTime::MIN.with_date_from_ordinal(1979, 365).unwrap_err(),
+            DateErrorKind::Range(DateRangeErrorKind::Negative).into()
+        );
         assert_eq!(
-            DateTime::from_date_time(date!(2018-11-17), time!(10:38:30)).unwrap(),
-            DateTime::new(
-                Date::new(0b0100_1101_0111_0001).unwrap(),
-                Time::new(0b0101_0100_1100_1111).unwrap()
-            )
+            DateTime::MIN.with_date_from_ordinal(2108, 1).unwrap_err(),
+            DateErrorKind::Range(DateRangeErrorKind::Overflow).into()
         );
         assert_eq!(
-            DateTime::from_date_time(date!(2107-12-31), time!(23:59:58)).unwrap(),
-            DateTime::MAX
+            DateTime::MIN.with_date_from_ordinal(2107, 366).unwrap_err(),
+            DateErrorKind::InvalidOrdinal.into()
         );
         assert_eq!(
-            DateTime::from_date_time(date!(2107-12-31), time!(23:59:59)).unwrap(),
-            DateTime::MAX
+            DateTime::MIN.with_date_from_ordinal(1980, 0).unwrap_err(),
+            DateErrorKind::InvalidOrdinal.into()
         );
     }
 
     #[test]
-    fn from_date_time_with_too_big_date_time() {
+    fn start_of_day() {
+        assert_eq!(DateTime::MIN.start_of_day(), DateTime::MIN);
+        // <https://github.com/zip-rs/zip/blob/v0.6.4/src/types.rs#L553-L569>.
+        let dt = DateTime::try_from(datetime!(2018-11-17 10:38:30)).unwrap();
+        assert_eq!(dt.start_of_day().date(), dt.date());
+        assert_eq!(dt.start_of_day().time(), Time::MIN);
         assert_eq!(
-            DateTime::from_date_time(date!(2108-01-01), time::Time::MIDNIGHT).unwrap_err(),
-            DateTimeRangeErrorKind::Overflow.into()
+            dt.start_of_day(),
+            DateTime::try_from(datetime!(2018-11-17 00:00:00)).unwrap()
+        );
+        assert_eq!(
+            DateTime::MAX.start_of_day(),
+            DateTime::new(Date::MAX, Time::MIN)
         );
     }
 
     #[test]
-    fn is_valid() {
-        assert!(DateTime::MIN.is_valid());
-        // <https://devblogs.microsoft.com/oldnewthing/20030905-02/?p=42653>.
-        assert!(
-            DateTime::new(
-                Date::new(0b0010_1101_0111_1010).unwrap(),
-                Time::new(0b1001_1011_0010_0000).unwrap()
-            )
-            .is_valid()
+    const fn start_of_day_is_const_fn() {
+        const _: DateTime = DateTime::MAX.start_of_day();
+    }
+
+    #[test]
+    fn end_of_day() {
+        assert_eq!(
+            DateTime::MIN.end_of_day(),
+            DateTime::new(Date::MIN, Time::MAX)
         );
         // <https://github.com/zip-rs/zip/blob/v0.6.4/src/types.rs#L553-L569>.
-        assert!(
-            DateTime::new(
-                Date::new(0b0100_1101_0111_0001).unwrap(),
-                Time::new(0b0101_0100_1100_1111).unwrap()
-            )
-            .is_valid()
+        let dt = DateTime::try_from(datetime!(2018-11-17 10:38:30)).unwrap();
+        assert_eq!(dt.end_of_day().date(), dt.date());
+        assert_eq!(dt.end_of_day().time(), Time::MAX);
+        assert_eq!(
+            dt.end_of_day(),
+            DateTime::try_from(datetime!(2018-11-17 23:59:58)).unwrap()
         );
-        assert!(DateTime::MAX.is_valid());
+        assert_eq!(DateTime::MAX.end_of_day(), DateTime::MAX);
     }
 
     #[test]
-    fn is_valid_with_invalid_date() {
-        // The Day field is 0.
-        assert!(
-            !DateTime::new(
-                unsafe { Date::new_unchecked(0b0000_0000_0010_0000) },
-                Time::MIN
-            )
-            .is_valid()
-        );
-        // The Day field is 30, which is after the last day of February.
-        assert!(
-            !DateTime::new(
-                unsafe { Date::new_unchecked(0b0000_0000_0101_1110) },
-                Time::MIN
-            )
-            .is_valid()
-        );
-        // The Month field is 0.
-        assert!(
-            !DateTime::new(
-                unsafe { Date::new_unchecked(0b0000_0000_0000_0001) },
-                Time::MIN
-            )
-            .is_valid()
-        );
-        // The Month field is 13.
-        assert!(
-            !DateTime::new(
-                unsafe { Date::new_unchecked(0b0000_0001_1010_0001) },
-                Time::MIN
-            )
-            .is_valid()
-        );
+    const fn end_of_day_is_const_fn() {
+        const _: DateTime = DateTime::MIN.end_of_day();
     }
 
     #[test]
-    fn is_valid_with_invalid_time() {
-        // The DoubleSeconds field is 30.
-        assert!(
-            !DateTime::new(Date::MIN, unsafe {
-                Time::new_unchecked(0b0000_0000_0001_1110)
-            })
-            .is_valid()
-        );
-        // The Minute field is 60.
-        assert!(
-            !DateTime::new(Date::MIN, unsafe {
-                Time::new_unchecked(0b0000_0111_1000_0000)
-            })
-            .is_valid()
+    const fn with_time_is_const_fn() {
+        const _: DateTime = DateTime::MIN.with_time(Time::MAX);
+    }
+
+    #[test]
+    fn with_date_of() {
+        // <https://devblogs.microsoft.com/oldnewthing/20030905-02/?p=42653>.
+        let a = DateTime::new(
+            Date::new(0b0010_1101_0111_1010).unwrap(),
+            Time::new(0b1001_1011_0010_0000).unwrap(),
         );
-        // The Hour field is 24.
-        assert!(
-            !DateTime::new(Date::MIN, unsafe {
-                Time::new_unchecked(0b1100_0000_0000_0000)
-            })
-            .is_valid()
+        // <https://github.com/zip-rs/zip/blob/v0.6.4/src/types.rs#L553-L569>.
+        let b = DateTime::new(
+            Date::new(0b0100_1101_0111_0001).unwrap(),
+            Time::new(0b0101_0100_1100_1111).unwrap(),
         );
+        assert_eq!(a.with_date_of(b), DateTime::new(b.date(), a.time()));
+        assert_eq!(b.with_date_of(a), DateTime::new(a.date(), b.time()));
+        assert_eq!(a.with_date_of(a), a);
     }
 
     #[test]
-    fn is_valid_with_invalid_date_time() {
-        assert!(
-            !DateTime::new(unsafe { Date::new_unchecked(u16::MAX) }, unsafe {
-                Time::new_unchecked(u16::MAX)
-            })
-            .is_valid()
-        );
+    const fn with_date_of_is_const_fn() {
+        const _: DateTime = DateTime::MIN.with_date_of(DateTime::MAX);
     }
 
     #[test]
-    fn date() {
-        assert_eq!(DateTime::MIN.date(), Date::MIN);
+    fn with_time_of() {
         // <https://devblogs.microsoft.com/oldnewthing/20030905-02/?p=42653>.
-        assert_eq!(
-            DateTime::new(
-                Date::new(0b0010_1101_0111_1010).unwrap(),
-                Time::new(0b1001_1011_0010_0000).unwrap()
-            )
-            .date(),
-            Date::new(0b0010_1101_0111_1010).unwrap()
+        let a = DateTime::new(
+            Date::new(0b0010_1101_0111_1010).unwrap(),
+            Time::new(0b1001_1011_0010_0000).unwrap(),
         );
         // <https://github.com/zip-rs/zip/blob/v0.6.4/src/types.rs#L553-L569>.
-        assert_eq!(
-            DateTime::new(
-                Date::new(0b0100_1101_0111_0001).unwrap(),
-                Time::new(0b0101_0100_1100_1111).unwrap()
-            )
-            .date(),
-            Date::new(0b0100_1101_0111_0001).unwrap()
+        let b = DateTime::new(
+            Date::new(0b0100_1101_0111_0001).unwrap(),
+            Time::new(0b0101_0100_1100_1111).unwrap(),
         );
-        assert_eq!(DateTime::MAX.date(), Date::MAX);
+        assert_eq!(a.with_time_of(b), DateTime::new(a.date(), b.time()));
+        assert_eq!(b.with_time_of(a), DateTime::new(b.date(), a.time()));
+        assert_eq!(a.with_time_of(a), a);
     }
 
     #[test]
-    const fn date_is_const_fn() {
-        const _: Date = DateTime::MIN.date();
+    const fn with_time_of_is_const_fn() {
+        const _: DateTime = DateTime::MIN.with_time_of(DateTime::MAX);
     }
 
     #[test]
-    fn time() {
-        assert_eq!(DateTime::MIN.time(), Time::MIN);
+    fn set_date() {
+        // <https://devblogs.microsoft.com/oldnewthing/20030905-02/?p=42653>.
+        let mut dt = DateTime::new(
+            Date::new(0b0010_1101_0111_1010).unwrap(),
+            Time::new(0b1001_1011_0010_0000).unwrap(),
+        );
+        dt.set_date(Date::MAX);
+        assert_eq!(dt.date(), Date::MAX);
+        assert_eq!(dt.time(), Time::new(0b1001_1011_0010_0000).unwrap());
+    }
+
+    #[test]
+    const fn set_date_is_const_fn() {
+        const _: DateTime = {
+            let mut dt = DateTime::MIN;
+            dt.set_date(Date::MAX);
+            dt
+        };
+    }
+
+    #[test]
+    fn set_time() {
         // <https://devblogs.microsoft.com/oldnewthing/20030905-02/?p=42653>.
+        let mut dt = DateTime::new(
+            Date::new(0b0010_1101_0111_1010).unwrap(),
+            Time::new(0b1001_1011_0010_0000).unwrap(),
+        );
+        dt.set_time(Time::MAX);
+        assert_eq!(dt.date(), Date::new(0b0010_1101_0111_1010).unwrap());
+        assert_eq!(dt.time(), Time::MAX);
+    }
+
+    #[test]
+    const fn set_time_is_const_fn() {
+        const _: DateTime = {
+            let mut dt = DateTime::MIN;
+            dt.set_time(Time::MAX);
+            dt
+        };
+    }
+
+    #[test]
+    fn checked_set_year() {
         assert_eq!(
-            DateTime::new(
-                Date::new(0b0010_1101_0111_1010).unwrap(),
-                Time::new(0b1001_1011_0010_0000).unwrap()
-            )
-            .time(),
-            Time::new(0b1001_1011_0010_0000).unwrap()
+            DateTime::MIN.checked_set_year(2107).unwrap(),
+            DateTime::try_from(datetime!(2107-01-01 00:00:00)).unwrap()
         );
         // <https://github.com/zip-rs/zip/blob/v0.6.4/src/types.rs#L553-L569>.
         assert_eq!(
@@ -499,15 +5135,57 @@ mod tests {
                 Date::new(0b0100_1101_0111_0001).unwrap(),
                 Time::new(0b0101_0100_1100_1111).unwrap()
             )
-            .time(),
-            Time::new(0b0101_0100_1100_1111).unwrap()
+            .checked_set_year(2002)
+            .unwrap(),
+            DateTime::try_from(datetime!(2002-11-17 10:38:30)).unwrap()
+        );
+        assert_eq!(
+            DateTime::MAX.checked_set_year(1980).unwrap(),
+            DateTime::try_from(datetime!(1980-12-31 23:59:58)).unwrap()
         );
-        assert_eq!(DateTime::MAX.time(), Time::MAX);
     }
 
     #[test]
-    const fn time_is_const_fn() {
-        const _: Time = DateTime::MIN.time();
+    fn checked_set_year_with_leap_day() {
+        let dt = DateTime::try_from(datetime!(2000-02-29 12:34:56)).unwrap();
+        assert_eq!(
+            dt.checked_set_year(1980).unwrap(),
+            DateTime::try_from(datetime!(1980-02-29 12:34:56)).unwrap()
+        );
+        assert_eq!(
+            dt.checked_set_year(2104).unwrap(),
+            DateTime::try_from(datetime!(2104-02-29 12:34:56)).unwrap()
+        );
+        // `2001` is not a leap year.
+        assert!(dt.checked_set_year(2001).is_none());
+        // `2100` is not a leap year.
+        assert!(dt.checked_set_year(2100).is_none());
+    }
+
+    #[test]
+    fn checked_set_year_with_out_of_range() {
+        assert!(DateTime::MIN.checked_set_year(1979).is_none());
+        assert!(DateTime::MAX.checked_set_year(2108).is_none());
+        assert!(DateTime::MIN.checked_set_year(u16::MIN).is_none());
+        assert!(DateTime::MIN.checked_set_year(u16::MAX).is_none());
+    }
+
+    #[test]
+    fn checked_set_year_agrees_with_date_with_year() {
+        for date in Date::all_valid().step_by(97) {
+            let dt = DateTime::new(date, Time::MAX);
+            for year in [1980, 1981, 2000, 2001, 2100, 2104, 2107] {
+                assert_eq!(
+                    dt.checked_set_year(year),
+                    date.with_year(year).ok().map(|date| dt.with_date(date))
+                );
+            }
+        }
+    }
+
+    #[test]
+    const fn checked_set_year_is_const_fn() {
+        const _: Option<DateTime> = DateTime::MIN.checked_set_year(2107);
     }
 
     #[test]
@@ -587,6 +5265,46 @@ mod tests {
         assert_eq!(DateTime::MAX.day(), 31);
     }
 
+    #[test]
+    fn month0() {
+        assert_eq!(DateTime::MIN.month0(), 0);
+        // <https://github.com/zip-rs/zip/blob/v0.6.4/src/types.rs#L553-L569>.
+        assert_eq!(
+            DateTime::new(
+                Date::new(0b0100_1101_0111_0001).unwrap(),
+                Time::new(0b0101_0100_1100_1111).unwrap()
+            )
+            .month0(),
+            10
+        );
+        assert_eq!(DateTime::MAX.month0(), 11);
+    }
+
+    #[test]
+    const fn month0_is_const_fn() {
+        const _: u8 = DateTime::MIN.month0();
+    }
+
+    #[test]
+    fn day0() {
+        assert_eq!(DateTime::MIN.day0(), 0);
+        // <https://github.com/zip-rs/zip/blob/v0.6.4/src/types.rs#L553-L569>.
+        assert_eq!(
+            DateTime::new(
+                Date::new(0b0100_1101_0111_0001).unwrap(),
+                Time::new(0b0101_0100_1100_1111).unwrap()
+            )
+            .day0(),
+            16
+        );
+        assert_eq!(DateTime::MAX.day0(), 30);
+    }
+
+    #[test]
+    const fn day0_is_const_fn() {
+        const _: u8 = DateTime::MIN.day0();
+    }
+
     #[test]
     fn hour() {
         assert_eq!(DateTime::MIN.hour(), u8::MIN);
@@ -659,6 +5377,174 @@ mod tests {
         assert_eq!(DateTime::MAX.second(), 58);
     }
 
+    #[test]
+    fn is_business_day() {
+        assert!(DateTime::MIN.is_business_day());
+        // <https://devblogs.microsoft.com/oldnewthing/20030905-02/?p=42653>.
+        assert!(
+            DateTime::try_from(datetime!(2002-11-26 19:25:00))
+                .unwrap()
+                .is_business_day()
+        );
+        // <https://github.com/zip-rs/zip/blob/v0.6.4/src/types.rs#L553-L569>.
+        assert!(
+            !DateTime::try_from(datetime!(2018-11-17 10:38:30))
+                .unwrap()
+                .is_business_day()
+        );
+        assert!(
+            !DateTime::try_from(datetime!(2018-11-18 10:38:30))
+                .unwrap()
+                .is_business_day()
+        );
+        assert!(
+            DateTime::try_from(datetime!(2018-11-21 10:38:30))
+                .unwrap()
+                .is_business_day()
+        );
+        assert!(!DateTime::MAX.is_business_day());
+    }
+
+    #[test]
+    fn is_business_day_ignores_time() {
+        let date = Date::from_date(date!(2018-11-21)).unwrap();
+        assert!(DateTime::new(date, Time::MIN).is_business_day());
+        assert!(DateTime::new(date, Time::MAX).is_business_day());
+        let date = Date::from_date(date!(2018-11-17)).unwrap();
+        assert!(!DateTime::new(date, Time::MIN).is_business_day());
+        assert!(!DateTime::new(date, Time::MAX).is_business_day());
+    }
+
+    #[test]
+    fn fields() {
+        assert_eq!(
+            DateTime::MIN.fields(),
+            [
+                ("year", 1980),
+                ("month", 1),
+                ("day", 1),
+                ("hour", 0),
+                ("minute", 0),
+                ("second", 0)
+            ]
+        );
+        // <https://github.com/zip-rs/zip/blob/v0.6.4/src/types.rs#L553-L569>.
+        assert_eq!(
+            DateTime::new(
+                Date::new(0b0100_1101_0111_0001).unwrap(),
+                Time::new(0b0101_0100_1100_1111).unwrap()
+            )
+            .fields(),
+            [
+                ("year", 2018),
+                ("month", 11),
+                ("day", 17),
+                ("hour", 10),
+                ("minute", 38),
+                ("second", 30)
+            ]
+        );
+        assert_eq!(
+            DateTime::MAX.fields(),
+            [
+                ("year", 2107),
+                ("month", 12),
+                ("day", 31),
+                ("hour", 23),
+                ("minute", 59),
+                ("second", 58)
+            ]
+        );
+    }
+
+    #[test]
+    fn breakdown() {
+        assert_eq!(
+            DateTime::MIN.breakdown(),
+            Breakdown {
+                year: 1980,
+                month: Month::January,
+                day: 1,
+                hour: 0,
+                minute: 0,
+                second: 0,
+                weekday: Weekday::Tuesday,
+                ordinal: 1,
+            }
+        );
+        // <https://devblogs.microsoft.com/oldnewthing/20030905-02/?p=42653>.
+        assert_eq!(
+            DateTime::new(
+                Date::new(0b0010_1101_0111_1010).unwrap(),
+                Time::new(0b1001_1011_0010_0000).unwrap()
+            )
+            .breakdown(),
+            Breakdown {
+                year: 2002,
+                month: Month::November,
+                day: 26,
+                hour: 19,
+                minute: 25,
+                second: 0,
+                weekday: Weekday::Tuesday,
+                ordinal: 330,
+            }
+        );
+        // <https://github.com/zip-rs/zip/blob/v0.6.4/src/types.rs#L553-L569>.
+        assert_eq!(
+            DateTime::new(
+                Date::new(0b0100_1101_0111_0001).unwrap(),
+                Time::new(0b0101_0100_1100_1111).unwrap()
+            )
+            .breakdown(),
+            Breakdown {
+                year: 2018,
+                month: Month::November,
+                day: 17,
+                hour: 10,
+                minute: 38,
+                second: 30,
+                weekday: Weekday::Saturday,
+                ordinal: 321,
+            }
+        );
+        assert_eq!(
+            DateTime::MAX.breakdown(),
+            Breakdown {
+                year: 2107,
+                month: Month::December,
+                day: 31,
+                hour: 23,
+                minute: 59,
+                second: 58,
+                weekday: Weekday::Saturday,
+                ordinal: 365,
+            }
+        );
+    }
+
+    #[test]
+    fn eq_to_the_minute() {
+        // <https://github.com/zip-rs/zip/blob/v0.6.4/src/types.rs#L553-L569>.
+        let dt = DateTime::try_from(datetime!(2018-11-17 10:38:30)).unwrap();
+        assert!(dt.eq_to_the_minute(dt));
+        assert!(dt.eq_to_the_minute(DateTime::try_from(datetime!(2018-11-17 10:38:00)).unwrap()));
+        assert!(dt.eq_to_the_minute(DateTime::try_from(datetime!(2018-11-17 10:38:58)).unwrap()));
+        assert!(!dt.eq_to_the_minute(DateTime::try_from(datetime!(2018-11-17 10:39:30)).unwrap()));
+        assert!(!dt.eq_to_the_minute(DateTime::try_from(datetime!(2018-11-17 11:38:30)).unwrap()));
+        assert!(!dt.eq_to_the_minute(DateTime::try_from(datetime!(2018-11-18 10:38:30)).unwrap()));
+        assert!(
+            DateTime::MAX
+                .eq_to_the_minute(DateTime::try_from(datetime!(2107-12-31 23:59:00)).unwrap())
+        );
+        assert!(!DateTime::MIN.eq_to_the_minute(DateTime::MAX));
+    }
+
+    #[test]
+    const fn eq_to_the_minute_is_const_fn() {
+        const _: bool = DateTime::MIN.eq_to_the_minute(DateTime::MAX);
+    }
+
     #[test]
     fn default() {
         assert_eq!(DateTime::default(), DateTime::MIN);
@@ -0,0 +1,75 @@
+// SPDX-FileCopyrightText: 2025 Shun Sakai
+//
+// SPDX-License-Identifier: Apache-2.0 OR MIT
+
+//! A breakdown of [`DateTime`](crate::DateTime) into its components.
+
+use time::{Month, Weekday};
+
+/// The components of a [`DateTime`](crate::DateTime).
+///
+/// This is returned by [`DateTime::breakdown`](crate::DateTime::breakdown).
+#[derive(Clone, Copy, Debug, Eq, Hash, PartialEq)]
+pub struct Breakdown {
+    /// The year, in the range `1980..=2107`.
+    pub year: u16,
+
+    /// The month.
+    pub month: Month,
+
+    /// The day of the month, in the range `1..=31`.
+    pub day: u8,
+
+    /// The hour, in the range `0..=23`.
+    pub hour: u8,
+
+    /// The minute, in the range `0..=59`.
+    pub minute: u8,
+
+    /// The second, in the range `0..=58`. This is always even.
+    pub second: u8,
+
+    /// The day of the week.
+    pub weekday: Weekday,
+
+    /// The day of the year, in the range `1..=366`.
+    pub ordinal: u16,
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn clone() {
+        let breakdown = Breakdown {
+            year: 1980,
+            month: Month::January,
+            day: 1,
+            hour: u8::MIN,
+            minute: u8::MIN,
+            second: u8::MIN,
+            weekday: Weekday::Tuesday,
+            ordinal: 1,
+        };
+        assert_eq!(breakdown.clone(), breakdown);
+    }
+
+    #[test]
+    fn debug() {
+        let breakdown = Breakdown {
+            year: 1980,
+            month: Month::January,
+            day: 1,
+            hour: u8::MIN,
+            minute: u8::MIN,
+            second: u8::MIN,
+            weekday: Weekday::Tuesday,
+            ordinal: 1,
+        };
+        assert_eq!(
+            format!("{breakdown:?}"),
+            "Breakdown { year: 1980, month: January, day: 1, hour: 0, minute: 0, second: 0, weekday: Tuesday, ordinal: 1 }"
+        );
+    }
+}
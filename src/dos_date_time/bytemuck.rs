@@ -0,0 +1,132 @@
+// SPDX-FileCopyrightText: 2025 Shun Sakai
+//
+// SPDX-License-Identifier: Apache-2.0 OR MIT
+
+//! Implementations of [`bytemuck`] traits for [`DateTime`].
+//!
+//! <div class="warning">
+//!
+//! `DateTime` is not [`Pod`](bytemuck::Pod), because not every bit pattern is
+//! a valid MS-DOS date and time. Casting from bytes goes through
+//! [`CheckedBitPattern`], which validates them.
+//!
+//! </div>
+
+use bytemuck::{CheckedBitPattern, NoUninit};
+
+use super::DateTime;
+use crate::{Date, Time};
+
+// SAFETY: `DateTime` is `#[repr(C)]` and consists of two `#[repr(transparent)]`
+// `u16` values, so it has no padding bytes.
+unsafe impl NoUninit for DateTime {}
+
+// SAFETY: `Bits` has the same layout as `DateTime`, and `is_valid_bit_pattern`
+// only accepts valid MS-DOS date and time.
+unsafe impl CheckedBitPattern for DateTime {
+    /// The MS-DOS date followed by the MS-DOS time, in native endianness.
+    type Bits = [u16; 2];
+
+    /// Returns [`true`] if `bits` are valid MS-DOS date and time.
+    ///
+    /// <div class="warning">
+    ///
+    /// The bytes of `DateTime` are the MS-DOS date followed by the MS-DOS time,
+    /// each in native endianness. This differs from the layout used by the ZIP
+    /// file format, so use [`DateTime::to_zip_bytes`] and
+    /// [`DateTime::read_le`] for portable serialization.
+    ///
+    /// </div>
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use dos_date_time::{DateTime, bytemuck};
+    /// #
+    /// let bytes = bytemuck::bytes_of(&DateTime::MAX);
+    /// assert_eq!(bytes.len(), 4);
+    /// assert_eq!(
+    ///     bytemuck::checked::try_from_bytes::<DateTime>(bytes),
+    ///     Ok(&DateTime::MAX)
+    /// );
+    ///
+    /// // The Day field is 0.
+    /// let bytes = bytemuck::bytes_of(&[0b0000_0000_0010_0000_u16, u16::MIN]);
+    /// assert!(bytemuck::checked::try_from_bytes::<DateTime>(bytes).is_err());
+    /// ```
+    fn is_valid_bit_pattern(&[date, time]: &Self::Bits) -> bool {
+        Date::new(date).is_some() && Time::new(time).is_some()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use core::mem;
+
+    use bytemuck::checked::{self, CheckedCastError};
+    use time::macros::datetime;
+
+    use super::*;
+
+    #[test]
+    fn size_of() {
+        assert_eq!(mem::size_of::<DateTime>(), 4);
+        assert_eq!(bytemuck::bytes_of(&DateTime::MIN).len(), 4);
+    }
+
+    #[test]
+    fn bytes_of() {
+        assert_eq!(
+            bytemuck::bytes_of(&DateTime::MIN),
+            bytemuck::bytes_of(&[0b0000_0000_0010_0001_u16, u16::MIN])
+        );
+        // <https://github.com/zip-rs/zip/blob/v0.6.4/src/types.rs#L553-L569>.
+        assert_eq!(
+            bytemuck::bytes_of(&DateTime::try_from(datetime!(2018-11-17 10:38:30)).unwrap()),
+            bytemuck::bytes_of(&[0b0100_1101_0111_0001_u16, 0b0101_0100_1100_1111])
+        );
+        assert_eq!(
+            bytemuck::bytes_of(&DateTime::MAX),
+            bytemuck::bytes_of(&[0b1111_1111_1001_1111_u16, 0b1011_1111_0111_1101])
+        );
+    }
+
+    #[test]
+    fn from_bytes_round_trip() {
+        for dt in [
+            DateTime::MIN,
+            DateTime::try_from(datetime!(2002-11-26 19:25:00)).unwrap(),
+            DateTime::try_from(datetime!(2018-11-17 10:38:30)).unwrap(),
+            DateTime::MAX,
+        ] {
+            assert_eq!(
+                checked::try_from_bytes::<DateTime>(bytemuck::bytes_of(&dt)),
+                Ok(&dt)
+            );
+            assert_eq!(
+                checked::pod_read_unaligned::<DateTime>(bytemuck::bytes_of(&dt)),
+                dt
+            );
+        }
+    }
+
+    #[test]
+    fn from_bytes_with_invalid_bit_pattern() {
+        // The Day field is 0.
+        assert_eq!(
+            checked::try_from_bytes::<DateTime>(bytemuck::bytes_of(&[
+                0b0000_0000_0010_0000_u16,
+                u16::MIN
+            ])),
+            Err(CheckedCastError::InvalidBitPattern)
+        );
+        // The Hour field is 24.
+        assert_eq!(
+            checked::try_from_bytes::<DateTime>(bytemuck::bytes_of(&[
+                0b0000_0000_0010_0001_u16,
+                0b1100_0000_0000_0000
+            ])),
+            Err(CheckedCastError::InvalidBitPattern)
+        );
+    }
+}
@@ -4,13 +4,54 @@
 
 //! Utilities for comparing and ordering values.
 
+use core::cmp::Ordering;
+
+use super::DateTime;
+use crate::{Date, Time};
+
+impl PartialEq<Date> for DateTime {
+    /// Returns [`true`] if this `DateTime` is midnight of `other`.
+    ///
+    /// `other` is treated as the `DateTime` at "00:00:00" on that date.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use dos_date_time::{Date, DateTime, Time};
+    /// #
+    /// assert_eq!(DateTime::MIN, Date::MIN);
+    /// assert_ne!(DateTime::new(Date::MIN, Time::MAX), Date::MIN);
+    /// ```
+    fn eq(&self, other: &Date) -> bool {
+        *self == Self::new(*other, Time::MIN)
+    }
+}
+
+impl PartialOrd<Date> for DateTime {
+    /// Compares this `DateTime` with `other`.
+    ///
+    /// `other` is treated as the `DateTime` at "00:00:00" on that date, so
+    /// `dt >= date` holds if this `DateTime` is on or after midnight of `date`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use dos_date_time::{Date, DateTime, Time};
+    /// #
+    /// assert!(DateTime::MIN >= Date::MIN);
+    /// assert!(DateTime::new(Date::MIN, Time::MAX) > Date::MIN);
+    /// assert!(DateTime::MIN < Date::MAX);
+    /// ```
+    fn partial_cmp(&self, other: &Date) -> Option<Ordering> {
+        Some(self.cmp(&Self::new(*other, Time::MIN)))
+    }
+}
+
 #[cfg(test)]
 mod tests {
-    use core::cmp::Ordering;
+    use time::macros::{date, datetime};
 
-    use time::macros::datetime;
-
-    use super::super::DateTime;
+    use super::*;
 
     #[test]
     fn equality() {
@@ -59,4 +100,88 @@ mod tests {
         assert!(dt > DateTime::try_from(datetime!(2018-11-17 10:38:29)).unwrap());
         assert!(dt > DateTime::try_from(datetime!(2018-11-17 10:38:28)).unwrap());
     }
+
+    #[test]
+    fn order_is_same_as_packed_u32() {
+        // xorshift32.
+        let mut state = 0x9E37_79B9_u32;
+        let mut next = || {
+            state ^= state << 13;
+            state ^= state >> 17;
+            state ^= state << 5;
+            state
+        };
+        let mut next_date_time = || loop {
+            let value = next();
+            let (date, time) = (
+                u16::try_from(value >> 16).unwrap(),
+                u16::try_from(value & 0xFFFF).unwrap(),
+            );
+            if let (Some(date), Some(time)) = (Date::new(date), Time::new(time)) {
+                break DateTime::new(date, time);
+            }
+        };
+
+        for _ in 0..10000 {
+            let (a, b) = (next_date_time(), next_date_time());
+            assert_eq!(a.cmp(&b), a.to_sort_key().cmp(&b.to_sort_key()));
+            // Tests values that share the same date.
+            let b = DateTime::new(a.date(), b.time());
+            assert_eq!(a.cmp(&b), a.to_sort_key().cmp(&b.to_sort_key()));
+        }
+    }
+
+    #[test]
+    fn equality_with_date() {
+        assert_eq!(DateTime::MIN, Date::MIN);
+        // <https://github.com/zip-rs/zip/blob/v0.6.4/src/types.rs#L553-L569>.
+        let date = Date::from_date(date!(2018-11-17)).unwrap();
+        assert_eq!(
+            DateTime::try_from(datetime!(2018-11-17 00:00:00)).unwrap(),
+            date
+        );
+        assert_ne!(
+            DateTime::try_from(datetime!(2018-11-17 10:38:30)).unwrap(),
+            date
+        );
+        assert_ne!(DateTime::MAX, Date::MAX);
+    }
+
+    #[test]
+    fn order_with_date() {
+        // <https://github.com/zip-rs/zip/blob/v0.6.4/src/types.rs#L553-L569>.
+        let date = Date::from_date(date!(2018-11-17)).unwrap();
+
+        // Tests the boundaries on the same day.
+        assert!(DateTime::try_from(datetime!(2018-11-17 00:00:00)).unwrap() >= date);
+        assert!(DateTime::try_from(datetime!(2018-11-17 00:00:00)).unwrap() <= date);
+        assert!(DateTime::try_from(datetime!(2018-11-17 00:00:02)).unwrap() > date);
+        assert!(DateTime::try_from(datetime!(2018-11-17 23:59:58)).unwrap() > date);
+
+        // Tests the boundaries on the adjacent days.
+        assert!(DateTime::try_from(datetime!(2018-11-16 23:59:58)).unwrap() < date);
+        assert!(DateTime::try_from(datetime!(2018-11-18 00:00:00)).unwrap() > date);
+    }
+
+    #[test]
+    fn order_with_date_is_symmetric() {
+        for dt in [
+            DateTime::MIN,
+            DateTime::try_from(datetime!(2018-11-17 00:00:00)).unwrap(),
+            DateTime::try_from(datetime!(2018-11-17 10:38:30)).unwrap(),
+            DateTime::MAX,
+        ] {
+            for date in [
+                Date::MIN,
+                Date::from_date(date!(2018-11-17)).unwrap(),
+                Date::MAX,
+            ] {
+                assert_eq!(
+                    dt.partial_cmp(&date),
+                    date.partial_cmp(&dt).map(Ordering::reverse)
+                );
+                assert_eq!(dt == date, date == dt);
+            }
+        }
+    }
 }
@@ -4,6 +4,9 @@
 
 //! Constants for [`DateTime`].
 
+#[cfg(feature = "num-traits")]
+use num_traits::Bounded;
+
 use super::DateTime;
 use crate::{Date, Time};
 
@@ -45,6 +48,77 @@ impl DateTime {
     /// );
     /// ```
     pub const MAX: Self = Self::new(Date::MAX, Time::MAX);
+
+    /// [`true`] if the order of `DateTime` is the same as the order of the
+    /// 32-bit unsigned integer value `(date << 16) | time`, where `date` and
+    /// `time` are the MS-DOS date and time.
+    ///
+    /// This is always [`true`]. Both the MS-DOS date and time store their
+    /// fields from the most significant (year or hour) to the least
+    /// significant (day or seconds), so packing the MS-DOS date into the high
+    /// 16 bits and the MS-DOS time into the low 16 bits yields a value that
+    /// increases monotonically with chronological order.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use dos_date_time::DateTime;
+    /// #
+    /// let to_u32 =
+    ///     |dt: DateTime| (u32::from(dt.date().to_raw()) << 16) | u32::from(dt.time().to_raw());
+    ///
+    /// assert!(DateTime::MONOTONIC);
+    /// assert_eq!(
+    ///     DateTime::MIN.cmp(&DateTime::MAX),
+    ///     to_u32(DateTime::MIN).cmp(&to_u32(DateTime::MAX))
+    /// );
+    /// ```
+    pub const MONOTONIC: bool = true;
+
+    /// The number of the valid MS-DOS date and time values.
+    ///
+    /// This is the number of days from [`DateTime::MIN`] to [`DateTime::MAX`]
+    /// inclusive multiplied by the number of 2-second intervals in a day. See
+    /// [`DateTime::from_index`] and [`DateTime::to_index`].
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use dos_date_time::DateTime;
+    /// #
+    /// assert_eq!(DateTime::INDEX_COUNT, 2_019_643_200);
+    /// assert_eq!(DateTime::INDEX_COUNT, DateTime::MAX.to_index() + 1);
+    /// ```
+    pub const INDEX_COUNT: u32 = 46751 * 43200;
+}
+
+#[cfg(feature = "num-traits")]
+impl Bounded for DateTime {
+    /// Returns [`DateTime::MIN`].
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use dos_date_time::{DateTime, num_traits::Bounded};
+    /// #
+    /// assert_eq!(<DateTime as Bounded>::min_value(), DateTime::MIN);
+    /// ```
+    fn min_value() -> Self {
+        Self::MIN
+    }
+
+    /// Returns [`DateTime::MAX`].
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use dos_date_time::{DateTime, num_traits::Bounded};
+    /// #
+    /// assert_eq!(<DateTime as Bounded>::max_value(), DateTime::MAX);
+    /// ```
+    fn max_value() -> Self {
+        Self::MAX
+    }
 }
 
 #[cfg(test)]
@@ -68,4 +142,22 @@ mod tests {
             DateTime::from_date_time(date!(2107-12-31), time!(23:59:58)).unwrap()
         );
     }
+
+    #[test]
+    fn monotonic() {
+        const { assert!(DateTime::MONOTONIC) };
+    }
+
+    #[test]
+    fn index_count() {
+        assert_eq!(DateTime::INDEX_COUNT, 2_019_643_200);
+        assert_eq!(DateTime::INDEX_COUNT, DateTime::MAX.to_index() + 1);
+    }
+
+    #[cfg(feature = "num-traits")]
+    #[test]
+    fn bounded() {
+        assert_eq!(<DateTime as Bounded>::min_value(), DateTime::MIN);
+        assert_eq!(<DateTime as Bounded>::max_value(), DateTime::MAX);
+    }
 }
@@ -11,7 +11,31 @@ use jiff::civil;
 use time::PrimitiveDateTime;
 
 use super::DateTime;
-use crate::error::DateTimeRangeError;
+use crate::{
+    Date, Time,
+    error::{DateTimeRangeError, InvalidBitsError},
+};
+
+impl From<DateTime> for [u8; 4] {
+    /// Converts a `DateTime` to its byte representation.
+    ///
+    /// The bytes are in the layout used by the ZIP file format: the MS-DOS time
+    /// as a little-endian [`u16`] value, followed by the MS-DOS date as a
+    /// little-endian [`u16`] value. This is equivalent to
+    /// [`DateTime::to_zip_bytes`].
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use dos_date_time::DateTime;
+    /// #
+    /// assert_eq!(<[u8; 4]>::from(DateTime::MIN), [0x00, 0x00, 0x21, 0x00]);
+    /// assert_eq!(<[u8; 4]>::from(DateTime::MAX), [0x7D, 0xBF, 0x9F, 0xFF]);
+    /// ```
+    fn from(dt: DateTime) -> Self {
+        dt.to_zip_bytes()
+    }
+}
 
 impl From<DateTime> for PrimitiveDateTime {
     /// Converts a `DateTime` to a [`PrimitiveDateTime`].
@@ -34,8 +58,7 @@ impl From<DateTime> for PrimitiveDateTime {
     /// );
     /// ```
     fn from(dt: DateTime) -> Self {
-        let (date, time) = (dt.date().into(), dt.time().into());
-        Self::new(date, time)
+        dt.to_primitive()
     }
 }
 
@@ -87,6 +110,27 @@ impl From<DateTime> for civil::DateTime {
     }
 }
 
+impl From<(Date, Time)> for DateTime {
+    /// Converts a pair of [`Date`] and [`Time`] to a `DateTime`.
+    ///
+    /// This is equivalent to [`DateTime::new`].
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use dos_date_time::{Date, DateTime, Time};
+    /// #
+    /// assert_eq!(DateTime::from((Date::MIN, Time::MIN)), DateTime::MIN);
+    /// assert_eq!(DateTime::from((Date::MAX, Time::MAX)), DateTime::MAX);
+    ///
+    /// let dt: DateTime = (Date::MIN, Time::MIN).into();
+    /// assert_eq!(dt, DateTime::MIN);
+    /// ```
+    fn from((date, time): (Date, Time)) -> Self {
+        Self::new(date, time)
+    }
+}
+
 impl TryFrom<PrimitiveDateTime> for DateTime {
     type Error = DateTimeRangeError;
 
@@ -217,12 +261,111 @@ impl TryFrom<civil::DateTime> for DateTime {
     }
 }
 
+impl TryFrom<[u8; 4]> for DateTime {
+    type Error = InvalidBitsError;
+
+    /// Converts the given byte representation to a `DateTime`.
+    ///
+    /// The bytes are expected in the layout used by the ZIP file format: the
+    /// MS-DOS time as a little-endian [`u16`] value, followed by the MS-DOS
+    /// date as a little-endian [`u16`] value. This is the inverse of
+    /// [`DateTime::to_zip_bytes`], and the same layout as
+    /// [`DateTime::read_le`].
+    ///
+    /// # Errors
+    ///
+    /// Returns [`Err`] if `bytes` is not valid MS-DOS date and time. The error
+    /// reports which field is invalid.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use dos_date_time::DateTime;
+    /// #
+    /// assert_eq!(
+    ///     DateTime::try_from([0x00, 0x00, 0x21, 0x00]),
+    ///     Ok(DateTime::MIN)
+    /// );
+    /// assert_eq!(
+    ///     DateTime::try_from([0x7D, 0xBF, 0x9F, 0xFF]),
+    ///     Ok(DateTime::MAX)
+    /// );
+    ///
+    /// // The month is 0.
+    /// assert!(DateTime::try_from([0x00, 0x00, 0x01, 0x00]).is_err());
+    /// ```
+    fn try_from(bytes: [u8; 4]) -> Result<Self, Self::Error> {
+        let [time_lo, time_hi, date_lo, date_hi] = bytes;
+        let date = Date::checked_from_raw(u16::from_le_bytes([date_lo, date_hi]))?;
+        let time = Time::checked_from_raw(u16::from_le_bytes([time_lo, time_hi]))?;
+        Ok(Self::new(date, time))
+    }
+}
+
+/// Returns [`true`] if `dt` is in the range of MS-DOS date and time.
+///
+/// This is equivalent to checking whether [`DateTime::try_from`] succeeds,
+/// but does not construct the error. Since the conversion truncates the
+/// second, any time of day is representable, so only the date matters.
+///
+/// # Examples
+///
+/// ```
+/// # use dos_date_time::time::macros::datetime;
+/// #
+/// assert!(dos_date_time::is_representable(
+///     datetime!(1980-01-01 00:00:00)
+/// ));
+/// assert!(dos_date_time::is_representable(
+///     datetime!(2107-12-31 23:59:59)
+/// ));
+///
+/// assert!(!dos_date_time::is_representable(
+///     datetime!(1979-12-31 23:59:59)
+/// ));
+/// assert!(!dos_date_time::is_representable(
+///     datetime!(2108-01-01 00:00:00)
+/// ));
+/// ```
+#[must_use]
+pub const fn is_representable(dt: PrimitiveDateTime) -> bool {
+    crate::is_date_representable(dt.date())
+}
+
 #[cfg(test)]
 mod tests {
     use time::macros::datetime;
 
     use super::*;
-    use crate::{Date, Time, error::DateTimeRangeErrorKind};
+    use crate::error::{DateTimeRangeErrorKind, InvalidDateField, InvalidTimeField};
+
+    #[test]
+    fn from_date_time_to_bytes() {
+        assert_eq!(<[u8; 4]>::from(DateTime::MIN), [0x00, 0x00, 0x21, 0x00]);
+        // <https://github.com/zip-rs/zip/blob/v0.6.4/src/types.rs#L553-L569>.
+        assert_eq!(
+            <[u8; 4]>::from(DateTime::new(
+                Date::new(0b0100_1101_0111_0001).unwrap(),
+                Time::new(0b0101_0100_1100_1111).unwrap()
+            )),
+            [0xCF, 0x54, 0x71, 0x4D]
+        );
+        assert_eq!(<[u8; 4]>::from(DateTime::MAX), [0x7D, 0xBF, 0x9F, 0xFF]);
+    }
+
+    #[test]
+    fn from_date_time_to_bytes_matches_to_zip_bytes() {
+        for dt in [
+            DateTime::MIN,
+            DateTime::new(
+                Date::new(0b0010_1101_0111_1010).unwrap(),
+                Time::new(0b1001_1011_0010_0000).unwrap(),
+            ),
+            DateTime::MAX,
+        ] {
+            assert_eq!(<[u8; 4]>::from(dt), dt.to_zip_bytes());
+        }
+    }
 
     #[test]
     fn from_date_time_to_primitive_date_time() {
@@ -310,6 +453,36 @@ mod tests {
         );
     }
 
+    #[test]
+    fn from_date_and_time_to_date_time() {
+        assert_eq!(
+            DateTime::from((Date::MIN, Time::MIN)),
+            DateTime::new(Date::MIN, Time::MIN)
+        );
+        // <https://devblogs.microsoft.com/oldnewthing/20030905-02/?p=42653>.
+        let (date, time) = (
+            Date::new(0b0010_1101_0111_1010).unwrap(),
+            Time::new(0b1001_1011_0010_0000).unwrap(),
+        );
+        assert_eq!(DateTime::from((date, time)), DateTime::new(date, time));
+        // <https://github.com/zip-rs/zip/blob/v0.6.4/src/types.rs#L553-L569>.
+        let (date, time) = (
+            Date::new(0b0100_1101_0111_0001).unwrap(),
+            Time::new(0b0101_0100_1100_1111).unwrap(),
+        );
+        assert_eq!(DateTime::from((date, time)), DateTime::new(date, time));
+        assert_eq!(
+            DateTime::from((Date::MAX, Time::MAX)),
+            DateTime::new(Date::MAX, Time::MAX)
+        );
+    }
+
+    #[test]
+    fn from_date_and_time_round_trip() {
+        let dt: DateTime = (Date::MAX, Time::MIN).into();
+        assert_eq!((dt.date(), dt.time()), (Date::MAX, Time::MIN));
+    }
+
     #[test]
     fn try_from_primitive_date_time_to_date_time_before_dos_date_time_epoch() {
         assert_eq!(
@@ -486,4 +659,95 @@ mod tests {
             DateTimeRangeErrorKind::Overflow.into()
         );
     }
+
+    #[test]
+    fn try_from_bytes_to_date_time() {
+        assert_eq!(
+            DateTime::try_from([0x00, 0x00, 0x21, 0x00]).unwrap(),
+            DateTime::MIN
+        );
+        // <https://github.com/zip-rs/zip/blob/v0.6.4/src/types.rs#L553-L569>.
+        assert_eq!(
+            DateTime::try_from([0xCF, 0x54, 0x71, 0x4D]).unwrap(),
+            DateTime::new(
+                Date::new(0b0100_1101_0111_0001).unwrap(),
+                Time::new(0b0101_0100_1100_1111).unwrap()
+            )
+        );
+        assert_eq!(
+            DateTime::try_from([0x7D, 0xBF, 0x9F, 0xFF]).unwrap(),
+            DateTime::MAX
+        );
+    }
+
+    #[test]
+    fn try_from_bytes_to_date_time_with_invalid_bits() {
+        assert_eq!(
+            DateTime::try_from([0x00, 0x00, 0x01, 0x00]).unwrap_err(),
+            InvalidDateField::MonthZero.into()
+        );
+        assert_eq!(
+            DateTime::try_from([0x00, 0x00, 0x20, 0x00]).unwrap_err(),
+            InvalidDateField::DayZero.into()
+        );
+        assert_eq!(
+            DateTime::try_from([0x00, 0xC0, 0x21, 0x00]).unwrap_err(),
+            InvalidTimeField::HourTooLarge(24).into()
+        );
+        // The Day field is checked before the Hour field.
+        assert_eq!(
+            DateTime::try_from([0x00, 0xC0, 0x20, 0x00]).unwrap_err(),
+            InvalidDateField::DayZero.into()
+        );
+    }
+
+    #[test]
+    fn bytes_round_trip() {
+        for dt in [
+            DateTime::MIN,
+            DateTime::new(
+                Date::new(0b0010_1101_0111_1010).unwrap(),
+                Time::new(0b1001_1011_0010_0000).unwrap(),
+            ),
+            DateTime::MAX,
+        ] {
+            assert_eq!(DateTime::try_from(<[u8; 4]>::from(dt)).unwrap(), dt);
+        }
+    }
+
+    #[test]
+    fn is_representable() {
+        assert!(!super::is_representable(PrimitiveDateTime::MIN));
+        assert!(!super::is_representable(
+            datetime!(1979-12-31 23:59:59.999_999_999)
+        ));
+        assert!(super::is_representable(datetime!(1980-01-01 00:00:00)));
+        // <https://github.com/zip-rs/zip/blob/v0.6.4/src/types.rs#L553-L569>.
+        assert!(super::is_representable(datetime!(2018-11-17 10:38:30)));
+        assert!(super::is_representable(datetime!(2107-12-31 23:59:58)));
+        assert!(super::is_representable(
+            datetime!(2107-12-31 23:59:59.999_999_999)
+        ));
+        assert!(!super::is_representable(datetime!(2108-01-01 00:00:00)));
+        assert!(!super::is_representable(PrimitiveDateTime::MAX));
+    }
+
+    #[test]
+    fn is_representable_agrees_with_try_from() {
+        for dt in [
+            PrimitiveDateTime::MIN,
+            datetime!(1979-12-31 23:59:59.999_999_999),
+            datetime!(1980-01-01 00:00:00),
+            datetime!(2107-12-31 23:59:59.999_999_999),
+            datetime!(2108-01-01 00:00:00),
+            PrimitiveDateTime::MAX,
+        ] {
+            assert_eq!(super::is_representable(dt), DateTime::try_from(dt).is_ok());
+        }
+    }
+
+    #[test]
+    const fn is_representable_is_const_fn() {
+        const _: bool = super::is_representable(PrimitiveDateTime::MIN);
+    }
 }
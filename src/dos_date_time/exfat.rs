@@ -0,0 +1,290 @@
+// SPDX-FileCopyrightText: 2025 Shun Sakai
+//
+// SPDX-License-Identifier: Apache-2.0 OR MIT
+
+//! MS-DOS date and time with the UTC offset used by [exFAT].
+//!
+//! [exFAT]: https://learn.microsoft.com/en-us/windows/win32/fileio/exfat-specification
+
+use time::{OffsetDateTime, PrimitiveDateTime, UtcOffset};
+
+use super::DateTime;
+
+impl DateTime {
+    /// Combines this `DateTime` with the given UTC offset byte of [exFAT].
+    ///
+    /// `offset_byte` is the `UtcOffset` field of an exFAT timestamp. See
+    /// [`ExfatDateTime`] for its structure.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use dos_date_time::{DateTime, time::UtcOffset};
+    /// #
+    /// let dt = DateTime::MIN.with_utc_offset_byte(0x80);
+    /// assert_eq!(dt.datetime(), DateTime::MIN);
+    /// assert_eq!(dt.utc_offset(), Some(UtcOffset::UTC));
+    /// ```
+    ///
+    /// [exFAT]: https://learn.microsoft.com/en-us/windows/win32/fileio/exfat-specification#7410-utcoffset-fields
+    #[must_use]
+    pub const fn with_utc_offset_byte(self, offset_byte: u8) -> ExfatDateTime {
+        ExfatDateTime {
+            dt: self,
+            offset_byte,
+        }
+    }
+}
+
+/// [`DateTime`] with the UTC offset byte of [exFAT].
+///
+/// The UTC offset byte is the `UtcOffset` field of an exFAT timestamp:
+///
+/// - Bit 7 is the `OffsetValid` field, which is set if the offset is valid.
+/// - Bits 0-6 are the `OffsetFromUtc` field, which is the offset from UTC in
+///   15-minute increments as a signed 7-bit integer.
+///
+/// For example, `0x80` is UTC, `0x88` is `+02:00`, and `0xF0` is `-04:00`.
+///
+/// This is returned by [`DateTime::with_utc_offset_byte`].
+///
+/// [exFAT]: https://learn.microsoft.com/en-us/windows/win32/fileio/exfat-specification#7410-utcoffset-fields
+#[derive(Clone, Copy, Debug, Eq, Hash, PartialEq)]
+pub struct ExfatDateTime {
+    dt: DateTime,
+    offset_byte: u8,
+}
+
+impl ExfatDateTime {
+    /// Gets the [`DateTime`] of this `ExfatDateTime`, which is in the local
+    /// time of the offset.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use dos_date_time::DateTime;
+    /// #
+    /// assert_eq!(
+    ///     DateTime::MAX.with_utc_offset_byte(0x88).datetime(),
+    ///     DateTime::MAX
+    /// );
+    /// ```
+    #[must_use]
+    pub const fn datetime(self) -> DateTime {
+        self.dt
+    }
+
+    /// Gets the UTC offset byte of this `ExfatDateTime`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use dos_date_time::DateTime;
+    /// #
+    /// assert_eq!(
+    ///     DateTime::MIN.with_utc_offset_byte(0x88).utc_offset_byte(),
+    ///     0x88
+    /// );
+    /// ```
+    #[must_use]
+    pub const fn utc_offset_byte(self) -> u8 {
+        self.offset_byte
+    }
+
+    #[allow(clippy::missing_panics_doc)]
+    /// Gets the UTC offset of this `ExfatDateTime`.
+    ///
+    /// Returns [`None`] if the `OffsetValid` field of the UTC offset byte is
+    /// not set, in which case the time zone is unknown.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use dos_date_time::{DateTime, time::macros::offset};
+    /// #
+    /// assert_eq!(
+    ///     DateTime::MIN.with_utc_offset_byte(0x80).utc_offset(),
+    ///     Some(offset!(UTC))
+    /// );
+    /// assert_eq!(
+    ///     DateTime::MIN.with_utc_offset_byte(0x88).utc_offset(),
+    ///     Some(offset!(+02:00))
+    /// );
+    /// assert_eq!(
+    ///     DateTime::MIN.with_utc_offset_byte(0xF0).utc_offset(),
+    ///     Some(offset!(-04:00))
+    /// );
+    ///
+    /// assert_eq!(DateTime::MIN.with_utc_offset_byte(0x00).utc_offset(), None);
+    /// ```
+    #[must_use]
+    pub fn utc_offset(self) -> Option<UtcOffset> {
+        (self.offset_byte & 0x80 != 0).then(|| {
+            // Sign-extends the `OffsetFromUtc` field from 7 bits.
+            let quarters = i8::from_ne_bytes([self.offset_byte << 1]) >> 1;
+            UtcOffset::from_whole_seconds(i32::from(quarters) * 900)
+                .expect("UTC offset should be in the range of `UtcOffset`")
+        })
+    }
+
+    /// Converts this `ExfatDateTime` to an [`OffsetDateTime`] with the UTC
+    /// offset.
+    ///
+    /// Returns [`None`] if the `OffsetValid` field of the UTC offset byte is
+    /// not set.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use dos_date_time::{DateTime, time::macros::datetime};
+    /// #
+    /// assert_eq!(
+    ///     DateTime::MIN
+    ///         .with_utc_offset_byte(0x80)
+    ///         .to_offset_date_time(),
+    ///     Some(datetime!(1980-01-01 00:00:00 UTC))
+    /// );
+    /// assert_eq!(
+    ///     DateTime::MIN
+    ///         .with_utc_offset_byte(0x88)
+    ///         .to_offset_date_time(),
+    ///     Some(datetime!(1980-01-01 00:00:00 +02:00))
+    /// );
+    ///
+    /// assert_eq!(
+    ///     DateTime::MIN
+    ///         .with_utc_offset_byte(0x00)
+    ///         .to_offset_date_time(),
+    ///     None
+    /// );
+    /// ```
+    #[must_use]
+    pub fn to_offset_date_time(self) -> Option<OffsetDateTime> {
+        self.utc_offset()
+            .map(|offset| PrimitiveDateTime::from(self.dt).assume_offset(offset))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use time::macros::{datetime, offset};
+
+    use super::*;
+
+    #[test]
+    fn clone() {
+        let dt = DateTime::MIN.with_utc_offset_byte(0x80);
+        assert_eq!(dt.clone(), dt);
+    }
+
+    #[test]
+    fn debug() {
+        assert_eq!(
+            format!("{:?}", DateTime::MIN.with_utc_offset_byte(0x80)),
+            "ExfatDateTime { dt: DateTime { date: Date(33), time: Time(0) }, offset_byte: 128 }"
+        );
+    }
+
+    #[test]
+    fn with_utc_offset_byte() {
+        let dt = DateTime::MAX.with_utc_offset_byte(0x88);
+        assert_eq!(dt.datetime(), DateTime::MAX);
+        assert_eq!(dt.utc_offset_byte(), 0x88);
+    }
+
+    #[test]
+    const fn with_utc_offset_byte_is_const_fn() {
+        const _: ExfatDateTime = DateTime::MIN.with_utc_offset_byte(0x80);
+    }
+
+    #[test]
+    fn utc_offset() {
+        assert_eq!(
+            DateTime::MIN.with_utc_offset_byte(0x80).utc_offset(),
+            Some(offset!(UTC))
+        );
+        assert_eq!(
+            DateTime::MIN.with_utc_offset_byte(0x81).utc_offset(),
+            Some(offset!(+00:15))
+        );
+        assert_eq!(
+            DateTime::MIN.with_utc_offset_byte(0x88).utc_offset(),
+            Some(offset!(+02:00))
+        );
+        assert_eq!(
+            DateTime::MIN.with_utc_offset_byte(0xBF).utc_offset(),
+            Some(offset!(+15:45))
+        );
+        assert_eq!(
+            DateTime::MIN.with_utc_offset_byte(0xC0).utc_offset(),
+            Some(offset!(-16:00))
+        );
+        assert_eq!(
+            DateTime::MIN.with_utc_offset_byte(0xF0).utc_offset(),
+            Some(offset!(-04:00))
+        );
+        assert_eq!(
+            DateTime::MIN.with_utc_offset_byte(0xFF).utc_offset(),
+            Some(offset!(-00:15))
+        );
+    }
+
+    #[test]
+    fn utc_offset_with_invalid_offset() {
+        assert!(
+            DateTime::MIN
+                .with_utc_offset_byte(0x00)
+                .utc_offset()
+                .is_none()
+        );
+        assert!(
+            DateTime::MIN
+                .with_utc_offset_byte(0x08)
+                .utc_offset()
+                .is_none()
+        );
+        assert!(
+            DateTime::MIN
+                .with_utc_offset_byte(0x7F)
+                .utc_offset()
+                .is_none()
+        );
+    }
+
+    #[test]
+    fn to_offset_date_time() {
+        assert_eq!(
+            DateTime::MIN
+                .with_utc_offset_byte(0x80)
+                .to_offset_date_time()
+                .unwrap(),
+            datetime!(1980-01-01 00:00:00 UTC)
+        );
+        // <https://github.com/zip-rs/zip/blob/v0.6.4/src/types.rs#L553-L569>.
+        assert_eq!(
+            DateTime::try_from(datetime!(2018-11-17 10:38:30))
+                .unwrap()
+                .with_utc_offset_byte(0x88)
+                .to_offset_date_time()
+                .unwrap(),
+            datetime!(2018-11-17 10:38:30 +02:00)
+        );
+        assert_eq!(
+            DateTime::MAX
+                .with_utc_offset_byte(0xF0)
+                .to_offset_date_time()
+                .unwrap(),
+            datetime!(2107-12-31 23:59:58 -04:00)
+        );
+    }
+
+    #[test]
+    fn to_offset_date_time_with_invalid_offset() {
+        assert!(
+            DateTime::MIN
+                .with_utc_offset_byte(0x00)
+                .to_offset_date_time()
+                .is_none()
+        );
+    }
+}
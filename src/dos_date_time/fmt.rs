@@ -4,10 +4,227 @@
 
 //! Utilities for formatting and printing [`DateTime`].
 
+#[cfg(feature = "alloc")]
+use alloc::string::String;
 use core::fmt;
 
+#[cfg(feature = "formatting")]
+use time::formatting::Formattable;
+
 use super::DateTime;
 
+impl DateTime {
+    /// Returns the value of this `DateTime` in the [RFC 2822 format].
+    ///
+    /// The MS-DOS date and time do not have a time zone, so this method
+    /// assumes that `self` is in UTC, and the offset is always `+0000`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use dos_date_time::DateTime;
+    /// #
+    /// assert_eq!(
+    ///     DateTime::MIN.to_rfc2822(),
+    ///     "Tue, 01 Jan 1980 00:00:00 +0000"
+    /// );
+    /// assert_eq!(
+    ///     DateTime::MAX.to_rfc2822(),
+    ///     "Sat, 31 Dec 2107 23:59:58 +0000"
+    /// );
+    /// ```
+    ///
+    /// [RFC 2822 format]: https://datatracker.ietf.org/doc/html/rfc2822#section-3.3
+    #[cfg(feature = "alloc")]
+    #[must_use]
+    pub fn to_rfc2822(self) -> String {
+        const WEEKDAYS: [&str; 7] = ["Mon", "Tue", "Wed", "Thu", "Fri", "Sat", "Sun"];
+        const MONTHS: [&str; 12] = [
+            "Jan", "Feb", "Mar", "Apr", "May", "Jun", "Jul", "Aug", "Sep", "Oct", "Nov", "Dec",
+        ];
+
+        let weekday = WEEKDAYS[usize::from(self.date().weekday().number_days_from_monday())];
+        let (year, month, day) = (
+            self.year(),
+            MONTHS[usize::from(u8::from(self.month()) - 1)],
+            self.day(),
+        );
+        let time = self.time();
+        format!("{weekday}, {day:02} {month} {year:04} {time} +0000")
+    }
+
+    /// Returns the raw bits of the MS-DOS date and time of this `DateTime`.
+    ///
+    /// This is intended for debugging, such as when writing bug reports.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use dos_date_time::DateTime;
+    /// #
+    /// assert_eq!(
+    ///     DateTime::MIN.debug_bits(),
+    ///     "date=0000000000100001b time=0000000000000000b"
+    /// );
+    /// assert_eq!(
+    ///     DateTime::MAX.debug_bits(),
+    ///     "date=1111111110011111b time=1011111101111101b"
+    /// );
+    /// ```
+    #[cfg(feature = "alloc")]
+    #[must_use]
+    pub fn debug_bits(self) -> String {
+        let (date, time) = (self.date().to_raw(), self.time().to_raw());
+        format!("date={date:016b}b time={time:016b}b")
+    }
+
+    /// Returns the [ISO week] of this `DateTime` in the format `YYYY-Www`, such
+    /// as `"2020-W53"`.
+    ///
+    /// The year is the ISO week-numbering year, which may differ from
+    /// [`DateTime::year`] near the start and the end of the year.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use dos_date_time::DateTime;
+    /// #
+    /// assert_eq!(DateTime::MIN.to_iso_week_string(), "1980-W01");
+    /// assert_eq!(DateTime::MAX.to_iso_week_string(), "2107-W52");
+    /// ```
+    ///
+    /// [ISO week]: https://en.wikipedia.org/wiki/ISO_week_date
+    #[cfg(feature = "alloc")]
+    #[must_use]
+    pub fn to_iso_week_string(self) -> String {
+        let (year, week, _) = time::Date::from(self.date()).to_iso_week_date();
+        format!("{year:04}-W{week:02}")
+    }
+
+    /// Returns the key of this `DateTime` for sortable names, such as
+    /// `"16Q2N6F"`.
+    ///
+    /// The key is [`DateTime::to_sort_key`] encoded in [Crockford's Base32],
+    /// zero-padded to 7 characters. So the lexicographic order of the keys is
+    /// the chronological order, and the keys are safe to use in file names.
+    /// Use [`DateTime::from_base32_key`] to decode the key.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use dos_date_time::DateTime;
+    /// #
+    /// assert_eq!(DateTime::MIN.to_base32_key(), "0022000");
+    /// assert_eq!(DateTime::MAX.to_base32_key(), "3ZSZFVX");
+    /// ```
+    ///
+    /// [Crockford's Base32]: https://www.crockford.com/base32.html
+    #[cfg(feature = "alloc")]
+    #[must_use]
+    pub fn to_base32_key(self) -> String {
+        let key = self.to_sort_key();
+        (0..7)
+            .rev()
+            .map(|i| char::from(super::CROCKFORD_BASE32[((key >> (i * 5)) & 0x1F) as usize]))
+            .collect()
+    }
+
+    /// Formats this `DateTime` using the given [format description].
+    ///
+    /// This is equivalent to converting `self` to [`PrimitiveDateTime`] and
+    /// formatting it. The MS-DOS date and time do not have a time zone, so
+    /// format descriptions which require an offset cannot be used.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`Err`] if `self` could not be formatted with `format`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use dos_date_time::{DateTime, time::macros::format_description};
+    /// #
+    /// let format = format_description!("[day]/[month]/[year] [hour]:[minute]");
+    /// assert_eq!(DateTime::MIN.format(&format).unwrap(), "01/01/1980 00:00");
+    /// assert_eq!(DateTime::MAX.format(&format).unwrap(), "31/12/2107 23:59");
+    /// ```
+    ///
+    /// [format description]: time::format_description
+    /// [`PrimitiveDateTime`]: time::PrimitiveDateTime
+    #[cfg(feature = "formatting")]
+    pub fn format(
+        self,
+        format: &(impl Formattable + ?Sized),
+    ) -> Result<String, time::error::Format> {
+        time::PrimitiveDateTime::from(self).format(format)
+    }
+
+    /// Returns an object that implements [`Display`](fmt::Display) for showing
+    /// this `DateTime` in the [RFC 3339 format] with the `Z` suffix.
+    ///
+    /// The MS-DOS date and time do not have a time zone, so this is useful for
+    /// making the assumption that `self` is in UTC explicit in the output.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use dos_date_time::DateTime;
+    /// #
+    /// assert_eq!(
+    ///     format!("{}", DateTime::MIN.display_utc()),
+    ///     "1980-01-01T00:00:00Z"
+    /// );
+    /// assert_eq!(
+    ///     format!("{}", DateTime::MAX.display_utc()),
+    ///     "2107-12-31T23:59:58Z"
+    /// );
+    /// ```
+    ///
+    /// [RFC 3339 format]: https://datatracker.ietf.org/doc/html/rfc3339#section-5.6
+    #[must_use]
+    pub const fn display_utc(self) -> DisplayUtc {
+        DisplayUtc(self)
+    }
+
+    #[allow(clippy::missing_panics_doc)]
+    /// Writes the [`Display`](fmt::Display) output of this `DateTime` into
+    /// `buf`.
+    ///
+    /// `buf` is cleared first, so its previous contents are discarded. This
+    /// is equivalent to `*buf = self.to_string()`, but reuses the allocation
+    /// of `buf`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use dos_date_time::DateTime;
+    /// #
+    /// let mut buf = String::new();
+    /// DateTime::MIN.to_string_buf(&mut buf);
+    /// assert_eq!(buf, "1980-01-01 00:00:00");
+    /// DateTime::MAX.to_string_buf(&mut buf);
+    /// assert_eq!(buf, "2107-12-31 23:59:58");
+    /// ```
+    #[cfg(feature = "alloc")]
+    pub fn to_string_buf(self, buf: &mut String) {
+        buf.clear();
+        fmt::write(buf, format_args!("{self}")).expect("formatting should not fail");
+    }
+}
+
+/// A helper type for showing [`DateTime`] in UTC.
+///
+/// This is returned by [`DateTime::display_utc`].
+#[derive(Clone, Copy, Debug)]
+pub struct DisplayUtc(DateTime);
+
+impl fmt::Display for DisplayUtc {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let (date, time) = (self.0.date(), self.0.time());
+        write!(f, "{date}T{time}Z")
+    }
+}
+
 impl fmt::Display for DateTime {
     /// Shows the value of this `DateTime` in the well-known [RFC 3339 format].
     ///
@@ -82,4 +299,282 @@ mod tests {
         );
         assert_eq!(format!("{}", DateTime::MAX), "2107-12-31 23:59:58");
     }
+
+    #[cfg(feature = "alloc")]
+    #[test]
+    fn to_rfc2822() {
+        assert_eq!(
+            DateTime::MIN.to_rfc2822(),
+            "Tue, 01 Jan 1980 00:00:00 +0000"
+        );
+        // <https://devblogs.microsoft.com/oldnewthing/20030905-02/?p=42653>.
+        assert_eq!(
+            DateTime::try_from(datetime!(2002-11-26 19:25:00))
+                .unwrap()
+                .to_rfc2822(),
+            "Tue, 26 Nov 2002 19:25:00 +0000"
+        );
+        // <https://github.com/zip-rs/zip/blob/v0.6.4/src/types.rs#L553-L569>.
+        assert_eq!(
+            DateTime::try_from(datetime!(2018-11-17 10:38:30))
+                .unwrap()
+                .to_rfc2822(),
+            "Sat, 17 Nov 2018 10:38:30 +0000"
+        );
+        assert_eq!(
+            DateTime::MAX.to_rfc2822(),
+            "Sat, 31 Dec 2107 23:59:58 +0000"
+        );
+    }
+
+    #[cfg(feature = "alloc")]
+    #[test]
+    fn to_rfc2822_matches_time_formatting() {
+        use time::format_description::well_known::Rfc2822;
+
+        for dt in [DateTime::MIN, DateTime::MAX] {
+            assert_eq!(
+                dt.to_rfc2822(),
+                time::PrimitiveDateTime::from(dt)
+                    .as_utc()
+                    .format(&Rfc2822)
+                    .unwrap()
+            );
+        }
+    }
+
+    #[cfg(feature = "alloc")]
+    #[test]
+    fn debug_bits() {
+        assert_eq!(
+            DateTime::MIN.debug_bits(),
+            "date=0000000000100001b time=0000000000000000b"
+        );
+        // <https://devblogs.microsoft.com/oldnewthing/20030905-02/?p=42653>.
+        assert_eq!(
+            DateTime::try_from(datetime!(2002-11-26 19:25:00))
+                .unwrap()
+                .debug_bits(),
+            "date=0010110101111010b time=1001101100100000b"
+        );
+        // <https://github.com/zip-rs/zip/blob/v0.6.4/src/types.rs#L553-L569>.
+        assert_eq!(
+            DateTime::try_from(datetime!(2018-11-17 10:38:30))
+                .unwrap()
+                .debug_bits(),
+            "date=0100110101110001b time=0101010011001111b"
+        );
+        assert_eq!(
+            DateTime::MAX.debug_bits(),
+            "date=1111111110011111b time=1011111101111101b"
+        );
+    }
+
+    #[cfg(feature = "formatting")]
+    #[test]
+    fn format() {
+        use time::macros::format_description;
+
+        let format = format_description!("[day]/[month]/[year] [hour]:[minute]:[second]");
+        assert_eq!(
+            DateTime::MIN.format(&format).unwrap(),
+            "01/01/1980 00:00:00"
+        );
+        // <https://devblogs.microsoft.com/oldnewthing/20030905-02/?p=42653>.
+        assert_eq!(
+            DateTime::try_from(datetime!(2002-11-26 19:25:00))
+                .unwrap()
+                .format(&format)
+                .unwrap(),
+            "26/11/2002 19:25:00"
+        );
+        // <https://github.com/zip-rs/zip/blob/v0.6.4/src/types.rs#L553-L569>.
+        assert_eq!(
+            DateTime::try_from(datetime!(2018-11-17 10:38:30))
+                .unwrap()
+                .format(&format)
+                .unwrap(),
+            "17/11/2018 10:38:30"
+        );
+        assert_eq!(
+            DateTime::MAX.format(&format).unwrap(),
+            "31/12/2107 23:59:58"
+        );
+    }
+
+    #[cfg(feature = "alloc")]
+    #[test]
+    fn to_iso_week_string() {
+        assert_eq!(DateTime::MIN.to_iso_week_string(), "1980-W01");
+        // <https://devblogs.microsoft.com/oldnewthing/20030905-02/?p=42653>.
+        assert_eq!(
+            DateTime::try_from(datetime!(2002-11-26 19:25:00))
+                .unwrap()
+                .to_iso_week_string(),
+            "2002-W48"
+        );
+        // <https://github.com/zip-rs/zip/blob/v0.6.4/src/types.rs#L553-L569>.
+        assert_eq!(
+            DateTime::try_from(datetime!(2018-11-17 10:38:30))
+                .unwrap()
+                .to_iso_week_string(),
+            "2018-W46"
+        );
+        assert_eq!(DateTime::MAX.to_iso_week_string(), "2107-W52");
+    }
+
+    #[cfg(feature = "alloc")]
+    #[test]
+    fn to_iso_week_string_with_week_53() {
+        assert_eq!(
+            DateTime::try_from(datetime!(2020-12-31 00:00:00))
+                .unwrap()
+                .to_iso_week_string(),
+            "2020-W53"
+        );
+        // The ISO week-numbering year is the previous year.
+        assert_eq!(
+            DateTime::try_from(datetime!(2021-01-03 00:00:00))
+                .unwrap()
+                .to_iso_week_string(),
+            "2020-W53"
+        );
+        assert_eq!(
+            DateTime::try_from(datetime!(2021-01-04 00:00:00))
+                .unwrap()
+                .to_iso_week_string(),
+            "2021-W01"
+        );
+        // The ISO week-numbering year is the next year.
+        assert_eq!(
+            DateTime::try_from(datetime!(2018-12-31 00:00:00))
+                .unwrap()
+                .to_iso_week_string(),
+            "2019-W01"
+        );
+    }
+
+    #[test]
+    fn display_utc() {
+        assert_eq!(
+            format!("{}", DateTime::MIN.display_utc()),
+            "1980-01-01T00:00:00Z"
+        );
+        // <https://devblogs.microsoft.com/oldnewthing/20030905-02/?p=42653>.
+        assert_eq!(
+            format!(
+                "{}",
+                DateTime::try_from(datetime!(2002-11-26 19:25:00))
+                    .unwrap()
+                    .display_utc()
+            ),
+            "2002-11-26T19:25:00Z"
+        );
+        // <https://github.com/zip-rs/zip/blob/v0.6.4/src/types.rs#L553-L569>.
+        assert_eq!(
+            format!(
+                "{}",
+                DateTime::try_from(datetime!(2018-11-17 10:38:30))
+                    .unwrap()
+                    .display_utc()
+            ),
+            "2018-11-17T10:38:30Z"
+        );
+        assert_eq!(
+            format!("{}", DateTime::MAX.display_utc()),
+            "2107-12-31T23:59:58Z"
+        );
+    }
+
+    #[test]
+    fn display_utc_differs_from_display() {
+        let s = format!("{}", DateTime::MIN.display_utc());
+        assert!(s.contains('T'));
+        assert!(s.ends_with('Z'));
+        assert_eq!(
+            s.replace('T', " ").trim_end_matches('Z'),
+            format!("{}", DateTime::MIN)
+        );
+    }
+
+    #[cfg(feature = "alloc")]
+    #[test]
+    fn to_base32_key() {
+        assert_eq!(DateTime::MIN.to_base32_key(), "0022000");
+        // <https://devblogs.microsoft.com/oldnewthing/20030905-02/?p=42653>.
+        assert_eq!(
+            DateTime::try_from(datetime!(2002-11-26 19:25:00))
+                .unwrap()
+                .to_base32_key(),
+            "0PQN6S0"
+        );
+        // <https://github.com/zip-rs/zip/blob/v0.6.4/src/types.rs#L553-L569>.
+        assert_eq!(
+            DateTime::try_from(datetime!(2018-11-17 10:38:30))
+                .unwrap()
+                .to_base32_key(),
+            "16Q2N6F"
+        );
+        assert_eq!(DateTime::MAX.to_base32_key(), "3ZSZFVX");
+    }
+
+    #[cfg(feature = "alloc")]
+    #[test]
+    fn to_base32_key_order() {
+        let values = [
+            DateTime::MIN,
+            DateTime::try_from(datetime!(1980-01-01 00:00:02)).unwrap(),
+            DateTime::try_from(datetime!(1980-01-01 00:01:00)).unwrap(),
+            DateTime::try_from(datetime!(1980-01-02 00:00:00)).unwrap(),
+            DateTime::try_from(datetime!(2002-11-26 19:25:00)).unwrap(),
+            DateTime::try_from(datetime!(2018-11-17 10:38:30)).unwrap(),
+            DateTime::try_from(datetime!(2018-11-17 10:38:32)).unwrap(),
+            DateTime::try_from(datetime!(2107-12-31 23:59:56)).unwrap(),
+            DateTime::MAX,
+        ];
+        for (a, b) in values.iter().zip(&values[1..]) {
+            assert!(a < b);
+            assert!(a.to_base32_key() < b.to_base32_key());
+        }
+    }
+
+    #[cfg(feature = "alloc")]
+    #[test]
+    fn base32_key_round_trip() {
+        for index in (0..DateTime::INDEX_COUNT).step_by(999_983) {
+            let dt = DateTime::from_index(index).unwrap();
+            let key = dt.to_base32_key();
+            assert_eq!(key.len(), 7);
+            assert_eq!(DateTime::from_base32_key(&key), Ok(dt));
+        }
+    }
+
+    #[cfg(feature = "alloc")]
+    #[test]
+    fn to_string_buf() {
+        use alloc::string::ToString;
+
+        // <https://github.com/zip-rs/zip/blob/v0.6.4/src/types.rs#L553-L569>.
+        for value in [
+            DateTime::MIN,
+            DateTime::try_from(datetime!(2018-11-17 10:38:30)).unwrap(),
+            DateTime::MAX,
+        ] {
+            let mut buf = String::new();
+            value.to_string_buf(&mut buf);
+            assert_eq!(buf, value.to_string());
+        }
+    }
+
+    #[cfg(feature = "alloc")]
+    #[test]
+    fn to_string_buf_reuses_buffer() {
+        let mut buf = String::from("this should be discarded entirely");
+        DateTime::MAX.to_string_buf(&mut buf);
+        assert_eq!(buf, "2107-12-31 23:59:58");
+        let capacity = buf.capacity();
+        DateTime::MIN.to_string_buf(&mut buf);
+        assert_eq!(buf, format!("{}", DateTime::MIN));
+        assert_eq!(buf.capacity(), capacity);
+    }
 }
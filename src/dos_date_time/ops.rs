@@ -0,0 +1,447 @@
+// SPDX-FileCopyrightText: 2025 Shun Sakai
+//
+// SPDX-License-Identifier: Apache-2.0 OR MIT
+
+//! Implementations of arithmetic operators for [`DateTime`].
+
+use core::ops::{Add, Sub};
+
+use time::{Duration, PrimitiveDateTime};
+
+use super::DateTime;
+use crate::{Days, Months};
+
+impl Add<Duration> for DateTime {
+    type Output = Self;
+
+    /// Computes `self + rhs`.
+    ///
+    /// `rhs` may be negative, in which case this moves backward.
+    ///
+    /// <div class="warning">
+    ///
+    /// The resolution of MS-DOS date and time is 2 seconds. So this method
+    /// rounds towards zero, truncating any fractional part of the exact result
+    /// of dividing seconds by 2.
+    ///
+    /// </div>
+    ///
+    /// # Panics
+    ///
+    /// Panics if the result is out of range for MS-DOS date and time. See
+    /// [`DateTime::checked_add`] and [`DateTime::saturating_add`] for
+    /// non-panicking alternatives.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use dos_date_time::{
+    /// #     DateTime,
+    /// #     time::{Duration, macros::datetime},
+    /// # };
+    /// #
+    /// let dt = DateTime::try_from(datetime!(2018-11-17 10:38:30)).unwrap();
+    /// assert_eq!(
+    ///     dt + Duration::hours(1),
+    ///     DateTime::try_from(datetime!(2018-11-17 11:38:30)).unwrap()
+    /// );
+    /// assert_eq!(
+    ///     dt + -Duration::hours(1),
+    ///     DateTime::try_from(datetime!(2018-11-17 09:38:30)).unwrap()
+    /// );
+    /// ```
+    fn add(self, rhs: Duration) -> Self::Output {
+        self.checked_add(rhs)
+            .expect("resulting value should be in the range of MS-DOS date and time")
+    }
+}
+
+impl Sub<Duration> for DateTime {
+    type Output = Self;
+
+    /// Computes `self - rhs`.
+    ///
+    /// `rhs` may be negative, in which case this moves forward.
+    ///
+    /// <div class="warning">
+    ///
+    /// The resolution of MS-DOS date and time is 2 seconds. So this method
+    /// rounds towards zero, truncating any fractional part of the exact result
+    /// of dividing seconds by 2.
+    ///
+    /// </div>
+    ///
+    /// # Panics
+    ///
+    /// Panics if the result is out of range for MS-DOS date and time. See
+    /// [`DateTime::checked_sub`] and [`DateTime::saturating_sub`] for
+    /// non-panicking alternatives.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use dos_date_time::{
+    /// #     DateTime,
+    /// #     time::{Duration, macros::datetime},
+    /// # };
+    /// #
+    /// let dt = DateTime::try_from(datetime!(2018-11-17 10:38:30)).unwrap();
+    /// assert_eq!(
+    ///     dt - Duration::hours(1),
+    ///     DateTime::try_from(datetime!(2018-11-17 09:38:30)).unwrap()
+    /// );
+    /// ```
+    fn sub(self, rhs: Duration) -> Self::Output {
+        self.checked_sub(rhs)
+            .expect("resulting value should be in the range of MS-DOS date and time")
+    }
+}
+
+impl Add<Days> for DateTime {
+    type Output = Self;
+
+    /// Computes `self + rhs`.
+    ///
+    /// `rhs` may be negative, in which case this moves backward.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the result is out of range for MS-DOS date and time. See
+    /// [`DateTime::checked_add_days`] for a non-panicking alternative.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use dos_date_time::{DateTime, Days, time::macros::datetime};
+    /// #
+    /// assert_eq!(
+    ///     DateTime::MIN + Days(31),
+    ///     DateTime::try_from(datetime!(1980-02-01 00:00:00)).unwrap()
+    /// );
+    /// ```
+    fn add(self, rhs: Days) -> Self::Output {
+        self.checked_add_days(rhs.0)
+            .expect("resulting value should be in the range of MS-DOS date and time")
+    }
+}
+
+impl Sub<Days> for DateTime {
+    type Output = Self;
+
+    /// Computes `self - rhs`.
+    ///
+    /// `rhs` may be negative, in which case this moves forward.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the result is out of range for MS-DOS date and time. See
+    /// [`DateTime::checked_sub_days`] for a non-panicking alternative.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use dos_date_time::{DateTime, Days, time::macros::datetime};
+    /// #
+    /// assert_eq!(
+    ///     DateTime::MAX - Days(31),
+    ///     DateTime::try_from(datetime!(2107-11-30 23:59:58)).unwrap()
+    /// );
+    /// ```
+    fn sub(self, rhs: Days) -> Self::Output {
+        self.checked_sub_days(rhs.0)
+            .expect("resulting value should be in the range of MS-DOS date and time")
+    }
+}
+
+impl Add<Months> for DateTime {
+    type Output = Self;
+
+    /// Computes `self + rhs`.
+    ///
+    /// `rhs` may be negative, in which case this moves backward. The day of
+    /// the month is clamped to the last day of the month if the resulting
+    /// month is shorter.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the result is out of range for MS-DOS date and time. See
+    /// [`DateTime::checked_add_months`] for a non-panicking alternative.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use dos_date_time::{DateTime, Months, time::macros::datetime};
+    /// #
+    /// assert_eq!(
+    ///     DateTime::MIN + Months(1),
+    ///     DateTime::try_from(datetime!(1980-02-01 00:00:00)).unwrap()
+    /// );
+    /// ```
+    fn add(self, rhs: Months) -> Self::Output {
+        self.checked_add_months(rhs.0)
+            .expect("resulting value should be in the range of MS-DOS date and time")
+    }
+}
+
+impl Sub<Months> for DateTime {
+    type Output = Self;
+
+    /// Computes `self - rhs`.
+    ///
+    /// `rhs` may be negative, in which case this moves forward. The day of
+    /// the month is clamped to the last day of the month if the resulting
+    /// month is shorter.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the result is out of range for MS-DOS date and time. See
+    /// [`DateTime::checked_sub_months`] for a non-panicking alternative.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use dos_date_time::{DateTime, Months, time::macros::datetime};
+    /// #
+    /// assert_eq!(
+    ///     DateTime::MAX - Months(1),
+    ///     DateTime::try_from(datetime!(2107-11-30 23:59:58)).unwrap()
+    /// );
+    /// ```
+    fn sub(self, rhs: Months) -> Self::Output {
+        self.checked_sub_months(rhs.0)
+            .expect("resulting value should be in the range of MS-DOS date and time")
+    }
+}
+
+impl Sub for DateTime {
+    type Output = Duration;
+
+    /// Returns the signed duration from `rhs` to `self`.
+    ///
+    /// The returned value is negative if `self` is before `rhs`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use dos_date_time::{DateTime, time::Duration};
+    /// #
+    /// assert_eq!(
+    ///     DateTime::MAX - DateTime::MIN,
+    ///     Duration::seconds(4_039_286_398)
+    /// );
+    /// assert_eq!(
+    ///     DateTime::MIN - DateTime::MAX,
+    ///     Duration::seconds(-4_039_286_398)
+    /// );
+    /// ```
+    fn sub(self, rhs: Self) -> Self::Output {
+        PrimitiveDateTime::from(self) - PrimitiveDateTime::from(rhs)
+    }
+}
+
+/// Returns the signed duration from `a` to `b`.
+///
+/// This is equivalent to `b - a`. The returned value is negative if `b` is
+/// before `a`.
+///
+/// # Examples
+///
+/// ```
+/// # use dos_date_time::{DateTime, time::Duration};
+/// #
+/// assert_eq!(
+///     dos_date_time::between(DateTime::MIN, DateTime::MAX),
+///     Duration::seconds(4_039_286_398)
+/// );
+/// assert_eq!(
+///     dos_date_time::between(DateTime::MAX, DateTime::MIN),
+///     Duration::seconds(-4_039_286_398)
+/// );
+/// ```
+#[must_use]
+pub fn between(a: DateTime, b: DateTime) -> Duration {
+    b - a
+}
+
+#[cfg(test)]
+mod tests {
+    use time::macros::datetime;
+
+    use super::*;
+
+    #[test]
+    fn add() {
+        // <https://github.com/zip-rs/zip/blob/v0.6.4/src/types.rs#L553-L569>.
+        let dt = DateTime::try_from(datetime!(2018-11-17 10:38:30)).unwrap();
+        assert_eq!(dt + Duration::ZERO, dt);
+        assert_eq!(
+            dt + Duration::hours(1),
+            DateTime::try_from(datetime!(2018-11-17 11:38:30)).unwrap()
+        );
+        assert_eq!(
+            dt + -Duration::hours(1),
+            DateTime::try_from(datetime!(2018-11-17 09:38:30)).unwrap()
+        );
+        assert_eq!(
+            DateTime::MIN + Duration::seconds(3),
+            DateTime::try_from(datetime!(1980-01-01 00:00:02)).unwrap()
+        );
+    }
+
+    #[test]
+    #[should_panic(expected = "resulting value should be in the range of MS-DOS date and time")]
+    fn add_with_overflow() {
+        let _ = DateTime::MAX + Duration::seconds(2);
+    }
+
+    #[test]
+    #[should_panic(expected = "resulting value should be in the range of MS-DOS date and time")]
+    fn add_with_negative_overflow() {
+        let _ = DateTime::MIN + -Duration::SECOND;
+    }
+
+    #[test]
+    fn sub_duration() {
+        // <https://github.com/zip-rs/zip/blob/v0.6.4/src/types.rs#L553-L569>.
+        let dt = DateTime::try_from(datetime!(2018-11-17 10:38:30)).unwrap();
+        assert_eq!(dt - Duration::ZERO, dt);
+        assert_eq!(
+            dt - Duration::hours(1),
+            DateTime::try_from(datetime!(2018-11-17 09:38:30)).unwrap()
+        );
+        assert_eq!(
+            dt - -Duration::hours(1),
+            DateTime::try_from(datetime!(2018-11-17 11:38:30)).unwrap()
+        );
+    }
+
+    #[test]
+    #[should_panic(expected = "resulting value should be in the range of MS-DOS date and time")]
+    fn sub_duration_with_overflow() {
+        let _ = DateTime::MIN - Duration::seconds(2);
+    }
+
+    #[test]
+    fn add_days() {
+        // <https://github.com/zip-rs/zip/blob/v0.6.4/src/types.rs#L553-L569>.
+        let dt = DateTime::try_from(datetime!(2018-11-17 10:38:30)).unwrap();
+        assert_eq!(dt + Days(0), dt);
+        assert_eq!(
+            dt + Days(1),
+            DateTime::try_from(datetime!(2018-11-18 10:38:30)).unwrap()
+        );
+        assert_eq!(
+            dt + Days(-1),
+            DateTime::try_from(datetime!(2018-11-16 10:38:30)).unwrap()
+        );
+    }
+
+    #[test]
+    #[should_panic(expected = "resulting value should be in the range of MS-DOS date and time")]
+    fn add_days_with_overflow() {
+        let _ = DateTime::MAX + Days(1);
+    }
+
+    #[test]
+    fn sub_days() {
+        // <https://github.com/zip-rs/zip/blob/v0.6.4/src/types.rs#L553-L569>.
+        let dt = DateTime::try_from(datetime!(2018-11-17 10:38:30)).unwrap();
+        assert_eq!(dt - Days(0), dt);
+        assert_eq!(
+            dt - Days(1),
+            DateTime::try_from(datetime!(2018-11-16 10:38:30)).unwrap()
+        );
+    }
+
+    #[test]
+    #[should_panic(expected = "resulting value should be in the range of MS-DOS date and time")]
+    fn sub_days_with_overflow() {
+        let _ = DateTime::MIN - Days(1);
+    }
+
+    #[test]
+    fn add_months() {
+        // <https://github.com/zip-rs/zip/blob/v0.6.4/src/types.rs#L553-L569>.
+        let dt = DateTime::try_from(datetime!(2018-11-17 10:38:30)).unwrap();
+        assert_eq!(dt + Months(0), dt);
+        assert_eq!(
+            dt + Months(1),
+            DateTime::try_from(datetime!(2018-12-17 10:38:30)).unwrap()
+        );
+        assert_eq!(
+            dt + Months(-1),
+            DateTime::try_from(datetime!(2018-10-17 10:38:30)).unwrap()
+        );
+        assert_ne!(DateTime::MIN + Months(1), DateTime::MIN + Days(1));
+    }
+
+    #[test]
+    #[should_panic(expected = "resulting value should be in the range of MS-DOS date and time")]
+    fn add_months_with_overflow() {
+        let _ = DateTime::MAX + Months(1);
+    }
+
+    #[test]
+    fn sub_months() {
+        // <https://github.com/zip-rs/zip/blob/v0.6.4/src/types.rs#L553-L569>.
+        let dt = DateTime::try_from(datetime!(2018-11-17 10:38:30)).unwrap();
+        assert_eq!(dt - Months(0), dt);
+        assert_eq!(
+            dt - Months(1),
+            DateTime::try_from(datetime!(2018-10-17 10:38:30)).unwrap()
+        );
+    }
+
+    #[test]
+    #[should_panic(expected = "resulting value should be in the range of MS-DOS date and time")]
+    fn sub_months_with_overflow() {
+        let _ = DateTime::MIN - Months(1);
+    }
+
+    #[test]
+    fn sub() {
+        assert_eq!(DateTime::MIN - DateTime::MIN, Duration::ZERO);
+        assert_eq!(
+            DateTime::MAX - DateTime::MIN,
+            Duration::seconds(4_039_286_398)
+        );
+        assert_eq!(
+            DateTime::MIN - DateTime::MAX,
+            Duration::seconds(-4_039_286_398)
+        );
+        // <https://devblogs.microsoft.com/oldnewthing/20030905-02/?p=42653>.
+        // <https://github.com/zip-rs/zip/blob/v0.6.4/src/types.rs#L553-L569>.
+        assert_eq!(
+            DateTime::try_from(datetime!(2018-11-17 10:38:30)).unwrap()
+                - DateTime::try_from(datetime!(2002-11-26 19:25:00)).unwrap(),
+            datetime!(2018-11-17 10:38:30) - datetime!(2002-11-26 19:25:00)
+        );
+        assert_eq!(
+            DateTime::try_from(datetime!(1980-01-01 00:00:02)).unwrap() - DateTime::MIN,
+            Duration::seconds(2)
+        );
+    }
+
+    #[test]
+    fn between() {
+        assert_eq!(super::between(DateTime::MIN, DateTime::MIN), Duration::ZERO);
+        assert_eq!(
+            super::between(DateTime::MIN, DateTime::MAX),
+            Duration::seconds(4_039_286_398)
+        );
+        assert_eq!(
+            super::between(DateTime::MAX, DateTime::MIN),
+            Duration::seconds(-4_039_286_398)
+        );
+        // <https://devblogs.microsoft.com/oldnewthing/20030905-02/?p=42653>.
+        // <https://github.com/zip-rs/zip/blob/v0.6.4/src/types.rs#L553-L569>.
+        let (a, b) = (
+            DateTime::try_from(datetime!(2002-11-26 19:25:00)).unwrap(),
+            DateTime::try_from(datetime!(2018-11-17 10:38:30)).unwrap(),
+        );
+        assert!(super::between(a, b).is_positive());
+        assert!(super::between(b, a).is_negative());
+        assert_eq!(super::between(a, b), b - a);
+        assert_eq!(super::between(b, a), -(b - a));
+    }
+}
@@ -0,0 +1,45 @@
+// SPDX-FileCopyrightText: 2025 Shun Sakai
+//
+// SPDX-License-Identifier: Apache-2.0 OR MIT
+
+//! Policies for validating MS-DOS date and time.
+
+/// The policy for validating MS-DOS date and time with
+/// [`DateTime::new_with_policy`](crate::DateTime::new_with_policy).
+#[derive(Clone, Copy, Debug, Default, Eq, Hash, PartialEq)]
+pub enum ValidationPolicy {
+    /// Rejects any MS-DOS date and time which are not valid.
+    ///
+    /// This is the default.
+    #[default]
+    Strict,
+
+    /// Treats the Month field and the Day field which are 0 as 1, and rejects
+    /// any other MS-DOS date and time which are not valid.
+    ///
+    /// This is useful for recovering data written by tools which use 0 to
+    /// mean that the month or the day is unspecified.
+    Lenient,
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn clone() {
+        assert_eq!(ValidationPolicy::Strict.clone(), ValidationPolicy::Strict);
+        assert_eq!(ValidationPolicy::Lenient.clone(), ValidationPolicy::Lenient);
+    }
+
+    #[test]
+    fn debug() {
+        assert_eq!(format!("{:?}", ValidationPolicy::Strict), "Strict");
+        assert_eq!(format!("{:?}", ValidationPolicy::Lenient), "Lenient");
+    }
+
+    #[test]
+    fn default() {
+        assert_eq!(ValidationPolicy::default(), ValidationPolicy::Strict);
+    }
+}
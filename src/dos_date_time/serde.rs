@@ -0,0 +1,221 @@
+// SPDX-FileCopyrightText: 2025 Shun Sakai
+//
+// SPDX-License-Identifier: Apache-2.0 OR MIT
+
+//! Implementations of [`Serialize`] and [`Deserialize`] for [`DateTime`].
+
+use core::fmt;
+
+use serde::{
+    Deserialize, Deserializer, Serialize, Serializer,
+    de::{self, Unexpected, Visitor},
+};
+
+use super::DateTime;
+use crate::{Date, Time};
+
+/// The version of the non-human-readable representation.
+const VERSION: u8 = 1;
+
+/// The non-human-readable representation of [`DateTime`].
+#[derive(Deserialize, Serialize)]
+#[serde(rename = "DateTime")]
+struct Versioned {
+    v: u8,
+    date: u16,
+    time: u16,
+}
+
+impl Serialize for DateTime {
+    /// Serializes this `DateTime`.
+    ///
+    /// If the format is human-readable, this is serialized as the
+    /// [`Display`](fmt::Display) string, such as `"1980-01-01 00:00:00"`.
+    /// Otherwise, this is serialized as a struct with the fields `v`, `date`,
+    /// and `time`, where `v` is the version of the representation, which is
+    /// `1`, and `date` and `time` are the MS-DOS date and MS-DOS time.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use dos_date_time::DateTime;
+    /// #
+    /// assert_eq!(
+    ///     serde_json::to_string(&DateTime::MIN).unwrap(),
+    ///     r#""1980-01-01 00:00:00""#
+    /// );
+    /// ```
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        if serializer.is_human_readable() {
+            serializer.collect_str(self)
+        } else {
+            Versioned {
+                v: VERSION,
+                date: self.date().to_raw(),
+                time: self.time().to_raw(),
+            }
+            .serialize(serializer)
+        }
+    }
+}
+
+impl<'de> Deserialize<'de> for DateTime {
+    /// Deserializes a `DateTime`.
+    ///
+    /// This accepts the representation described in [`DateTime::serialize`].
+    /// In the non-human-readable representation, any version other than `1` is
+    /// rejected.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use dos_date_time::DateTime;
+    /// #
+    /// assert_eq!(
+    ///     serde_json::from_str::<DateTime>(r#""2107-12-31 23:59:58""#).unwrap(),
+    ///     DateTime::MAX
+    /// );
+    /// ```
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        struct DateTimeVisitor;
+
+        impl Visitor<'_> for DateTimeVisitor {
+            type Value = DateTime;
+
+            fn expecting(&self, formatter: &mut fmt::Formatter<'_>) -> fmt::Result {
+                formatter.write_str("a string in the format `YYYY-MM-DD hh:mm:ss`")
+            }
+
+            fn visit_str<E: de::Error>(self, v: &str) -> Result<Self::Value, E> {
+                DateTime::from_ascii(v.as_bytes()).map_err(E::custom)
+            }
+        }
+
+        if deserializer.is_human_readable() {
+            deserializer.deserialize_str(DateTimeVisitor)
+        } else {
+            let Versioned { v, date, time } = Versioned::deserialize(deserializer)?;
+            if v != VERSION {
+                return Err(de::Error::invalid_value(
+                    Unexpected::Unsigned(v.into()),
+                    &"version 1",
+                ));
+            }
+            let date = Date::checked_from_raw(date).map_err(de::Error::custom)?;
+            let time = Time::checked_from_raw(time).map_err(de::Error::custom)?;
+            Ok(Self::new(date, time))
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use serde_test::{Compact, Configure, Readable, Token};
+    use time::macros::datetime;
+
+    use super::*;
+
+    fn versioned_tokens(v: u8, date: u16, time: u16) -> [Token; 8] {
+        [
+            Token::Struct {
+                name: "DateTime",
+                len: 3,
+            },
+            Token::Str("v"),
+            Token::U8(v),
+            Token::Str("date"),
+            Token::U16(date),
+            Token::Str("time"),
+            Token::U16(time),
+            Token::StructEnd,
+        ]
+    }
+
+    #[test]
+    fn serialize_readable() {
+        serde_test::assert_tokens(
+            &DateTime::MIN.readable(),
+            &[Token::Str("1980-01-01 00:00:00")],
+        );
+        // <https://github.com/zip-rs/zip/blob/v0.6.4/src/types.rs#L553-L569>.
+        serde_test::assert_tokens(
+            &DateTime::try_from(datetime!(2018-11-17 10:38:30))
+                .unwrap()
+                .readable(),
+            &[Token::Str("2018-11-17 10:38:30")],
+        );
+        serde_test::assert_tokens(
+            &DateTime::MAX.readable(),
+            &[Token::Str("2107-12-31 23:59:58")],
+        );
+    }
+
+    #[test]
+    fn serialize_compact() {
+        serde_test::assert_tokens(
+            &DateTime::MIN.compact(),
+            &versioned_tokens(1, 0b0000_0000_0010_0001, u16::MIN),
+        );
+        // <https://github.com/zip-rs/zip/blob/v0.6.4/src/types.rs#L553-L569>.
+        serde_test::assert_tokens(
+            &DateTime::try_from(datetime!(2018-11-17 10:38:30))
+                .unwrap()
+                .compact(),
+            &versioned_tokens(1, 0b0100_1101_0111_0001, 0b0101_0100_1100_1111),
+        );
+        serde_test::assert_tokens(
+            &DateTime::MAX.compact(),
+            &versioned_tokens(1, 0b1111_1111_1001_1111, 0b1011_1111_0111_1101),
+        );
+    }
+
+    #[test]
+    fn serialize_json() {
+        assert_eq!(
+            serde_json::to_string(&DateTime::MIN).unwrap(),
+            r#""1980-01-01 00:00:00""#
+        );
+        assert_eq!(
+            serde_json::from_str::<DateTime>(r#""2107-12-31T23:59:58""#).unwrap(),
+            DateTime::MAX
+        );
+    }
+
+    #[test]
+    fn deserialize_readable_with_invalid_string() {
+        serde_test::assert_de_tokens_error::<Readable<DateTime>>(
+            &[Token::Str("1980-01-01")],
+            "input has an invalid length",
+        );
+        serde_test::assert_de_tokens_error::<Readable<DateTime>>(
+            &[Token::Str("1979-12-31 23:59:58")],
+            "MS-DOS date and time are before `1980-01-01 00:00:00`",
+        );
+    }
+
+    #[test]
+    fn deserialize_compact_with_unknown_version() {
+        serde_test::assert_de_tokens_error::<Compact<DateTime>>(
+            &versioned_tokens(0, 0b0000_0000_0010_0001, u16::MIN),
+            "invalid value: integer `0`, expected version 1",
+        );
+        serde_test::assert_de_tokens_error::<Compact<DateTime>>(
+            &versioned_tokens(2, 0b0000_0000_0010_0001, u16::MIN),
+            "invalid value: integer `2`, expected version 1",
+        );
+    }
+
+    #[test]
+    fn deserialize_compact_with_invalid_date_time() {
+        // The Day field is 0.
+        serde_test::assert_de_tokens_error::<Compact<DateTime>>(
+            &versioned_tokens(1, 0b0000_0000_0010_0000, u16::MIN),
+            "the Day field is 0",
+        );
+        // The Hour field is 24.
+        serde_test::assert_de_tokens_error::<Compact<DateTime>>(
+            &versioned_tokens(1, 0b0000_0000_0010_0001, 0b1100_0000_0000_0000),
+            "the Hour field is 24, which is greater than 23",
+        );
+    }
+}
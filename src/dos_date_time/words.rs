@@ -0,0 +1,44 @@
+// SPDX-FileCopyrightText: 2025 Shun Sakai
+//
+// SPDX-License-Identifier: Apache-2.0 OR MIT
+
+//! The raw MS-DOS date and time of [`DateTime`](crate::DateTime).
+
+/// The raw MS-DOS date and time of a [`DateTime`](crate::DateTime).
+///
+/// Unlike a `(u16, u16)` tuple, the two 16-bit words are named, so they cannot
+/// be accidentally swapped.
+///
+/// This is returned by [`DateTime::to_words`](crate::DateTime::to_words), and
+/// is accepted by [`DateTime::from_words`](crate::DateTime::from_words).
+#[derive(Clone, Copy, Debug, Eq, Hash, PartialEq)]
+pub struct DosWords {
+    /// The MS-DOS date.
+    pub date: u16,
+
+    /// The MS-DOS time.
+    pub time: u16,
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn clone() {
+        let words = DosWords {
+            date: 0b0000_0000_0010_0001,
+            time: u16::MIN,
+        };
+        assert_eq!(words.clone(), words);
+    }
+
+    #[test]
+    fn debug() {
+        let words = DosWords {
+            date: 0b0000_0000_0010_0001,
+            time: u16::MIN,
+        };
+        assert_eq!(format!("{words:?}"), "DosWords { date: 33, time: 0 }");
+    }
+}
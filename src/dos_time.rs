@@ -11,6 +11,10 @@ mod consts;
 mod convert;
 mod fmt;
 
+use core::time::Duration;
+
+use crate::error::{InvalidTimeField, TimePrecisionError, TimePrecisionErrorKind};
+
 /// `Time` is a type that represents the [MS-DOS time].
 ///
 /// This is a packed 16-bit unsigned integer value.
@@ -32,7 +36,6 @@ mod fmt;
 pub struct Time(u16);
 
 impl Time {
-    #[allow(clippy::missing_panics_doc)]
     /// Creates a new `Time` with the given MS-DOS time.
     ///
     /// Returns [`None`] if the given MS-DOS time is not a valid MS-DOS time.
@@ -50,20 +53,109 @@ impl Time {
     /// ```
     #[must_use]
     pub fn new(time: u16) -> Option<Self> {
-        let (hour, minute, second) = (
-            (time >> 11)
-                .try_into()
-                .expect("hour should be in the range of `u8`"),
-            ((time >> 5) & 0x3F)
-                .try_into()
-                .expect("minute should be in the range of `u8`"),
-            ((time & 0x1F) * 2)
-                .try_into()
-                .expect("second should be in the range of `u8`"),
+        // SAFETY: `time` is a valid as the MS-DOS time.
+        Self::is_valid_raw(time).then(|| unsafe { Self::new_unchecked(time) })
+    }
+
+    #[allow(clippy::missing_panics_doc)]
+    /// Creates a new `Time` with the given MS-DOS time.
+    ///
+    /// Unlike [`Time::new`], this reports which field is invalid.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`Err`] if the given MS-DOS time is not a valid MS-DOS time.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use dos_date_time::{Time, error::InvalidTimeField};
+    /// #
+    /// assert_eq!(Time::checked_from_raw(u16::MIN), Ok(Time::MIN));
+    ///
+    /// // The Hour field is 24.
+    /// assert_eq!(
+    ///     Time::checked_from_raw(0b1100_0000_0000_0000),
+    ///     Err(InvalidTimeField::HourTooLarge(24))
+    /// );
+    /// // The Minute field is 60.
+    /// assert_eq!(
+    ///     Time::checked_from_raw(0b0000_0111_1000_0000),
+    ///     Err(InvalidTimeField::MinuteTooLarge(60))
+    /// );
+    /// // The DoubleSeconds field is 30.
+    /// assert_eq!(
+    ///     Time::checked_from_raw(0b0000_0000_0001_1110),
+    ///     Err(InvalidTimeField::DoubleSecondsTooLarge(30))
+    /// );
+    /// ```
+    pub fn checked_from_raw(time: u16) -> Result<Self, InvalidTimeField> {
+        let (hour, minute, double_seconds) = (
+            u8::try_from(time >> 11).expect("hour should be in the range of `u8`"),
+            u8::try_from((time >> 5) & 0x3F).expect("minute should be in the range of `u8`"),
+            u8::try_from(time & 0x1F).expect("double seconds should be in the range of `u8`"),
         );
-        let time = time::Time::from_hms(hour, minute, second).ok()?;
-        let time = Self::from_time(time);
-        Some(time)
+        if hour > 23 {
+            return Err(InvalidTimeField::HourTooLarge(hour));
+        }
+        if minute > 59 {
+            return Err(InvalidTimeField::MinuteTooLarge(minute));
+        }
+        if double_seconds > 29 {
+            return Err(InvalidTimeField::DoubleSecondsTooLarge(double_seconds));
+        }
+        // SAFETY: `time` is a valid as the MS-DOS time.
+        let time = unsafe { Self::new_unchecked(time) };
+        Ok(time)
+    }
+
+    /// Creates a new `Time` with the given MS-DOS time.
+    ///
+    /// This is an alias for [`Time::checked_from_raw`].
+    ///
+    /// # Errors
+    ///
+    /// Returns [`Err`] if the given MS-DOS time is not a valid MS-DOS time.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use dos_date_time::{Time, error::InvalidTimeField};
+    /// #
+    /// assert_eq!(Time::checked_new(u16::MIN), Ok(Time::MIN));
+    ///
+    /// // The Hour field is 24.
+    /// assert_eq!(
+    ///     Time::checked_new(0b1100_0000_0000_0000),
+    ///     Err(InvalidTimeField::HourTooLarge(24))
+    /// );
+    /// ```
+    pub fn checked_new(time: u16) -> Result<Self, InvalidTimeField> {
+        Self::checked_from_raw(time)
+    }
+
+    /// Creates a new `Time` with the given MS-DOS time as an [`i64`] value.
+    ///
+    /// This is intended for interoperability with databases which store the
+    /// MS-DOS time as a signed integer. Returns [`None`] if `time` is out of
+    /// range for [`u16`], or if it is not a valid MS-DOS time.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use dos_date_time::Time;
+    /// #
+    /// assert_eq!(Time::from_i64(0), Some(Time::MIN));
+    /// assert_eq!(Time::from_i64(49_021), Some(Time::MAX));
+    ///
+    /// assert_eq!(Time::from_i64(-1), None);
+    /// assert_eq!(Time::from_i64(65536), None);
+    /// // The DoubleSeconds field is 30.
+    /// assert_eq!(Time::from_i64(0b0000_0000_0001_1110), None);
+    /// ```
+    #[must_use]
+    pub fn from_i64(time: i64) -> Option<Self> {
+        u16::try_from(time).ok().and_then(Self::new)
     }
 
     /// Creates a new `Time` with the given MS-DOS time.
@@ -76,6 +168,51 @@ impl Time {
         Self(time)
     }
 
+    /// Adds `delta` to the MS-DOS time of this `Time`, wrapping around at the
+    /// boundary of [`u16`].
+    ///
+    /// This operates on the raw MS-DOS time, not on the clock, so it is
+    /// intended for low-level manipulation such as fuzzing. The result may
+    /// not be a valid MS-DOS time.
+    ///
+    /// # Safety
+    ///
+    /// The returned `Time` may not be a valid MS-DOS time. Before it is used
+    /// for anything other than [`Time::is_valid`] and [`Time::to_raw`], it must
+    /// be checked with [`Time::is_valid`].
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use dos_date_time::{Time, time::macros::time};
+    /// #
+    /// let time = unsafe { Time::MIN.wrapping_add_raw(1) };
+    /// assert!(time.is_valid());
+    /// assert_eq!(time, Time::from_time(time!(00:00:02)));
+    ///
+    /// let time = unsafe { Time::MAX.wrapping_add_raw(u16::MAX) };
+    /// assert_eq!(time.to_raw(), Time::MAX.to_raw() - 1);
+    /// ```
+    #[must_use]
+    pub const unsafe fn wrapping_add_raw(self, delta: u16) -> Self {
+        Self(self.to_raw().wrapping_add(delta))
+    }
+
+    #[doc(hidden)]
+    #[must_use]
+    pub const fn __from_hms(hour: u16, minute: u16, second: u16) -> Self {
+        assert!(hour < 24, "hour should be in `0..24`");
+        assert!(minute < 60, "minute should be in `0..60`");
+        assert!(second < 60, "second should be in `0..60`");
+        assert!(
+            second.is_multiple_of(2),
+            "second should be even, since the resolution of MS-DOS time is 2 seconds"
+        );
+        let time = (hour << 11) | (minute << 5) | (second / 2);
+        // SAFETY: `time` is a valid as the MS-DOS time.
+        unsafe { Self::new_unchecked(time) }
+    }
+
     /// Creates a new `Time` with the given [`time::Time`].
     ///
     /// <div class="warning">
@@ -110,11 +247,212 @@ impl Time {
         unsafe { Self::new_unchecked(time) }
     }
 
+    /// Creates a new `Time` with the given [`time::Time`], which must be
+    /// exactly representable as the MS-DOS time.
+    ///
+    /// Unlike [`Time::from_time`], this does not round.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`Err`] if `time` has an odd second or a fractional second.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use dos_date_time::{
+    /// #     Time,
+    /// #     time::{self, macros::time},
+    /// # };
+    /// #
+    /// assert_eq!(Time::from_time_exact(time::Time::MIDNIGHT), Ok(Time::MIN));
+    /// assert_eq!(Time::from_time_exact(time!(23:59:58)), Ok(Time::MAX));
+    ///
+    /// // The second is odd.
+    /// assert!(Time::from_time_exact(time!(23:59:59)).is_err());
+    /// // The time has a fractional second.
+    /// assert!(Time::from_time_exact(time!(23:59:58.5)).is_err());
+    /// ```
+    pub fn from_time_exact(time: time::Time) -> Result<Self, TimePrecisionError> {
+        if time.nanosecond() != 0 {
+            return Err(TimePrecisionErrorKind::Subsecond.into());
+        }
+        if !time.second().is_multiple_of(2) {
+            return Err(TimePrecisionErrorKind::OddSecond.into());
+        }
+        Ok(Self::from_time(time))
+    }
+
+    #[allow(clippy::missing_panics_doc)]
+    /// Creates a new `Time` with the given number of seconds since midnight.
+    ///
+    /// This is the inverse of [`Time::second_of_day`].
+    ///
+    /// Returns [`None`] if `secs` is not in the range `0..=86399`.
+    ///
+    /// <div class="warning">
+    ///
+    /// The resolution of MS-DOS time is 2 seconds. So this method rounds
+    /// towards zero, truncating any fractional part of the exact result of
+    /// dividing seconds by 2.
+    ///
+    /// </div>
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use dos_date_time::Time;
+    /// #
+    /// assert_eq!(Time::from_second_of_day(0), Some(Time::MIN));
+    /// assert_eq!(Time::from_second_of_day(86399), Some(Time::MAX));
+    ///
+    /// assert_eq!(Time::from_second_of_day(86400), None);
+    /// ```
+    #[must_use]
+    pub fn from_second_of_day(secs: u32) -> Option<Self> {
+        (secs < 86400).then(|| {
+            let (hour, minute, second) = (secs / 3600, (secs / 60) % 60, secs % 60);
+            let time = u16::try_from((hour << 11) | (minute << 5) | (second / 2))
+                .expect("time should be in the range of `u16`");
+            // SAFETY: `time` is a valid as the MS-DOS time.
+            unsafe { Self::new_unchecked(time) }
+        })
+    }
+
+    /// Creates a new `Time` with the given hour, minute, second, and
+    /// nanosecond.
+    ///
+    /// Returns [`None`] if the given values do not form a valid time, in the
+    /// same way as [`time::Time::from_hms_nano`].
+    ///
+    /// <div class="warning">
+    ///
+    /// The resolution of MS-DOS time is 2 seconds. So this method discards
+    /// `nanosecond` and rounds `second` down to an even number.
+    ///
+    /// </div>
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use dos_date_time::Time;
+    /// #
+    /// assert_eq!(Time::from_hms_nano(0, 0, 0, 0), Some(Time::MIN));
+    /// assert_eq!(
+    ///     Time::from_hms_nano(23, 59, 59, 999_999_999),
+    ///     Some(Time::MAX)
+    /// );
+    ///
+    /// assert_eq!(Time::from_hms_nano(24, 0, 0, 0), None);
+    /// assert_eq!(Time::from_hms_nano(0, 0, 0, 1_000_000_000), None);
+    /// ```
+    #[must_use]
+    pub fn from_hms_nano(hour: u8, minute: u8, second: u8, nanosecond: u32) -> Option<Self> {
+        time::Time::from_hms_nano(hour, minute, second, nanosecond)
+            .ok()
+            .map(Self::from_time)
+    }
+
+    /// Returns an iterator over all valid MS-DOS times in ascending order.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use dos_date_time::Time;
+    /// #
+    /// let mut times = Time::all_valid();
+    /// assert_eq!(times.next(), Some(Time::MIN));
+    /// assert_eq!(times.last(), Some(Time::MAX));
+    ///
+    /// assert_eq!(Time::all_valid().count(), 43200);
+    /// ```
+    pub fn all_valid() -> impl Iterator<Item = Self> {
+        (u16::MIN..=u16::MAX).filter_map(Self::new)
+    }
+
     /// Returns [`true`] if `self` is a valid MS-DOS time, and [`false`]
     /// otherwise.
     #[must_use]
-    pub fn is_valid(self) -> bool {
-        Self::new(self.to_raw()).is_some()
+    pub const fn is_valid(self) -> bool {
+        Self::is_valid_raw(self.to_raw())
+    }
+
+    /// Returns [`true`] if `time` is a valid MS-DOS time, and [`false`]
+    /// otherwise.
+    ///
+    /// This checks the fields directly instead of constructing [`time::Time`].
+    const fn is_valid_raw(time: u16) -> bool {
+        let (hour, minute, double_seconds) = (time >> 11, (time >> 5) & 0x3F, time & 0x1F);
+        hour < 24 && minute < 60 && double_seconds < 30
+    }
+
+    /// Restricts this `Time` to the range `min..=max`.
+    ///
+    /// This is the same as [`Ord::clamp`]. `Time` has a total order, which is
+    /// the chronological order, so any two values can be compared.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `min > max`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use dos_date_time::{Time, time::macros::time};
+    /// #
+    /// let (min, max) = (
+    ///     Time::from_time(time!(08:00:00)),
+    ///     Time::from_time(time!(18:00:00)),
+    /// );
+    /// assert_eq!(Time::MIN.clamp(min, max), min);
+    /// assert_eq!(
+    ///     Time::from_time(time!(10:38:30)).clamp(min, max),
+    ///     Time::from_time(time!(10:38:30))
+    /// );
+    /// assert_eq!(Time::MAX.clamp(min, max), max);
+    /// ```
+    #[must_use]
+    pub fn clamp(self, min: Self, max: Self) -> Self {
+        assert!(min <= max, "`min` should be less than or equal to `max`");
+        Ord::clamp(self, min, max)
+    }
+
+    /// Returns [`true`] if this `Time` is in the half-open window
+    /// `start..end`, and [`false`] otherwise.
+    ///
+    /// If `start <= end`, this is a window within a day, such as business
+    /// hours. If `start > end`, this is a window which wraps around midnight,
+    /// such as an overnight window, and contains the times on or after `start`
+    /// and the times before `end`. If `start == end`, the window is empty.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use dos_date_time::{Time, time::macros::time};
+    /// #
+    /// let (start, end) = (
+    ///     Time::from_time(time!(09:00:00)),
+    ///     Time::from_time(time!(17:00:00)),
+    /// );
+    /// assert!(Time::from_time(time!(10:38:30)).between(start, end));
+    /// assert!(!Time::from_time(time!(17:00:00)).between(start, end));
+    ///
+    /// // The overnight window from 22:00 to 06:00.
+    /// let (start, end) = (
+    ///     Time::from_time(time!(22:00:00)),
+    ///     Time::from_time(time!(06:00:00)),
+    /// );
+    /// assert!(Time::from_time(time!(23:00:00)).between(start, end));
+    /// assert!(Time::from_time(time!(05:00:00)).between(start, end));
+    /// assert!(!Time::from_time(time!(10:38:30)).between(start, end));
+    /// ```
+    #[must_use]
+    pub const fn between(self, start: Self, end: Self) -> bool {
+        let (time, start, end) = (self.to_raw(), start.to_raw(), end.to_raw());
+        if start <= end {
+            start <= time && time < end
+        } else {
+            start <= time || time < end
+        }
     }
 
     /// Returns the MS-DOS time of this `Time` as the underlying [`u16`] value.
@@ -127,11 +465,30 @@ impl Time {
     /// assert_eq!(Time::MIN.to_raw(), u16::MIN);
     /// assert_eq!(Time::MAX.to_raw(), 0b1011_1111_0111_1101);
     /// ```
+    #[inline]
     #[must_use]
     pub const fn to_raw(self) -> u16 {
         self.0
     }
 
+    /// Returns the MS-DOS time of this `Time` as an [`i64`] value.
+    ///
+    /// This is the underlying [`u16`] value widened to [`i64`], and is the
+    /// inverse of [`Time::from_i64`].
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use dos_date_time::Time;
+    /// #
+    /// assert_eq!(Time::MIN.to_i64(), 0);
+    /// assert_eq!(Time::MAX.to_i64(), 49_021);
+    /// ```
+    #[must_use]
+    pub const fn to_i64(self) -> i64 {
+        self.to_raw() as i64
+    }
+
     #[allow(clippy::missing_panics_doc)]
     /// Gets the hour of this `Time`.
     ///
@@ -143,6 +500,7 @@ impl Time {
     /// assert_eq!(Time::MIN.hour(), 0);
     /// assert_eq!(Time::MAX.hour(), 23);
     /// ```
+    #[inline]
     #[must_use]
     pub fn hour(self) -> u8 {
         (self.to_raw() >> 11)
@@ -161,6 +519,7 @@ impl Time {
     /// assert_eq!(Time::MIN.minute(), 0);
     /// assert_eq!(Time::MAX.minute(), 59);
     /// ```
+    #[inline]
     #[must_use]
     pub fn minute(self) -> u8 {
         ((self.to_raw() >> 5) & 0x3F)
@@ -171,20 +530,148 @@ impl Time {
     #[allow(clippy::missing_panics_doc)]
     /// Gets the second of this `Time`.
     ///
+    /// The MS-DOS time stores the second divided by 2, so the returned value
+    /// is always even. A `Time` cannot hold an odd second, so there is nothing
+    /// to round to an even second. Any odd second is truncated when the `Time`
+    /// is constructed, such as by [`Time::from_time`].
+    ///
     /// # Examples
     ///
     /// ```
-    /// # use dos_date_time::Time;
+    /// # use dos_date_time::{Time, time::macros::time};
     /// #
     /// assert_eq!(Time::MIN.second(), 0);
+    /// assert_eq!(Time::from_time(time!(10:38:31)).second(), 30);
     /// assert_eq!(Time::MAX.second(), 58);
     /// ```
+    #[inline]
     #[must_use]
     pub fn second(self) -> u8 {
         ((self.to_raw() & 0x1F) * 2)
             .try_into()
             .expect("second should be in the range of `u8`")
     }
+
+    /// Returns the number of seconds since midnight of this `Time`.
+    ///
+    /// The returned value is in the range `0..=86398`, and is always even.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use dos_date_time::Time;
+    /// #
+    /// assert_eq!(Time::MIN.second_of_day(), 0);
+    /// assert_eq!(Time::MAX.second_of_day(), 86398);
+    /// ```
+    #[must_use]
+    pub fn second_of_day(self) -> u32 {
+        let (hour, minute, second) = (
+            u32::from(self.hour()),
+            u32::from(self.minute()),
+            u32::from(self.second()),
+        );
+        (hour * 3600) + (minute * 60) + second
+    }
+
+    /// Returns the duration from this `Time` until `other` on the same day, or
+    /// [`None`] if `other` is before this `Time`.
+    ///
+    /// See [`Time::wrapping_duration_until`] for an interval which may wrap
+    /// around midnight.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use core::time::Duration;
+    /// #
+    /// # use dos_date_time::{Time, time::macros::time};
+    /// #
+    /// let (start, end) = (
+    ///     Time::from_time(time!(09:00:00)),
+    ///     Time::from_time(time!(17:30:00)),
+    /// );
+    /// assert_eq!(start.duration_until(end), Some(Duration::from_secs(30600)));
+    ///
+    /// assert_eq!(end.duration_until(start), None);
+    /// ```
+    #[must_use]
+    pub fn duration_until(self, other: Self) -> Option<Duration> {
+        let secs = other.second_of_day().checked_sub(self.second_of_day())?;
+        Some(Duration::from_secs(secs.into()))
+    }
+
+    /// Returns the duration from this `Time` until the next occurrence of
+    /// `other`, wrapping around midnight if `other` is before this `Time`.
+    ///
+    /// This is useful for an overnight interval, such as from 22:00 to 06:00.
+    /// If `other` is equal to this `Time`, this returns [`Duration::ZERO`].
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use core::time::Duration;
+    /// #
+    /// # use dos_date_time::{Time, time::macros::time};
+    /// #
+    /// let (start, end) = (
+    ///     Time::from_time(time!(22:00:00)),
+    ///     Time::from_time(time!(06:00:00)),
+    /// );
+    /// assert_eq!(
+    ///     start.wrapping_duration_until(end),
+    ///     Duration::from_secs(28800)
+    /// );
+    /// assert_eq!(
+    ///     end.wrapping_duration_until(start),
+    ///     Duration::from_secs(57600)
+    /// );
+    /// ```
+    #[must_use]
+    pub fn wrapping_duration_until(self, other: Self) -> Duration {
+        let secs = (other.second_of_day() + 86400 - self.second_of_day()) % 86400;
+        Duration::from_secs(secs.into())
+    }
+
+    /// Returns this `Time` rounded to the nearest whole minute.
+    ///
+    /// 30 seconds or more rounds up. However, `23:59:30` or later rounds down
+    /// to `23:59:00`, since the next minute would be on the next day.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use dos_date_time::{Time, time::macros::time};
+    /// #
+    /// assert_eq!(
+    ///     Time::from_time(time!(10:38:28)).round_to_minute(),
+    ///     Time::from_time(time!(10:38:00))
+    /// );
+    /// assert_eq!(
+    ///     Time::from_time(time!(10:38:30)).round_to_minute(),
+    ///     Time::from_time(time!(10:39:00))
+    /// );
+    /// assert_eq!(
+    ///     Time::MAX.round_to_minute(),
+    ///     Time::from_time(time!(23:59:00))
+    /// );
+    /// ```
+    #[must_use]
+    pub const fn round_to_minute(self) -> Self {
+        const LAST_MINUTE: u16 = (23 << 11) | (59 << 5);
+
+        let time = self.to_raw();
+        let (truncated, double_seconds) = (time & !0x1F, time & 0x1F);
+        let time = if double_seconds < 15 || truncated == LAST_MINUTE {
+            truncated
+        } else if (truncated >> 5) & 0x3F == 59 {
+            ((truncated >> 11) + 1) << 11
+        } else {
+            truncated + (1 << 5)
+        };
+        // SAFETY: `time` is a valid as the MS-DOS time.
+        unsafe { Self::new_unchecked(time) }
+    }
 }
 
 impl Default for Time {
@@ -274,39 +761,347 @@ mod tests {
     }
 
     #[test]
-    fn new_unchecked() {
-        assert_eq!(unsafe { Time::new_unchecked(u16::MIN) }, Time::MIN);
-        assert_eq!(
-            unsafe { Time::new_unchecked(0b1011_1111_0111_1101) },
-            Time::MAX
-        );
+    fn new_agrees_with_time_based_validation() {
+        fn is_valid_with_time(time: u16) -> bool {
+            let (hour, minute, second) = (
+                u8::try_from(time >> 11).unwrap(),
+                u8::try_from((time >> 5) & 0x3F).unwrap(),
+                u8::try_from((time & 0x1F) * 2).unwrap(),
+            );
+            time::Time::from_hms(hour, minute, second).is_ok()
+        }
+
+        for time in u16::MIN..=u16::MAX {
+            assert_eq!(Time::new(time).is_some(), is_valid_with_time(time));
+        }
     }
 
     #[test]
-    const fn new_unchecked_is_const_fn() {
-        const _: Time = unsafe { Time::new_unchecked(u16::MIN) };
+    fn checked_from_raw() {
+        assert_eq!(Time::checked_from_raw(u16::MIN).unwrap(), Time::MIN);
+        // <https://github.com/zip-rs/zip/blob/v0.6.4/src/types.rs#L553-L569>.
+        assert_eq!(
+            Time::checked_from_raw(0b0101_0100_1100_1111).unwrap(),
+            Time::new(0b0101_0100_1100_1111).unwrap()
+        );
+        assert_eq!(
+            Time::checked_from_raw(0b1011_1111_0111_1101).unwrap(),
+            Time::MAX
+        );
     }
 
     #[test]
-    fn from_time() {
-        assert_eq!(Time::from_time(time::Time::MIDNIGHT), Time::MIN);
-        assert_eq!(Time::from_time(time!(00:00:01)), Time::MIN);
-        // <https://devblogs.microsoft.com/oldnewthing/20030905-02/?p=42653>.
+    fn checked_from_raw_with_invalid_time() {
+        // The Hour field is 24.
         assert_eq!(
-            Time::from_time(time!(19:25:00)),
-            Time::new(0b1001_1011_0010_0000).unwrap()
+            Time::checked_from_raw(0b1100_0000_0000_0000).unwrap_err(),
+            InvalidTimeField::HourTooLarge(24)
         );
-        // <https://github.com/zip-rs/zip/blob/v0.6.4/src/types.rs#L553-L569>.
+        // The Hour field is 31.
         assert_eq!(
-            Time::from_time(time!(10:38:30)),
-            Time::new(0b0101_0100_1100_1111).unwrap()
+            Time::checked_from_raw(u16::MAX).unwrap_err(),
+            InvalidTimeField::HourTooLarge(31)
+        );
+        // The Minute field is 60.
+        assert_eq!(
+            Time::checked_from_raw(0b0000_0111_1000_0000).unwrap_err(),
+            InvalidTimeField::MinuteTooLarge(60)
+        );
+        // The DoubleSeconds field is 30.
+        assert_eq!(
+            Time::checked_from_raw(0b0000_0000_0001_1110).unwrap_err(),
+            InvalidTimeField::DoubleSecondsTooLarge(30)
         );
-        assert_eq!(Time::from_time(time!(23:59:58)), Time::MAX);
-        assert_eq!(Time::from_time(time!(23:59:59)), Time::MAX);
     }
 
     #[test]
-    fn is_valid() {
+    fn checked_from_raw_agrees_with_is_valid_raw() {
+        for time in u16::MIN..=u16::MAX {
+            assert_eq!(
+                Time::checked_from_raw(time).is_ok(),
+                Time::is_valid_raw(time)
+            );
+        }
+    }
+
+    #[test]
+    fn checked_new() {
+        assert_eq!(Time::checked_new(u16::MIN).unwrap(), Time::MIN);
+        // <https://github.com/zip-rs/zip/blob/v0.6.4/src/types.rs#L553-L569>.
+        assert_eq!(
+            Time::checked_new(0b0101_0100_1100_1111).unwrap(),
+            Time::new(0b0101_0100_1100_1111).unwrap()
+        );
+        assert_eq!(Time::checked_new(0b1011_1111_0111_1101).unwrap(), Time::MAX);
+    }
+
+    #[test]
+    fn checked_new_with_hour_too_large() {
+        assert_eq!(
+            Time::checked_new(0b1100_0000_0000_0000).unwrap_err(),
+            InvalidTimeField::HourTooLarge(24)
+        );
+    }
+
+    #[test]
+    fn checked_new_with_minute_too_large() {
+        assert_eq!(
+            Time::checked_new(0b0000_0111_1000_0000).unwrap_err(),
+            InvalidTimeField::MinuteTooLarge(60)
+        );
+    }
+
+    #[test]
+    fn checked_new_with_double_seconds_too_large() {
+        assert_eq!(
+            Time::checked_new(0b0000_0000_0001_1110).unwrap_err(),
+            InvalidTimeField::DoubleSecondsTooLarge(30)
+        );
+    }
+
+    #[test]
+    fn from_i64() {
+        assert_eq!(Time::from_i64(0).unwrap(), Time::MIN);
+        // <https://github.com/zip-rs/zip/blob/v0.6.4/src/types.rs#L553-L569>.
+        assert_eq!(
+            Time::from_i64(0b0101_0100_1100_1111).unwrap(),
+            Time::new(0b0101_0100_1100_1111).unwrap()
+        );
+        assert_eq!(Time::from_i64(0b1011_1111_0111_1101).unwrap(), Time::MAX);
+    }
+
+    #[test]
+    fn from_i64_with_out_of_range() {
+        assert!(Time::from_i64(-1).is_none());
+        assert!(Time::from_i64(i64::from(u16::MAX) + 1).is_none());
+        assert!(Time::from_i64(i64::MIN).is_none());
+        assert!(Time::from_i64(i64::MAX).is_none());
+    }
+
+    #[test]
+    fn from_i64_with_invalid_time() {
+        // The DoubleSeconds field is 30.
+        assert!(Time::from_i64(0b0000_0000_0001_1110).is_none());
+    }
+
+    #[test]
+    fn from_i64_agrees_with_new() {
+        for raw in u16::MIN..=u16::MAX {
+            assert_eq!(Time::from_i64(raw.into()), Time::new(raw));
+        }
+    }
+
+    #[test]
+    fn new_unchecked() {
+        assert_eq!(unsafe { Time::new_unchecked(u16::MIN) }, Time::MIN);
+        assert_eq!(
+            unsafe { Time::new_unchecked(0b1011_1111_0111_1101) },
+            Time::MAX
+        );
+    }
+
+    #[test]
+    const fn new_unchecked_is_const_fn() {
+        const _: Time = unsafe { Time::new_unchecked(u16::MIN) };
+    }
+
+    #[test]
+    fn wrapping_add_raw() {
+        assert_eq!(unsafe { Time::MIN.wrapping_add_raw(0) }, Time::MIN);
+        assert_eq!(
+            unsafe { Time::MIN.wrapping_add_raw(1) }.to_raw(),
+            0b0000_0000_0000_0001
+        );
+        assert_eq!(
+            unsafe { Time::MAX.wrapping_add_raw(u16::MAX) }.to_raw(),
+            0b1011_1111_0111_1101 - 1
+        );
+        assert_eq!(
+            unsafe { Time::MAX.wrapping_add_raw(0x4083) }.to_raw(),
+            u16::MIN
+        );
+    }
+
+    #[test]
+    fn wrapping_add_raw_with_invalid_time() {
+        // The DoubleSeconds field is 30.
+        let time = unsafe { Time::MIN.wrapping_add_raw(0b0000_0000_0001_1110) };
+        assert!(!time.is_valid());
+        assert_eq!(time.to_raw(), 0b0000_0000_0001_1110);
+        // The Hour field is 24.
+        assert!(!unsafe { Time::MAX.wrapping_add_raw(0x0083) }.is_valid());
+    }
+
+    #[test]
+    const fn wrapping_add_raw_is_const_fn() {
+        const _: Time = unsafe { Time::MIN.wrapping_add_raw(1) };
+    }
+
+    #[test]
+    fn from_time() {
+        assert_eq!(Time::from_time(time::Time::MIDNIGHT), Time::MIN);
+        assert_eq!(Time::from_time(time!(00:00:01)), Time::MIN);
+        // <https://devblogs.microsoft.com/oldnewthing/20030905-02/?p=42653>.
+        assert_eq!(
+            Time::from_time(time!(19:25:00)),
+            Time::new(0b1001_1011_0010_0000).unwrap()
+        );
+        // <https://github.com/zip-rs/zip/blob/v0.6.4/src/types.rs#L553-L569>.
+        assert_eq!(
+            Time::from_time(time!(10:38:30)),
+            Time::new(0b0101_0100_1100_1111).unwrap()
+        );
+        assert_eq!(Time::from_time(time!(23:59:58)), Time::MAX);
+        assert_eq!(Time::from_time(time!(23:59:59)), Time::MAX);
+    }
+
+    #[test]
+    fn from_time_exact() {
+        assert_eq!(
+            Time::from_time_exact(time::Time::MIDNIGHT).unwrap(),
+            Time::MIN
+        );
+        // <https://devblogs.microsoft.com/oldnewthing/20030905-02/?p=42653>.
+        assert_eq!(
+            Time::from_time_exact(time!(19:25:00)).unwrap(),
+            Time::new(0b1001_1011_0010_0000).unwrap()
+        );
+        // <https://github.com/zip-rs/zip/blob/v0.6.4/src/types.rs#L553-L569>.
+        assert_eq!(
+            Time::from_time_exact(time!(10:38:30)).unwrap(),
+            Time::new(0b0101_0100_1100_1111).unwrap()
+        );
+        assert_eq!(Time::from_time_exact(time!(23:59:58)).unwrap(), Time::MAX);
+    }
+
+    #[test]
+    fn from_time_exact_with_odd_second() {
+        assert_eq!(
+            Time::from_time_exact(time!(00:00:01)).unwrap_err(),
+            TimePrecisionErrorKind::OddSecond.into()
+        );
+        assert_eq!(
+            Time::from_time_exact(time!(23:59:59)).unwrap_err(),
+            TimePrecisionErrorKind::OddSecond.into()
+        );
+    }
+
+    #[test]
+    fn from_time_exact_with_subsecond() {
+        assert_eq!(
+            Time::from_time_exact(time!(00:00:00.000_000_001)).unwrap_err(),
+            TimePrecisionErrorKind::Subsecond.into()
+        );
+        assert_eq!(
+            Time::from_time_exact(time!(23:59:59.999_999_999)).unwrap_err(),
+            TimePrecisionErrorKind::Subsecond.into()
+        );
+    }
+
+    #[test]
+    fn from_second_of_day() {
+        assert_eq!(Time::from_second_of_day(0).unwrap(), Time::MIN);
+        assert_eq!(Time::from_second_of_day(1).unwrap(), Time::MIN);
+        // <https://devblogs.microsoft.com/oldnewthing/20030905-02/?p=42653>.
+        assert_eq!(
+            Time::from_second_of_day(69900).unwrap(),
+            Time::new(0b1001_1011_0010_0000).unwrap()
+        );
+        // <https://github.com/zip-rs/zip/blob/v0.6.4/src/types.rs#L553-L569>.
+        assert_eq!(
+            Time::from_second_of_day(38310).unwrap(),
+            Time::new(0b0101_0100_1100_1111).unwrap()
+        );
+        assert_eq!(Time::from_second_of_day(86398).unwrap(), Time::MAX);
+        assert_eq!(Time::from_second_of_day(86399).unwrap(), Time::MAX);
+    }
+
+    #[test]
+    fn from_second_of_day_with_out_of_range() {
+        assert!(Time::from_second_of_day(86400).is_none());
+        assert!(Time::from_second_of_day(u32::MAX).is_none());
+    }
+
+    #[test]
+    fn from_second_of_day_roundtrip() {
+        for time in Time::all_valid() {
+            assert_eq!(
+                Time::from_second_of_day(time.second_of_day()).unwrap(),
+                time
+            );
+            assert_eq!(
+                Time::from_second_of_day(time.second_of_day() + 1).unwrap(),
+                time
+            );
+        }
+    }
+
+    #[test]
+    fn from_hms_nano() {
+        assert_eq!(Time::from_hms_nano(0, 0, 0, 0).unwrap(), Time::MIN);
+        // <https://devblogs.microsoft.com/oldnewthing/20030905-02/?p=42653>.
+        assert_eq!(
+            Time::from_hms_nano(19, 25, 0, 0).unwrap(),
+            Time::new(0b1001_1011_0010_0000).unwrap()
+        );
+        // <https://github.com/zip-rs/zip/blob/v0.6.4/src/types.rs#L553-L569>.
+        assert_eq!(
+            Time::from_hms_nano(10, 38, 30, 0).unwrap(),
+            Time::new(0b0101_0100_1100_1111).unwrap()
+        );
+        assert_eq!(Time::from_hms_nano(23, 59, 58, 0).unwrap(), Time::MAX);
+    }
+
+    #[test]
+    fn from_hms_nano_drops_nanosecond_and_odd_second() {
+        assert_eq!(
+            Time::from_hms_nano(0, 0, 0, 999_999_999).unwrap(),
+            Time::MIN
+        );
+        assert_eq!(Time::from_hms_nano(0, 0, 1, 0).unwrap(), Time::MIN);
+        assert_eq!(
+            Time::from_hms_nano(0, 0, 1, 999_999_999).unwrap(),
+            Time::MIN
+        );
+        // <https://github.com/zip-rs/zip/blob/v0.6.4/src/types.rs#L553-L569>.
+        assert_eq!(
+            Time::from_hms_nano(10, 38, 31, 500_000_000).unwrap(),
+            Time::new(0b0101_0100_1100_1111).unwrap()
+        );
+        assert_eq!(
+            Time::from_hms_nano(23, 59, 59, 999_999_999).unwrap(),
+            Time::MAX
+        );
+    }
+
+    #[test]
+    fn from_hms_nano_with_invalid_time() {
+        assert!(Time::from_hms_nano(24, 0, 0, 0).is_none());
+        assert!(Time::from_hms_nano(0, 60, 0, 0).is_none());
+        assert!(Time::from_hms_nano(0, 0, 60, 0).is_none());
+        assert!(Time::from_hms_nano(0, 0, 0, 1_000_000_000).is_none());
+        assert!(Time::from_hms_nano(u8::MAX, u8::MAX, u8::MAX, u32::MAX).is_none());
+    }
+
+    #[test]
+    fn all_valid() {
+        assert_eq!(Time::all_valid().count(), 43200);
+        assert_eq!(Time::all_valid().next().unwrap(), Time::MIN);
+        assert_eq!(Time::all_valid().last().unwrap(), Time::MAX);
+        assert!(Time::all_valid().all(Time::is_valid));
+    }
+
+    #[test]
+    fn all_valid_is_strictly_increasing() {
+        assert!(
+            Time::all_valid()
+                .zip(Time::all_valid().skip(1))
+                .all(|(a, b)| a < b)
+        );
+    }
+
+    #[test]
+    fn is_valid() {
         assert!(Time::MIN.is_valid());
         // <https://devblogs.microsoft.com/oldnewthing/20030905-02/?p=42653>.
         assert!(Time::new(0b1001_1011_0010_0000).unwrap().is_valid());
@@ -315,6 +1110,11 @@ mod tests {
         assert!(Time::MAX.is_valid());
     }
 
+    #[test]
+    const fn is_valid_is_const_fn() {
+        const _: bool = Time::MIN.is_valid();
+    }
+
     #[test]
     fn is_valid_with_invalid_time() {
         // The DoubleSeconds field is 30.
@@ -325,6 +1125,88 @@ mod tests {
         assert!(!unsafe { Time::new_unchecked(0b1100_0000_0000_0000) }.is_valid());
     }
 
+    #[test]
+    fn clamp() {
+        let (min, max) = (
+            Time::from_time(time!(08:00:00)),
+            Time::from_time(time!(18:00:00)),
+        );
+        assert_eq!(Time::MIN.clamp(min, max), min);
+        assert_eq!(min.clamp(min, max), min);
+        assert_eq!(
+            Time::from_time(time!(10:38:30)).clamp(min, max),
+            Time::from_time(time!(10:38:30))
+        );
+        assert_eq!(max.clamp(min, max), max);
+        assert_eq!(Time::MAX.clamp(min, max), max);
+        assert_eq!(Time::MIN.clamp(Time::MIN, Time::MAX), Time::MIN);
+        assert_eq!(Time::MAX.clamp(Time::MIN, Time::MAX), Time::MAX);
+    }
+
+    #[test]
+    #[should_panic(expected = "`min` should be less than or equal to `max`")]
+    fn clamp_with_min_greater_than_max() {
+        let _ = Time::MIN.clamp(Time::MAX, Time::MIN);
+    }
+
+    #[test]
+    fn between() {
+        let (start, end) = (
+            Time::from_time(time!(09:00:00)),
+            Time::from_time(time!(17:00:00)),
+        );
+        assert!(!Time::MIN.between(start, end));
+        assert!(!Time::from_time(time!(08:59:58)).between(start, end));
+        assert!(Time::from_time(time!(09:00:00)).between(start, end));
+        // <https://github.com/zip-rs/zip/blob/v0.6.4/src/types.rs#L553-L569>.
+        assert!(Time::from_time(time!(10:38:30)).between(start, end));
+        assert!(Time::from_time(time!(16:59:58)).between(start, end));
+        assert!(!Time::from_time(time!(17:00:00)).between(start, end));
+        // <https://devblogs.microsoft.com/oldnewthing/20030905-02/?p=42653>.
+        assert!(!Time::from_time(time!(19:25:00)).between(start, end));
+        assert!(!Time::MAX.between(start, end));
+    }
+
+    #[test]
+    fn between_with_overnight_window() {
+        let (start, end) = (
+            Time::from_time(time!(22:00:00)),
+            Time::from_time(time!(06:00:00)),
+        );
+        assert!(Time::MIN.between(start, end));
+        assert!(Time::from_time(time!(05:59:58)).between(start, end));
+        assert!(!Time::from_time(time!(06:00:00)).between(start, end));
+        // <https://github.com/zip-rs/zip/blob/v0.6.4/src/types.rs#L553-L569>.
+        assert!(!Time::from_time(time!(10:38:30)).between(start, end));
+        assert!(!Time::from_time(time!(21:59:58)).between(start, end));
+        assert!(Time::from_time(time!(22:00:00)).between(start, end));
+        assert!(Time::MAX.between(start, end));
+    }
+
+    #[test]
+    fn between_with_empty_window() {
+        let time = Time::from_time(time!(12:00:00));
+        assert!(!time.between(time, time));
+        assert!(!Time::MIN.between(time, time));
+        assert!(!Time::MAX.between(time, time));
+    }
+
+    #[test]
+    fn between_is_complement_of_reversed_window() {
+        let (start, end) = (
+            Time::from_time(time!(09:00:00)),
+            Time::from_time(time!(17:00:00)),
+        );
+        for time in Time::all_valid() {
+            assert_ne!(time.between(start, end), time.between(end, start));
+        }
+    }
+
+    #[test]
+    const fn between_is_const_fn() {
+        const _: bool = Time::MIN.between(Time::MIN, Time::MAX);
+    }
+
     #[test]
     fn to_raw() {
         assert_eq!(Time::MIN.to_raw(), u16::MIN);
@@ -346,6 +1228,31 @@ mod tests {
         const _: u16 = Time::MIN.to_raw();
     }
 
+    #[test]
+    fn to_i64() {
+        assert_eq!(Time::MIN.to_i64(), 0);
+        // <https://github.com/zip-rs/zip/blob/v0.6.4/src/types.rs#L553-L569>.
+        assert_eq!(
+            Time::new(0b0101_0100_1100_1111).unwrap().to_i64(),
+            0b0101_0100_1100_1111
+        );
+        assert_eq!(Time::MAX.to_i64(), 0b1011_1111_0111_1101);
+    }
+
+    #[test]
+    fn to_i64_round_trip() {
+        for raw in u16::MIN..=u16::MAX {
+            if let Some(value) = Time::new(raw) {
+                assert_eq!(Time::from_i64(value.to_i64()), Some(value));
+            }
+        }
+    }
+
+    #[test]
+    const fn to_i64_is_const_fn() {
+        const _: i64 = Time::MIN.to_i64();
+    }
+
     #[test]
     fn hour() {
         assert_eq!(Time::MIN.hour(), u8::MIN);
@@ -376,6 +1283,161 @@ mod tests {
         assert_eq!(Time::MAX.second(), 58);
     }
 
+    #[test]
+    fn second_is_always_even() {
+        for time in u16::MIN..=u16::MAX {
+            let time = unsafe { Time::new_unchecked(time) };
+            assert_eq!(time.second() % 2, 0);
+        }
+        assert_eq!(Time::from_time(time!(10:38:31)).second(), 30);
+        assert_eq!(Time::from_time(time!(23:59:59)).second(), 58);
+    }
+
+    #[test]
+    fn second_of_day() {
+        assert_eq!(Time::MIN.second_of_day(), u32::MIN);
+        // <https://devblogs.microsoft.com/oldnewthing/20030905-02/?p=42653>.
+        assert_eq!(
+            Time::new(0b1001_1011_0010_0000).unwrap().second_of_day(),
+            69900
+        );
+        // <https://github.com/zip-rs/zip/blob/v0.6.4/src/types.rs#L553-L569>.
+        assert_eq!(
+            Time::new(0b0101_0100_1100_1111).unwrap().second_of_day(),
+            38310
+        );
+        assert_eq!(Time::MAX.second_of_day(), 86398);
+    }
+
+    #[test]
+    fn second_of_day_is_strictly_increasing() {
+        assert!(
+            Time::all_valid()
+                .zip(Time::all_valid().skip(1))
+                .all(|(a, b)| a.second_of_day() + 2 == b.second_of_day())
+        );
+    }
+
+    #[test]
+    fn duration_until() {
+        assert_eq!(Time::MIN.duration_until(Time::MIN), Some(Duration::ZERO));
+        assert_eq!(
+            Time::MIN.duration_until(Time::MAX),
+            Some(Duration::from_secs(86398))
+        );
+        // <https://github.com/zip-rs/zip/blob/v0.6.4/src/types.rs#L553-L569>.
+        assert_eq!(
+            Time::from_time(time!(09:00:00)).duration_until(Time::from_time(time!(10:38:30))),
+            Some(Duration::from_secs(5910))
+        );
+    }
+
+    #[test]
+    fn duration_until_with_earlier_time() {
+        assert_eq!(Time::MAX.duration_until(Time::MIN), None);
+        assert_eq!(
+            Time::from_time(time!(10:38:30)).duration_until(Time::from_time(time!(10:38:28))),
+            None
+        );
+    }
+
+    #[test]
+    fn wrapping_duration_until() {
+        assert_eq!(Time::MIN.wrapping_duration_until(Time::MIN), Duration::ZERO);
+        assert_eq!(
+            Time::MIN.wrapping_duration_until(Time::MAX),
+            Duration::from_secs(86398)
+        );
+        // <https://github.com/zip-rs/zip/blob/v0.6.4/src/types.rs#L553-L569>.
+        assert_eq!(
+            Time::from_time(time!(09:00:00))
+                .wrapping_duration_until(Time::from_time(time!(10:38:30))),
+            Duration::from_secs(5910)
+        );
+    }
+
+    #[test]
+    fn wrapping_duration_until_overnight() {
+        assert_eq!(
+            Time::MAX.wrapping_duration_until(Time::MIN),
+            Duration::from_secs(2)
+        );
+        assert_eq!(
+            Time::from_time(time!(22:00:00))
+                .wrapping_duration_until(Time::from_time(time!(06:00:00))),
+            Duration::from_secs(28800)
+        );
+        assert_eq!(
+            Time::from_time(time!(10:38:30))
+                .wrapping_duration_until(Time::from_time(time!(10:38:28))),
+            Duration::from_secs(86398)
+        );
+    }
+
+    #[test]
+    fn wrapping_duration_until_agrees_with_duration_until() {
+        let (start, end) = (
+            Time::from_time(time!(09:00:00)),
+            Time::from_time(time!(17:30:00)),
+        );
+        assert_eq!(
+            start.duration_until(end),
+            Some(start.wrapping_duration_until(end))
+        );
+    }
+
+    #[test]
+    fn round_to_minute() {
+        assert_eq!(Time::MIN.round_to_minute(), Time::MIN);
+        assert_eq!(
+            Time::from_time(time!(00:00:28)).round_to_minute(),
+            Time::MIN
+        );
+        assert_eq!(
+            Time::from_time(time!(00:00:30)).round_to_minute(),
+            Time::from_time(time!(00:01:00))
+        );
+        // <https://devblogs.microsoft.com/oldnewthing/20030905-02/?p=42653>.
+        assert_eq!(
+            Time::from_time(time!(19:25:00)).round_to_minute(),
+            Time::from_time(time!(19:25:00))
+        );
+        // <https://github.com/zip-rs/zip/blob/v0.6.4/src/types.rs#L553-L569>.
+        assert_eq!(
+            Time::from_time(time!(10:38:30)).round_to_minute(),
+            Time::from_time(time!(10:39:00))
+        );
+        assert_eq!(
+            Time::from_time(time!(10:59:30)).round_to_minute(),
+            Time::from_time(time!(11:00:00))
+        );
+        assert_eq!(
+            Time::from_time(time!(23:59:28)).round_to_minute(),
+            Time::from_time(time!(23:59:00))
+        );
+        assert_eq!(
+            Time::from_time(time!(23:59:30)).round_to_minute(),
+            Time::from_time(time!(23:59:00))
+        );
+        assert_eq!(
+            Time::MAX.round_to_minute(),
+            Time::from_time(time!(23:59:00))
+        );
+    }
+
+    #[test]
+    const fn round_to_minute_is_const_fn() {
+        const _: Time = Time::MAX.round_to_minute();
+    }
+
+    #[test]
+    fn round_to_minute_agrees_with_second_of_day() {
+        for time in Time::all_valid() {
+            let expected = ((time.second_of_day() + 30) / 60 * 60).min(86340);
+            assert_eq!(time.round_to_minute().second_of_day(), expected);
+        }
+    }
+
     #[test]
     fn default() {
         assert_eq!(Time::default(), Time::MIN);
@@ -4,6 +4,9 @@
 
 //! Constants for [`Time`].
 
+#[cfg(feature = "num-traits")]
+use num_traits::Bounded;
+
 use super::Time;
 
 impl Time {
@@ -36,6 +39,35 @@ impl Time {
     pub const MAX: Self = unsafe { Self::new_unchecked(0b1011_1111_0111_1101) };
 }
 
+#[cfg(feature = "num-traits")]
+impl Bounded for Time {
+    /// Returns [`Time::MIN`].
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use dos_date_time::{Time, num_traits::Bounded};
+    /// #
+    /// assert_eq!(<Time as Bounded>::min_value(), Time::MIN);
+    /// ```
+    fn min_value() -> Self {
+        Self::MIN
+    }
+
+    /// Returns [`Time::MAX`].
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use dos_date_time::{Time, num_traits::Bounded};
+    /// #
+    /// assert_eq!(<Time as Bounded>::max_value(), Time::MAX);
+    /// ```
+    fn max_value() -> Self {
+        Self::MAX
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use time::macros::time;
@@ -51,4 +83,11 @@ mod tests {
     fn max() {
         assert_eq!(Time::MAX, Time::from_time(time!(23:59:58)));
     }
+
+    #[cfg(feature = "num-traits")]
+    #[test]
+    fn bounded() {
+        assert_eq!(<Time as Bounded>::min_value(), Time::MIN);
+        assert_eq!(<Time as Bounded>::max_value(), Time::MAX);
+    }
 }
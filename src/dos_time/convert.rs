@@ -10,6 +10,26 @@ use chrono::{NaiveTime, Timelike};
 use jiff::civil;
 
 use super::Time;
+use crate::error::InvalidTimeField;
+
+impl From<Time> for u16 {
+    /// Converts a `Time` to the MS-DOS time as the underlying [`u16`]
+    /// value.
+    ///
+    /// This is equivalent to [`Time::to_raw`].
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use dos_date_time::Time;
+    /// #
+    /// assert_eq!(u16::from(Time::MIN), u16::MIN);
+    /// assert_eq!(u16::from(Time::MAX), 0b1011_1111_0111_1101);
+    /// ```
+    fn from(time: Time) -> Self {
+        time.to_raw()
+    }
+}
 
 impl From<Time> for time::Time {
     /// Converts a `Time` to a [`time::Time`].
@@ -69,6 +89,27 @@ impl From<Time> for civil::Time {
     /// assert_eq!(civil::Time::from(Time::MIN), civil::Time::MIN);
     /// assert_eq!(civil::Time::from(Time::MAX), civil::time(23, 59, 58, 0));
     /// ```
+    fn from(time: Time) -> Self {
+        let (hour, minute, second) = time.into();
+        civil::time(hour, minute, second, i32::default())
+    }
+}
+
+#[cfg(feature = "jiff")]
+impl From<Time> for (i8, i8, i8) {
+    /// Converts a `Time` to a tuple of the hour, minute, and second.
+    ///
+    /// The types of the fields are the same as those of [`civil::time`], which
+    /// is useful for constructing [`jiff`] values from parts.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use dos_date_time::Time;
+    /// #
+    /// assert_eq!(<(i8, i8, i8)>::from(Time::MIN), (0, 0, 0));
+    /// assert_eq!(<(i8, i8, i8)>::from(Time::MAX), (23, 59, 58));
+    /// ```
     fn from(time: Time) -> Self {
         let (hour, minute, second) = (
             time.hour()
@@ -81,7 +122,34 @@ impl From<Time> for civil::Time {
                 .try_into()
                 .expect("second should be in the range of `i8`"),
         );
-        civil::time(hour, minute, second, i32::default())
+        (hour, minute, second)
+    }
+}
+
+impl TryFrom<u16> for Time {
+    type Error = InvalidTimeField;
+
+    /// Converts the given MS-DOS time to a `Time`.
+    ///
+    /// This is the trait form of [`Time::new`], and is equivalent to
+    /// [`Time::checked_from_raw`].
+    ///
+    /// # Errors
+    ///
+    /// Returns [`Err`] if `time` is not a valid MS-DOS time.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use dos_date_time::Time;
+    /// #
+    /// assert_eq!(Time::try_from(u16::MIN), Ok(Time::MIN));
+    /// assert_eq!(Time::try_from(0b1011_1111_0111_1101), Ok(Time::MAX));
+    ///
+    /// assert!(Time::try_from(0b1100_0000_0000_0000).is_err());
+    /// ```
+    fn try_from(time: u16) -> Result<Self, Self::Error> {
+        Self::checked_from_raw(time)
     }
 }
 
@@ -197,6 +265,22 @@ mod tests {
 
     use super::*;
 
+    #[test]
+    fn from_time_to_u16() {
+        assert_eq!(u16::from(Time::MIN), u16::MIN);
+        // <https://devblogs.microsoft.com/oldnewthing/20030905-02/?p=42653>.
+        assert_eq!(
+            u16::from(Time::new(0b1001_1011_0010_0000).unwrap()),
+            0b1001_1011_0010_0000
+        );
+        // <https://github.com/zip-rs/zip/blob/v0.6.4/src/types.rs#L553-L569>.
+        assert_eq!(
+            u16::from(Time::new(0b0101_0100_1100_1111).unwrap()),
+            0b0101_0100_1100_1111
+        );
+        assert_eq!(u16::from(Time::MAX), 0b1011_1111_0111_1101);
+    }
+
     #[test]
     fn from_time_to_time_time() {
         assert_eq!(time::Time::from(Time::MIN), time::Time::MIDNIGHT);
@@ -250,6 +334,23 @@ mod tests {
         assert_eq!(civil::Time::from(Time::MAX), civil::time(23, 59, 58, 0));
     }
 
+    #[cfg(feature = "jiff")]
+    #[test]
+    fn from_time_to_tuple() {
+        assert_eq!(<(i8, i8, i8)>::from(Time::MIN), (0, 0, 0));
+        // <https://devblogs.microsoft.com/oldnewthing/20030905-02/?p=42653>.
+        assert_eq!(
+            <(i8, i8, i8)>::from(Time::new(0b1001_1011_0010_0000).unwrap()),
+            (19, 25, 0)
+        );
+        // <https://github.com/zip-rs/zip/blob/v0.6.4/src/types.rs#L553-L569>.
+        assert_eq!(
+            <(i8, i8, i8)>::from(Time::new(0b0101_0100_1100_1111).unwrap()),
+            (10, 38, 30)
+        );
+        assert_eq!(<(i8, i8, i8)>::from(Time::MAX), (23, 59, 58));
+    }
+
     #[test]
     fn from_time_time_to_time() {
         assert_eq!(Time::from(time::Time::MIDNIGHT), Time::MIN);
@@ -314,4 +415,53 @@ mod tests {
         assert_eq!(Time::from(civil::time(23, 59, 58, 0)), Time::MAX);
         assert_eq!(Time::from(civil::time(23, 59, 59, 0)), Time::MAX);
     }
+
+    #[test]
+    fn try_from_u16_to_time() {
+        assert_eq!(Time::try_from(u16::MIN).unwrap(), Time::MIN);
+        // <https://devblogs.microsoft.com/oldnewthing/20030905-02/?p=42653>.
+        assert_eq!(
+            Time::try_from(0b1001_1011_0010_0000).unwrap(),
+            Time::new(0b1001_1011_0010_0000).unwrap()
+        );
+        // <https://github.com/zip-rs/zip/blob/v0.6.4/src/types.rs#L553-L569>.
+        assert_eq!(
+            Time::try_from(0b0101_0100_1100_1111).unwrap(),
+            Time::new(0b0101_0100_1100_1111).unwrap()
+        );
+        assert_eq!(Time::try_from(0b1011_1111_0111_1101).unwrap(), Time::MAX);
+    }
+
+    #[test]
+    fn try_from_u16_to_time_with_invalid_time() {
+        // The Hour field is 24.
+        assert_eq!(
+            Time::try_from(0b1100_0000_0000_0000).unwrap_err(),
+            InvalidTimeField::HourTooLarge(24)
+        );
+        // The Minute field is 60.
+        assert_eq!(
+            Time::try_from(0b0000_0111_1000_0000).unwrap_err(),
+            InvalidTimeField::MinuteTooLarge(60)
+        );
+        // The `DoubleSeconds` field is 30.
+        assert_eq!(
+            Time::try_from(0b0000_0000_0001_1110).unwrap_err(),
+            InvalidTimeField::DoubleSecondsTooLarge(30)
+        );
+    }
+
+    #[test]
+    fn try_from_u16_agrees_with_new() {
+        for time in u16::MIN..=u16::MAX {
+            assert_eq!(Time::try_from(time).ok(), Time::new(time));
+        }
+    }
+
+    #[test]
+    fn u16_roundtrip() {
+        for time in Time::all_valid() {
+            assert_eq!(Time::try_from(u16::from(time)).unwrap(), time);
+        }
+    }
 }
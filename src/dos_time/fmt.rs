@@ -4,10 +4,64 @@
 
 //! Utilities for formatting and printing [`Time`].
 
+#[cfg(feature = "alloc")]
+use alloc::string::String;
 use core::fmt;
 
 use super::Time;
 
+impl Time {
+    /// Returns the value of this `Time` in the 12-hour clock with AM or PM,
+    /// such as `"10:38:30 AM"`.
+    ///
+    /// Midnight is `"12:00:00 AM"`, and noon is `"12:00:00 PM"`. The
+    /// [`Display`](fmt::Display) output is in the 24-hour clock.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use dos_date_time::Time;
+    /// #
+    /// assert_eq!(Time::MIN.to_12h_string(), "12:00:00 AM");
+    /// assert_eq!(Time::MAX.to_12h_string(), "11:59:58 PM");
+    /// ```
+    #[cfg(feature = "alloc")]
+    #[must_use]
+    pub fn to_12h_string(self) -> String {
+        let (hour, minute, second) = (self.hour(), self.minute(), self.second());
+        let period = if hour < 12 { "AM" } else { "PM" };
+        let hour = match hour % 12 {
+            0 => 12,
+            hour => hour,
+        };
+        format!("{hour:02}:{minute:02}:{second:02} {period}")
+    }
+
+    #[allow(clippy::missing_panics_doc)]
+    /// Writes the [`Display`](fmt::Display) output of this `Time` into `buf`.
+    ///
+    /// `buf` is cleared first, so its previous contents are discarded. This
+    /// is equivalent to `*buf = self.to_string()`, but reuses the allocation
+    /// of `buf`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use dos_date_time::Time;
+    /// #
+    /// let mut buf = String::new();
+    /// Time::MIN.to_string_buf(&mut buf);
+    /// assert_eq!(buf, "00:00:00");
+    /// Time::MAX.to_string_buf(&mut buf);
+    /// assert_eq!(buf, "23:59:58");
+    /// ```
+    #[cfg(feature = "alloc")]
+    pub fn to_string_buf(self, buf: &mut String) {
+        buf.clear();
+        fmt::write(buf, format_args!("{self}")).expect("formatting should not fail");
+    }
+}
+
 impl fmt::Display for Time {
     /// Shows the value of this `Time` in the well-known [RFC 3339 format].
     ///
@@ -27,6 +81,25 @@ impl fmt::Display for Time {
     }
 }
 
+impl fmt::Octal for Time {
+    /// Shows the MS-DOS time of this `Time` in octal.
+    ///
+    /// This forwards to the implementation for [`u16`], so the formatting
+    /// flags such as `#` and the width are supported.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use dos_date_time::Time;
+    /// #
+    /// assert_eq!(format!("{:o}", Time::MIN), "0");
+    /// assert_eq!(format!("{:#o}", Time::MAX), "0o137575");
+    /// ```
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        fmt::Octal::fmt(&self.to_raw(), f)
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use time::macros::time;
@@ -58,4 +131,97 @@ mod tests {
         assert_eq!(format!("{}", Time::from_time(time!(10:38:30))), "10:38:30");
         assert_eq!(format!("{}", Time::MAX), "23:59:58");
     }
+
+    #[test]
+    fn octal() {
+        assert_eq!(format!("{:o}", Time::MIN), "0");
+        // <https://github.com/zip-rs/zip/blob/v0.6.4/src/types.rs#L553-L569>.
+        assert_eq!(format!("{:o}", Time::from_time(time!(10:38:30))), "52317");
+        assert_eq!(format!("{:o}", Time::MAX), "137575");
+    }
+
+    #[test]
+    fn octal_with_flags() {
+        assert_eq!(format!("{:#o}", Time::MIN), "0o0");
+        assert_eq!(format!("{:06o}", Time::MIN), "000000");
+        assert_eq!(format!("{:#08o}", Time::MAX), "0o137575");
+        assert_eq!(
+            format!("{:o}", Time::MAX),
+            format!("{:o}", 0b1011_1111_0111_1101_u16)
+        );
+    }
+
+    #[cfg(feature = "alloc")]
+    #[test]
+    fn to_12h_string() {
+        // Midnight.
+        assert_eq!(Time::MIN.to_12h_string(), "12:00:00 AM");
+        assert_eq!(
+            Time::from_time(time!(00:59:58)).to_12h_string(),
+            "12:59:58 AM"
+        );
+        // Morning.
+        assert_eq!(
+            Time::from_time(time!(01:00:00)).to_12h_string(),
+            "01:00:00 AM"
+        );
+        // <https://github.com/zip-rs/zip/blob/v0.6.4/src/types.rs#L553-L569>.
+        assert_eq!(
+            Time::from_time(time!(10:38:30)).to_12h_string(),
+            "10:38:30 AM"
+        );
+        assert_eq!(
+            Time::from_time(time!(11:59:58)).to_12h_string(),
+            "11:59:58 AM"
+        );
+        // Noon.
+        assert_eq!(
+            Time::from_time(time!(12:00:00)).to_12h_string(),
+            "12:00:00 PM"
+        );
+        assert_eq!(
+            Time::from_time(time!(12:59:58)).to_12h_string(),
+            "12:59:58 PM"
+        );
+        // Afternoon and evening.
+        assert_eq!(
+            Time::from_time(time!(13:00:00)).to_12h_string(),
+            "01:00:00 PM"
+        );
+        // <https://devblogs.microsoft.com/oldnewthing/20030905-02/?p=42653>.
+        assert_eq!(
+            Time::from_time(time!(19:25:00)).to_12h_string(),
+            "07:25:00 PM"
+        );
+        assert_eq!(
+            Time::from_time(time!(23:25:00)).to_12h_string(),
+            "11:25:00 PM"
+        );
+        assert_eq!(Time::MAX.to_12h_string(), "11:59:58 PM");
+    }
+
+    #[cfg(feature = "alloc")]
+    #[test]
+    fn to_string_buf() {
+        use alloc::string::ToString;
+
+        // <https://github.com/zip-rs/zip/blob/v0.6.4/src/types.rs#L553-L569>.
+        for value in [Time::MIN, Time::from_time(time!(10:38:30)), Time::MAX] {
+            let mut buf = String::new();
+            value.to_string_buf(&mut buf);
+            assert_eq!(buf, value.to_string());
+        }
+    }
+
+    #[cfg(feature = "alloc")]
+    #[test]
+    fn to_string_buf_reuses_buffer() {
+        let mut buf = String::from("this should be discarded entirely");
+        Time::MAX.to_string_buf(&mut buf);
+        assert_eq!(buf, "23:59:58");
+        let capacity = buf.capacity();
+        Time::MIN.to_string_buf(&mut buf);
+        assert_eq!(buf, format!("{}", Time::MIN));
+        assert_eq!(buf.capacity(), capacity);
+    }
 }
@@ -4,10 +4,24 @@
 
 //! Error types for this crate.
 
+mod bits;
+mod convert;
 mod dos_date;
 mod dos_date_time;
+mod dos_time;
+mod parse;
+mod read;
+mod unknown_kind;
+mod write;
 
 pub use self::{
-    dos_date::{DateRangeError, DateRangeErrorKind},
-    dos_date_time::{DateTimeRangeError, DateTimeRangeErrorKind},
+    bits::{InvalidBitsError, InvalidBitsErrorKind},
+    convert::{ConvertError, ConvertErrorKind},
+    dos_date::{DateError, DateErrorKind, DateRangeError, DateRangeErrorKind, InvalidDateField},
+    dos_date_time::{DateTimeError, DateTimeErrorKind, DateTimeRangeError, DateTimeRangeErrorKind},
+    dos_time::{InvalidTimeField, TimePrecisionError, TimePrecisionErrorKind},
+    parse::{ParseError, ParseErrorKind},
+    read::{ReadError, ReadErrorKind},
+    unknown_kind::UnknownKindError,
+    write::BufferTooSmallError,
 };
@@ -0,0 +1,252 @@
+// SPDX-FileCopyrightText: 2025 Shun Sakai
+//
+// SPDX-License-Identifier: Apache-2.0 OR MIT
+
+//! Error types for creating [`DateTime`](crate::DateTime) from raw bits.
+
+use core::{error::Error, fmt};
+
+use crate::error::{InvalidDateField, InvalidTimeField};
+
+/// The error type indicating that the given MS-DOS date and time were not
+/// valid.
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub struct InvalidBitsError(InvalidBitsErrorKind);
+
+impl InvalidBitsError {
+    pub(crate) const fn new(kind: InvalidBitsErrorKind) -> Self {
+        Self(kind)
+    }
+
+    /// Returns the corresponding [`InvalidBitsErrorKind`] for this error.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use dos_date_time::{
+    /// #     DateTime, ValidationPolicy,
+    /// #     error::{InvalidBitsErrorKind, InvalidDateField, InvalidTimeField},
+    /// # };
+    /// #
+    /// let err = DateTime::new_with_policy(0b0000_0000_0000_0001, u16::MIN, ValidationPolicy::Strict)
+    ///     .unwrap_err();
+    /// assert_eq!(
+    ///     err.kind(),
+    ///     InvalidBitsErrorKind::Date(InvalidDateField::MonthZero)
+    /// );
+    ///
+    /// let err = DateTime::new_with_policy(0b0000_0000_0010_0001, u16::MAX, ValidationPolicy::Strict)
+    ///     .unwrap_err();
+    /// assert_eq!(
+    ///     err.kind(),
+    ///     InvalidBitsErrorKind::Time(InvalidTimeField::HourTooLarge(31))
+    /// );
+    /// ```
+    #[must_use]
+    pub const fn kind(&self) -> InvalidBitsErrorKind {
+        self.0
+    }
+}
+
+impl fmt::Display for InvalidBitsError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        self.kind().fmt(f)
+    }
+}
+
+impl Error for InvalidBitsError {}
+
+impl From<InvalidBitsErrorKind> for InvalidBitsError {
+    fn from(kind: InvalidBitsErrorKind) -> Self {
+        Self::new(kind)
+    }
+}
+
+impl From<InvalidDateField> for InvalidBitsError {
+    fn from(field: InvalidDateField) -> Self {
+        Self::new(InvalidBitsErrorKind::Date(field))
+    }
+}
+
+impl From<InvalidTimeField> for InvalidBitsError {
+    fn from(field: InvalidTimeField) -> Self {
+        Self::new(InvalidBitsErrorKind::Time(field))
+    }
+}
+
+/// Details of the error that caused an [`InvalidBitsError`].
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize))]
+pub enum InvalidBitsErrorKind {
+    /// The MS-DOS date was not valid.
+    Date(InvalidDateField),
+
+    /// The MS-DOS time was not valid.
+    Time(InvalidTimeField),
+}
+
+impl fmt::Display for InvalidBitsErrorKind {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::Date(field) => write!(f, "invalid MS-DOS date: {field}"),
+            Self::Time(field) => write!(f, "invalid MS-DOS time: {field}"),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn clone_invalid_bits_error() {
+        assert_eq!(
+            InvalidBitsError::new(InvalidBitsErrorKind::Date(InvalidDateField::MonthZero)).clone(),
+            InvalidBitsError::new(InvalidBitsErrorKind::Date(InvalidDateField::MonthZero))
+        );
+        assert_eq!(
+            InvalidBitsError::new(InvalidBitsErrorKind::Time(InvalidTimeField::HourTooLarge(
+                24
+            )))
+            .clone(),
+            InvalidBitsError::new(InvalidBitsErrorKind::Time(InvalidTimeField::HourTooLarge(
+                24
+            )))
+        );
+    }
+
+    #[test]
+    fn copy_invalid_bits_error() {
+        let a = InvalidBitsError::new(InvalidBitsErrorKind::Date(InvalidDateField::DayZero));
+        let b = a;
+        assert_eq!(a, b);
+    }
+
+    #[test]
+    fn debug_invalid_bits_error() {
+        assert_eq!(
+            format!(
+                "{:?}",
+                InvalidBitsError::new(InvalidBitsErrorKind::Date(InvalidDateField::MonthZero))
+            ),
+            "InvalidBitsError(Date(MonthZero))"
+        );
+        assert_eq!(
+            format!(
+                "{:?}",
+                InvalidBitsError::new(InvalidBitsErrorKind::Time(
+                    InvalidTimeField::MinuteTooLarge(60)
+                ))
+            ),
+            "InvalidBitsError(Time(MinuteTooLarge(60)))"
+        );
+    }
+
+    #[test]
+    fn invalid_bits_error_equality() {
+        assert_eq!(
+            InvalidBitsError::new(InvalidBitsErrorKind::Date(InvalidDateField::MonthZero)),
+            InvalidBitsError::new(InvalidBitsErrorKind::Date(InvalidDateField::MonthZero))
+        );
+        assert_ne!(
+            InvalidBitsError::new(InvalidBitsErrorKind::Date(InvalidDateField::MonthZero)),
+            InvalidBitsError::new(InvalidBitsErrorKind::Date(InvalidDateField::DayZero))
+        );
+        assert_ne!(
+            InvalidBitsError::new(InvalidBitsErrorKind::Time(InvalidTimeField::HourTooLarge(
+                24
+            ))),
+            InvalidBitsError::new(InvalidBitsErrorKind::Time(
+                InvalidTimeField::MinuteTooLarge(24)
+            ))
+        );
+    }
+
+    #[test]
+    fn kind_invalid_bits_error() {
+        assert_eq!(
+            InvalidBitsError::new(InvalidBitsErrorKind::Date(InvalidDateField::DayZero)).kind(),
+            InvalidBitsErrorKind::Date(InvalidDateField::DayZero)
+        );
+    }
+
+    #[test]
+    const fn kind_invalid_bits_error_is_const_fn() {
+        const _: InvalidBitsErrorKind =
+            InvalidBitsError::new(InvalidBitsErrorKind::Date(InvalidDateField::DayZero)).kind();
+    }
+
+    #[test]
+    fn display_invalid_bits_error() {
+        assert_eq!(
+            format!(
+                "{}",
+                InvalidBitsError::new(InvalidBitsErrorKind::Date(InvalidDateField::MonthZero))
+            ),
+            "invalid MS-DOS date: the Month field is 0"
+        );
+        assert_eq!(
+            format!(
+                "{}",
+                InvalidBitsError::new(InvalidBitsErrorKind::Time(InvalidTimeField::HourTooLarge(
+                    24
+                )))
+            ),
+            "invalid MS-DOS time: the Hour field is 24, which is greater than 23"
+        );
+    }
+
+    #[test]
+    fn source_invalid_bits_error() {
+        assert!(
+            InvalidBitsError::new(InvalidBitsErrorKind::Date(InvalidDateField::DayZero))
+                .source()
+                .is_none()
+        );
+    }
+
+    #[cfg(feature = "serde")]
+    #[test]
+    fn serialize_invalid_bits_error_kind() {
+        assert_eq!(
+            serde_json::to_string(&InvalidBitsErrorKind::Date(InvalidDateField::MonthZero))
+                .unwrap(),
+            r#"{"Date":"MonthZero"}"#
+        );
+        assert_eq!(
+            serde_json::to_string(&InvalidBitsErrorKind::Time(InvalidTimeField::HourTooLarge(
+                24
+            )))
+            .unwrap(),
+            r#"{"Time":{"HourTooLarge":24}}"#
+        );
+    }
+
+    #[test]
+    fn from_invalid_bits_error_kind_to_invalid_bits_error() {
+        assert_eq!(
+            InvalidBitsError::from(InvalidBitsErrorKind::Date(InvalidDateField::DayZero)),
+            InvalidBitsError::new(InvalidBitsErrorKind::Date(InvalidDateField::DayZero))
+        );
+    }
+
+    #[test]
+    fn from_invalid_date_field_to_invalid_bits_error() {
+        assert_eq!(
+            InvalidBitsError::from(InvalidDateField::MonthTooLarge(13)),
+            InvalidBitsError::new(InvalidBitsErrorKind::Date(InvalidDateField::MonthTooLarge(
+                13
+            )))
+        );
+    }
+
+    #[test]
+    fn from_invalid_time_field_to_invalid_bits_error() {
+        assert_eq!(
+            InvalidBitsError::from(InvalidTimeField::DoubleSecondsTooLarge(30)),
+            InvalidBitsError::new(InvalidBitsErrorKind::Time(
+                InvalidTimeField::DoubleSecondsTooLarge(30)
+            ))
+        );
+    }
+}
@@ -0,0 +1,238 @@
+// SPDX-FileCopyrightText: 2025 Shun Sakai
+//
+// SPDX-License-Identifier: Apache-2.0 OR MIT
+
+//! Error types for converting to [`DateTime`](crate::DateTime).
+
+use core::{error::Error, fmt};
+
+use crate::error::{DateTimeRangeError, DateTimeRangeErrorKind};
+
+/// The error type indicating that a value could not be converted to a
+/// [`DateTime`](crate::DateTime).
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub struct ConvertError(ConvertErrorKind);
+
+impl ConvertError {
+    pub(crate) const fn new(kind: ConvertErrorKind) -> Self {
+        Self(kind)
+    }
+
+    /// Returns the corresponding [`ConvertErrorKind`] for this error.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use dos_date_time::{
+    /// #     DateTime,
+    /// #     error::{ConvertErrorKind, DateTimeRangeErrorKind},
+    /// #     time::macros::datetime,
+    /// # };
+    /// #
+    /// let err = DateTime::try_from_utc(datetime!(1980-01-01 00:00:00 +01:00)).unwrap_err();
+    /// assert_eq!(err.kind(), ConvertErrorKind::NotUtc);
+    ///
+    /// let err = DateTime::try_from_utc(datetime!(1979-12-31 23:59:59 UTC)).unwrap_err();
+    /// assert_eq!(
+    ///     err.kind(),
+    ///     ConvertErrorKind::Range(DateTimeRangeErrorKind::Negative)
+    /// );
+    /// ```
+    #[must_use]
+    pub const fn kind(&self) -> ConvertErrorKind {
+        self.0
+    }
+}
+
+impl fmt::Display for ConvertError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        self.kind().fmt(f)
+    }
+}
+
+impl Error for ConvertError {}
+
+impl From<ConvertErrorKind> for ConvertError {
+    fn from(kind: ConvertErrorKind) -> Self {
+        Self::new(kind)
+    }
+}
+
+impl From<DateTimeRangeError> for ConvertError {
+    fn from(err: DateTimeRangeError) -> Self {
+        Self::new(ConvertErrorKind::Range(err.kind()))
+    }
+}
+
+/// Details of the error that caused a [`ConvertError`].
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize))]
+pub enum ConvertErrorKind {
+    /// The UTC offset of the value was not zero.
+    NotUtc,
+
+    /// The value was NaN or infinite.
+    NotFinite,
+
+    /// The converted value was out of range for MS-DOS date and time.
+    Range(DateTimeRangeErrorKind),
+}
+
+impl fmt::Display for ConvertErrorKind {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::NotUtc => write!(f, "UTC offset is not zero"),
+            Self::NotFinite => write!(f, "value is not finite"),
+            Self::Range(kind) => kind.fmt(f),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn clone_convert_error() {
+        assert_eq!(
+            ConvertError::new(ConvertErrorKind::NotUtc).clone(),
+            ConvertError::new(ConvertErrorKind::NotUtc)
+        );
+        assert_eq!(
+            ConvertError::new(ConvertErrorKind::Range(DateTimeRangeErrorKind::Overflow)).clone(),
+            ConvertError::new(ConvertErrorKind::Range(DateTimeRangeErrorKind::Overflow))
+        );
+    }
+
+    #[test]
+    fn copy_convert_error() {
+        let a = ConvertError::new(ConvertErrorKind::NotUtc);
+        let b = a;
+        assert_eq!(a, b);
+    }
+
+    #[test]
+    fn debug_convert_error() {
+        assert_eq!(
+            format!("{:?}", ConvertError::new(ConvertErrorKind::NotUtc)),
+            "ConvertError(NotUtc)"
+        );
+        assert_eq!(
+            format!(
+                "{:?}",
+                ConvertError::new(ConvertErrorKind::Range(DateTimeRangeErrorKind::Negative))
+            ),
+            "ConvertError(Range(Negative))"
+        );
+    }
+
+    #[test]
+    fn convert_error_equality() {
+        assert_eq!(
+            ConvertError::new(ConvertErrorKind::NotUtc),
+            ConvertError::new(ConvertErrorKind::NotUtc)
+        );
+        assert_ne!(
+            ConvertError::new(ConvertErrorKind::NotUtc),
+            ConvertError::new(ConvertErrorKind::NotFinite)
+        );
+        assert_ne!(
+            ConvertError::new(ConvertErrorKind::NotUtc),
+            ConvertError::new(ConvertErrorKind::Range(DateTimeRangeErrorKind::Negative))
+        );
+        assert_ne!(
+            ConvertError::new(ConvertErrorKind::Range(DateTimeRangeErrorKind::Negative)),
+            ConvertError::new(ConvertErrorKind::Range(DateTimeRangeErrorKind::Overflow))
+        );
+    }
+
+    #[test]
+    fn kind_convert_error() {
+        assert_eq!(
+            ConvertError::new(ConvertErrorKind::NotUtc).kind(),
+            ConvertErrorKind::NotUtc
+        );
+        assert_eq!(
+            ConvertError::new(ConvertErrorKind::Range(DateTimeRangeErrorKind::Overflow)).kind(),
+            ConvertErrorKind::Range(DateTimeRangeErrorKind::Overflow)
+        );
+    }
+
+    #[test]
+    const fn kind_convert_error_is_const_fn() {
+        const _: ConvertErrorKind = ConvertError::new(ConvertErrorKind::NotUtc).kind();
+    }
+
+    #[test]
+    fn display_convert_error() {
+        assert_eq!(
+            format!("{}", ConvertError::new(ConvertErrorKind::NotUtc)),
+            "UTC offset is not zero"
+        );
+        assert_eq!(
+            format!("{}", ConvertError::new(ConvertErrorKind::NotFinite)),
+            "value is not finite"
+        );
+        assert_eq!(
+            format!(
+                "{}",
+                ConvertError::new(ConvertErrorKind::Range(DateTimeRangeErrorKind::Negative))
+            ),
+            "MS-DOS date and time are before `1980-01-01 00:00:00`"
+        );
+        assert_eq!(
+            format!(
+                "{}",
+                ConvertError::new(ConvertErrorKind::Range(DateTimeRangeErrorKind::Overflow))
+            ),
+            "MS-DOS date and time are after `2107-12-31 23:59:58`"
+        );
+    }
+
+    #[test]
+    fn source_convert_error() {
+        assert!(
+            ConvertError::new(ConvertErrorKind::NotUtc)
+                .source()
+                .is_none()
+        );
+    }
+
+    #[test]
+    fn from_convert_error_kind_to_convert_error() {
+        assert_eq!(
+            ConvertError::from(ConvertErrorKind::NotUtc),
+            ConvertError::new(ConvertErrorKind::NotUtc)
+        );
+    }
+
+    #[test]
+    fn from_date_time_range_error_to_convert_error() {
+        assert_eq!(
+            ConvertError::from(DateTimeRangeError::new(DateTimeRangeErrorKind::Negative)),
+            ConvertError::new(ConvertErrorKind::Range(DateTimeRangeErrorKind::Negative))
+        );
+        assert_eq!(
+            ConvertError::from(DateTimeRangeError::new(DateTimeRangeErrorKind::Overflow)),
+            ConvertError::new(ConvertErrorKind::Range(DateTimeRangeErrorKind::Overflow))
+        );
+    }
+
+    #[cfg(feature = "serde")]
+    #[test]
+    fn serialize_convert_error_kind() {
+        assert_eq!(
+            serde_json::to_string(&ConvertErrorKind::NotUtc).unwrap(),
+            r#""NotUtc""#
+        );
+        assert_eq!(
+            serde_json::to_string(&ConvertErrorKind::NotFinite).unwrap(),
+            r#""NotFinite""#
+        );
+        assert_eq!(
+            serde_json::to_string(&ConvertErrorKind::Range(DateTimeRangeErrorKind::Overflow))
+                .unwrap(),
+            r#"{"Range":"Overflow"}"#
+        );
+    }
+}
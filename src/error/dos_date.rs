@@ -4,7 +4,9 @@
 
 //! Error types for [`Date`](crate::Date).
 
-use core::{error::Error, fmt};
+use core::{error::Error, fmt, str::FromStr};
+
+use crate::error::UnknownKindError;
 
 /// The error type indicating that a [`Date`](crate::Date) was out of range.
 #[derive(Clone, Copy, Debug, Eq, PartialEq)]
@@ -42,6 +44,25 @@ impl fmt::Display for DateRangeError {
 
 impl Error for DateRangeError {}
 
+impl Default for DateRangeError {
+    /// Returns the default value of an error with
+    /// [`DateRangeErrorKind::Overflow`].
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use dos_date_time::error::{DateRangeErrorKind, DateRangeError};
+    /// #
+    /// assert_eq!(
+    ///     DateRangeError::default().kind(),
+    ///     DateRangeErrorKind::Overflow
+    /// );
+    /// ```
+    fn default() -> Self {
+        Self::new(DateRangeErrorKind::Overflow)
+    }
+}
+
 impl From<DateRangeErrorKind> for DateRangeError {
     fn from(kind: DateRangeErrorKind) -> Self {
         Self::new(kind)
@@ -50,6 +71,7 @@ impl From<DateRangeErrorKind> for DateRangeError {
 
 /// Details of the error that caused a [`DateRangeError`].
 #[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize))]
 pub enum DateRangeErrorKind {
     /// Value was negative.
     ///
@@ -71,6 +93,167 @@ impl fmt::Display for DateRangeErrorKind {
     }
 }
 
+impl FromStr for DateRangeErrorKind {
+    type Err = UnknownKindError;
+
+    /// Parses a string `s` to return a value of this type.
+    ///
+    /// `s` is the name of the variant, and is matched case-insensitively.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`Err`] if `s` is not the name of any variant.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use dos_date_time::error::DateRangeErrorKind;
+    /// #
+    /// assert_eq!("negative".parse(), Ok(DateRangeErrorKind::Negative));
+    /// assert_eq!("OVERFLOW".parse(), Ok(DateRangeErrorKind::Overflow));
+    ///
+    /// assert!("underflow".parse::<DateRangeErrorKind>().is_err());
+    /// ```
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        if s.eq_ignore_ascii_case("Negative") {
+            Ok(Self::Negative)
+        } else if s.eq_ignore_ascii_case("Overflow") {
+            Ok(Self::Overflow)
+        } else {
+            Err(UnknownKindError)
+        }
+    }
+}
+
+/// The error type indicating that a [`Date`](crate::Date) could not be created
+/// from its components.
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub struct DateError(DateErrorKind);
+
+impl DateError {
+    pub(crate) const fn new(kind: DateErrorKind) -> Self {
+        Self(kind)
+    }
+
+    /// Returns the corresponding [`DateErrorKind`] for this error.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use dos_date_time::{
+    /// #     Date,
+    /// #     error::{DateErrorKind, DateRangeErrorKind},
+    /// #     time::Month,
+    /// # };
+    /// #
+    /// let err = Date::from_year_month_day(2001, Month::February, 29).unwrap_err();
+    /// assert_eq!(err.kind(), DateErrorKind::InvalidDay);
+    ///
+    /// let err = Date::from_year_month_day(2108, Month::January, 1).unwrap_err();
+    /// assert_eq!(
+    ///     err.kind(),
+    ///     DateErrorKind::Range(DateRangeErrorKind::Overflow)
+    /// );
+    /// ```
+    #[must_use]
+    pub const fn kind(&self) -> DateErrorKind {
+        self.0
+    }
+}
+
+impl fmt::Display for DateError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        self.kind().fmt(f)
+    }
+}
+
+impl Error for DateError {}
+
+impl From<DateErrorKind> for DateError {
+    fn from(kind: DateErrorKind) -> Self {
+        Self::new(kind)
+    }
+}
+
+impl From<DateRangeError> for DateError {
+    fn from(err: DateRangeError) -> Self {
+        Self::new(DateErrorKind::Range(err.kind()))
+    }
+}
+
+/// Details of the error that caused a [`DateError`].
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize))]
+pub enum DateErrorKind {
+    /// The month was not in the range `1..=12`.
+    InvalidMonth,
+
+    /// The day was invalid for the given month in the given year.
+    ///
+    /// This means, for example, the day was 29 in February of a non-leap
+    /// year.
+    InvalidDay,
+
+    /// The ordinal day was invalid for the given year.
+    ///
+    /// This means, for example, the ordinal day was 366 in a non-leap year.
+    InvalidOrdinal,
+
+    /// The date was out of range for the MS-DOS date.
+    Range(DateRangeErrorKind),
+}
+
+impl fmt::Display for DateErrorKind {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::InvalidMonth => write!(f, "month is out of range"),
+            Self::InvalidDay => write!(f, "day is out of range for the month"),
+            Self::InvalidOrdinal => write!(f, "ordinal day is out of range for the year"),
+            Self::Range(kind) => kind.fmt(f),
+        }
+    }
+}
+
+/// The error type indicating which field of an MS-DOS date was invalid.
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize))]
+pub enum InvalidDateField {
+    /// The Month field was 0.
+    MonthZero,
+
+    /// The Month field was greater than 12.
+    MonthTooLarge(u8),
+
+    /// The Day field was 0.
+    DayZero,
+
+    /// The Day field was after the last day of the month.
+    DayTooLarge {
+        /// The value of the Day field.
+        day: u8,
+
+        /// The last day of the month.
+        max: u8,
+    },
+}
+
+impl fmt::Display for InvalidDateField {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::MonthZero => write!(f, "the Month field is 0"),
+            Self::MonthTooLarge(month) => {
+                write!(f, "the Month field is {month}, which is greater than 12")
+            }
+            Self::DayZero => write!(f, "the Day field is 0"),
+            Self::DayTooLarge { day, max } => {
+                write!(f, "the Day field is {day}, which is greater than {max}")
+            }
+        }
+    }
+}
+
+impl Error for InvalidDateField {}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -177,6 +360,14 @@ mod tests {
         );
     }
 
+    #[test]
+    fn default_date_range_error() {
+        assert_eq!(
+            DateRangeError::default().kind(),
+            DateRangeErrorKind::Overflow
+        );
+    }
+
     #[test]
     fn from_date_range_error_kind_to_date_range_error() {
         assert_eq!(
@@ -241,4 +432,304 @@ mod tests {
             "MS-DOS date is after `2107-12-31`"
         );
     }
+
+    #[cfg(feature = "serde")]
+    #[test]
+    fn serialize_date_range_error_kind() {
+        assert_eq!(
+            serde_json::to_string(&DateRangeErrorKind::Negative).unwrap(),
+            r#""Negative""#
+        );
+        assert_eq!(
+            serde_json::to_string(&DateRangeErrorKind::Overflow).unwrap(),
+            r#""Overflow""#
+        );
+    }
+
+    #[test]
+    fn clone_date_error() {
+        assert_eq!(
+            DateError::new(DateErrorKind::InvalidDay).clone(),
+            DateError::new(DateErrorKind::InvalidDay)
+        );
+        assert_eq!(
+            DateError::new(DateErrorKind::Range(DateRangeErrorKind::Negative)).clone(),
+            DateError::new(DateErrorKind::Range(DateRangeErrorKind::Negative))
+        );
+    }
+
+    #[test]
+    fn copy_date_error() {
+        let a = DateError::new(DateErrorKind::InvalidDay);
+        let b = a;
+        assert_eq!(a, b);
+    }
+
+    #[test]
+    fn debug_date_error() {
+        assert_eq!(
+            format!("{:?}", DateError::new(DateErrorKind::InvalidDay)),
+            "DateError(InvalidDay)"
+        );
+        assert_eq!(
+            format!(
+                "{:?}",
+                DateError::new(DateErrorKind::Range(DateRangeErrorKind::Overflow))
+            ),
+            "DateError(Range(Overflow))"
+        );
+    }
+
+    #[test]
+    fn date_error_equality() {
+        assert_eq!(
+            DateError::new(DateErrorKind::InvalidDay),
+            DateError::new(DateErrorKind::InvalidDay)
+        );
+        assert_ne!(
+            DateError::new(DateErrorKind::InvalidDay),
+            DateError::new(DateErrorKind::InvalidOrdinal)
+        );
+        assert_ne!(
+            DateError::new(DateErrorKind::InvalidDay),
+            DateError::new(DateErrorKind::Range(DateRangeErrorKind::Negative))
+        );
+        assert_ne!(
+            DateError::new(DateErrorKind::Range(DateRangeErrorKind::Negative)),
+            DateError::new(DateErrorKind::Range(DateRangeErrorKind::Overflow))
+        );
+    }
+
+    #[test]
+    fn kind_date_error() {
+        assert_eq!(
+            DateError::new(DateErrorKind::InvalidDay).kind(),
+            DateErrorKind::InvalidDay
+        );
+        assert_eq!(
+            DateError::new(DateErrorKind::Range(DateRangeErrorKind::Overflow)).kind(),
+            DateErrorKind::Range(DateRangeErrorKind::Overflow)
+        );
+    }
+
+    #[test]
+    const fn kind_date_error_is_const_fn() {
+        const _: DateErrorKind = DateError::new(DateErrorKind::InvalidDay).kind();
+    }
+
+    #[test]
+    fn display_date_error() {
+        assert_eq!(
+            format!("{}", DateError::new(DateErrorKind::InvalidMonth)),
+            "month is out of range"
+        );
+        assert_eq!(
+            format!("{}", DateError::new(DateErrorKind::InvalidDay)),
+            "day is out of range for the month"
+        );
+        assert_eq!(
+            format!("{}", DateError::new(DateErrorKind::InvalidOrdinal)),
+            "ordinal day is out of range for the year"
+        );
+        assert_eq!(
+            format!(
+                "{}",
+                DateError::new(DateErrorKind::Range(DateRangeErrorKind::Negative))
+            ),
+            "MS-DOS date is before `1980-01-01`"
+        );
+        assert_eq!(
+            format!(
+                "{}",
+                DateError::new(DateErrorKind::Range(DateRangeErrorKind::Overflow))
+            ),
+            "MS-DOS date is after `2107-12-31`"
+        );
+    }
+
+    #[test]
+    fn source_date_error() {
+        assert!(DateError::new(DateErrorKind::InvalidDay).source().is_none());
+    }
+
+    #[test]
+    fn from_date_error_kind_to_date_error() {
+        assert_eq!(
+            DateError::from(DateErrorKind::InvalidDay),
+            DateError::new(DateErrorKind::InvalidDay)
+        );
+    }
+
+    #[test]
+    fn from_date_range_error_to_date_error() {
+        assert_eq!(
+            DateError::from(DateRangeError::new(DateRangeErrorKind::Negative)),
+            DateError::new(DateErrorKind::Range(DateRangeErrorKind::Negative))
+        );
+        assert_eq!(
+            DateError::from(DateRangeError::new(DateRangeErrorKind::Overflow)),
+            DateError::new(DateErrorKind::Range(DateRangeErrorKind::Overflow))
+        );
+    }
+
+    #[cfg(feature = "serde")]
+    #[test]
+    fn serialize_date_error_kind() {
+        assert_eq!(
+            serde_json::to_string(&DateErrorKind::InvalidMonth).unwrap(),
+            r#""InvalidMonth""#
+        );
+        assert_eq!(
+            serde_json::to_string(&DateErrorKind::InvalidDay).unwrap(),
+            r#""InvalidDay""#
+        );
+        assert_eq!(
+            serde_json::to_string(&DateErrorKind::InvalidOrdinal).unwrap(),
+            r#""InvalidOrdinal""#
+        );
+        assert_eq!(
+            serde_json::to_string(&DateErrorKind::Range(DateRangeErrorKind::Negative)).unwrap(),
+            r#"{"Range":"Negative"}"#
+        );
+    }
+
+    #[test]
+    fn clone_invalid_date_field() {
+        assert_eq!(
+            InvalidDateField::MonthZero.clone(),
+            InvalidDateField::MonthZero
+        );
+        assert_eq!(
+            InvalidDateField::DayTooLarge { day: 30, max: 28 }.clone(),
+            InvalidDateField::DayTooLarge { day: 30, max: 28 }
+        );
+    }
+
+    #[test]
+    fn copy_invalid_date_field() {
+        let a = InvalidDateField::MonthTooLarge(13);
+        let b = a;
+        assert_eq!(a, b);
+    }
+
+    #[test]
+    fn debug_invalid_date_field() {
+        assert_eq!(format!("{:?}", InvalidDateField::MonthZero), "MonthZero");
+        assert_eq!(
+            format!("{:?}", InvalidDateField::MonthTooLarge(13)),
+            "MonthTooLarge(13)"
+        );
+        assert_eq!(format!("{:?}", InvalidDateField::DayZero), "DayZero");
+        assert_eq!(
+            format!("{:?}", InvalidDateField::DayTooLarge { day: 30, max: 28 }),
+            "DayTooLarge { day: 30, max: 28 }"
+        );
+    }
+
+    #[test]
+    fn invalid_date_field_equality() {
+        assert_eq!(InvalidDateField::DayZero, InvalidDateField::DayZero);
+        assert_ne!(InvalidDateField::DayZero, InvalidDateField::MonthZero);
+        assert_ne!(
+            InvalidDateField::MonthTooLarge(13),
+            InvalidDateField::MonthTooLarge(14)
+        );
+        assert_ne!(
+            InvalidDateField::DayTooLarge { day: 30, max: 28 },
+            InvalidDateField::DayTooLarge { day: 30, max: 29 }
+        );
+    }
+
+    #[test]
+    fn display_invalid_date_field() {
+        assert_eq!(
+            format!("{}", InvalidDateField::MonthZero),
+            "the Month field is 0"
+        );
+        assert_eq!(
+            format!("{}", InvalidDateField::MonthTooLarge(13)),
+            "the Month field is 13, which is greater than 12"
+        );
+        assert_eq!(
+            format!("{}", InvalidDateField::DayZero),
+            "the Day field is 0"
+        );
+        assert_eq!(
+            format!("{}", InvalidDateField::DayTooLarge { day: 30, max: 28 }),
+            "the Day field is 30, which is greater than 28"
+        );
+    }
+
+    #[test]
+    fn source_invalid_date_field() {
+        assert!(InvalidDateField::MonthZero.source().is_none());
+        assert!(
+            InvalidDateField::DayTooLarge { day: 30, max: 28 }
+                .source()
+                .is_none()
+        );
+    }
+
+    #[cfg(feature = "serde")]
+    #[test]
+    fn serialize_invalid_date_field() {
+        assert_eq!(
+            serde_json::to_string(&InvalidDateField::MonthZero).unwrap(),
+            r#""MonthZero""#
+        );
+        assert_eq!(
+            serde_json::to_string(&InvalidDateField::MonthTooLarge(13)).unwrap(),
+            r#"{"MonthTooLarge":13}"#
+        );
+        assert_eq!(
+            serde_json::to_string(&InvalidDateField::DayTooLarge { day: 30, max: 28 }).unwrap(),
+            r#"{"DayTooLarge":{"day":30,"max":28}}"#
+        );
+    }
+
+    #[test]
+    fn from_str_date_range_error_kind() {
+        assert_eq!(
+            DateRangeErrorKind::from_str("Negative").unwrap(),
+            DateRangeErrorKind::Negative
+        );
+        assert_eq!(
+            DateRangeErrorKind::from_str("negative").unwrap(),
+            DateRangeErrorKind::Negative
+        );
+        assert_eq!(
+            DateRangeErrorKind::from_str("Overflow").unwrap(),
+            DateRangeErrorKind::Overflow
+        );
+        assert_eq!(
+            DateRangeErrorKind::from_str("OVERFLOW").unwrap(),
+            DateRangeErrorKind::Overflow
+        );
+    }
+
+    #[test]
+    fn from_str_date_range_error_kind_with_unknown_kind() {
+        assert_eq!(
+            DateRangeErrorKind::from_str("").unwrap_err(),
+            UnknownKindError
+        );
+        assert_eq!(
+            DateRangeErrorKind::from_str("Underflow").unwrap_err(),
+            UnknownKindError
+        );
+        assert_eq!(
+            DateRangeErrorKind::from_str(" Negative").unwrap_err(),
+            UnknownKindError
+        );
+    }
+
+    #[test]
+    fn from_str_date_range_error_kind_roundtrip() {
+        for kind in [DateRangeErrorKind::Negative, DateRangeErrorKind::Overflow] {
+            assert_eq!(
+                format!("{kind:?}").parse::<DateRangeErrorKind>().unwrap(),
+                kind
+            );
+        }
+    }
 }
@@ -4,9 +4,12 @@
 
 //! Error types for [`DateTime`](crate::DateTime).
 
-use core::{error::Error, fmt};
+use core::{error::Error, fmt, str::FromStr};
 
-use crate::error::{DateRangeError, DateRangeErrorKind};
+use crate::error::{
+    DateRangeError, DateRangeErrorKind, TimePrecisionError, TimePrecisionErrorKind,
+    UnknownKindError,
+};
 
 /// The error type indicating that a [`DateTime`](crate::DateTime) was out of
 /// range.
@@ -52,6 +55,25 @@ impl fmt::Display for DateTimeRangeError {
 
 impl Error for DateTimeRangeError {}
 
+impl Default for DateTimeRangeError {
+    /// Returns the default value of an error with
+    /// [`DateTimeRangeErrorKind::Overflow`].
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use dos_date_time::error::{DateTimeRangeErrorKind, DateTimeRangeError};
+    /// #
+    /// assert_eq!(
+    ///     DateTimeRangeError::default().kind(),
+    ///     DateTimeRangeErrorKind::Overflow
+    /// );
+    /// ```
+    fn default() -> Self {
+        Self::new(DateTimeRangeErrorKind::Overflow)
+    }
+}
+
 impl From<DateTimeRangeErrorKind> for DateTimeRangeError {
     fn from(kind: DateTimeRangeErrorKind) -> Self {
         Self::new(kind)
@@ -69,6 +91,7 @@ impl From<DateRangeError> for DateTimeRangeError {
 
 /// Details of the error that caused a [`DateTimeRangeError`].
 #[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize))]
 pub enum DateTimeRangeErrorKind {
     /// Value was negative.
     ///
@@ -90,6 +113,123 @@ impl fmt::Display for DateTimeRangeErrorKind {
     }
 }
 
+impl FromStr for DateTimeRangeErrorKind {
+    type Err = UnknownKindError;
+
+    /// Parses a string `s` to return a value of this type.
+    ///
+    /// `s` is the name of the variant, and is matched case-insensitively.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`Err`] if `s` is not the name of any variant.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use dos_date_time::error::DateTimeRangeErrorKind;
+    /// #
+    /// assert_eq!("negative".parse(), Ok(DateTimeRangeErrorKind::Negative));
+    /// assert_eq!("OVERFLOW".parse(), Ok(DateTimeRangeErrorKind::Overflow));
+    ///
+    /// assert!("underflow".parse::<DateTimeRangeErrorKind>().is_err());
+    /// ```
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        if s.eq_ignore_ascii_case("Negative") {
+            Ok(Self::Negative)
+        } else if s.eq_ignore_ascii_case("Overflow") {
+            Ok(Self::Overflow)
+        } else {
+            Err(UnknownKindError)
+        }
+    }
+}
+
+/// The error type indicating that a [`DateTime`](crate::DateTime) could not
+/// represent the given date and time exactly.
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub struct DateTimeError(DateTimeErrorKind);
+
+impl DateTimeError {
+    pub(crate) const fn new(kind: DateTimeErrorKind) -> Self {
+        Self(kind)
+    }
+
+    /// Returns the corresponding [`DateTimeErrorKind`] for this error.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use dos_date_time::{
+    /// #     DateTime,
+    /// #     error::{DateTimeErrorKind, DateTimeRangeErrorKind, TimePrecisionErrorKind},
+    /// #     time::macros::{date, time},
+    /// # };
+    /// #
+    /// let err = DateTime::from_date_time_exact(date!(1979-12-31), time!(23:59:58)).unwrap_err();
+    /// assert_eq!(
+    ///     err.kind(),
+    ///     DateTimeErrorKind::Range(DateTimeRangeErrorKind::Negative)
+    /// );
+    ///
+    /// let err = DateTime::from_date_time_exact(date!(1980-01-01), time!(00:00:01)).unwrap_err();
+    /// assert_eq!(
+    ///     err.kind(),
+    ///     DateTimeErrorKind::Precision(TimePrecisionErrorKind::OddSecond)
+    /// );
+    /// ```
+    #[must_use]
+    pub const fn kind(&self) -> DateTimeErrorKind {
+        self.0
+    }
+}
+
+impl fmt::Display for DateTimeError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        self.kind().fmt(f)
+    }
+}
+
+impl Error for DateTimeError {}
+
+impl From<DateTimeErrorKind> for DateTimeError {
+    fn from(kind: DateTimeErrorKind) -> Self {
+        Self::new(kind)
+    }
+}
+
+impl From<DateTimeRangeError> for DateTimeError {
+    fn from(err: DateTimeRangeError) -> Self {
+        Self::new(DateTimeErrorKind::Range(err.kind()))
+    }
+}
+
+impl From<TimePrecisionError> for DateTimeError {
+    fn from(err: TimePrecisionError) -> Self {
+        Self::new(DateTimeErrorKind::Precision(err.kind()))
+    }
+}
+
+/// Details of the error that caused a [`DateTimeError`].
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize))]
+pub enum DateTimeErrorKind {
+    /// The date and time were out of range for MS-DOS date and time.
+    Range(DateTimeRangeErrorKind),
+
+    /// The time could not be represented exactly as the MS-DOS time.
+    Precision(TimePrecisionErrorKind),
+}
+
+impl fmt::Display for DateTimeErrorKind {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::Range(kind) => kind.fmt(f),
+            Self::Precision(kind) => kind.fmt(f),
+        }
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -209,6 +349,14 @@ mod tests {
         );
     }
 
+    #[test]
+    fn default_date_time_range_error() {
+        assert_eq!(
+            DateTimeRangeError::default().kind(),
+            DateTimeRangeErrorKind::Overflow
+        );
+    }
+
     #[test]
     fn from_date_time_range_error_kind_to_date_time_range_error() {
         assert_eq!(
@@ -291,4 +439,252 @@ mod tests {
             "MS-DOS date and time are after `2107-12-31 23:59:58`"
         );
     }
+
+    #[cfg(feature = "serde")]
+    #[test]
+    fn serialize_date_time_range_error_kind() {
+        assert_eq!(
+            serde_json::to_string(&DateTimeRangeErrorKind::Negative).unwrap(),
+            r#""Negative""#
+        );
+        assert_eq!(
+            serde_json::to_string(&DateTimeRangeErrorKind::Overflow).unwrap(),
+            r#""Overflow""#
+        );
+    }
+
+    #[test]
+    fn clone_date_time_error() {
+        assert_eq!(
+            DateTimeError::new(DateTimeErrorKind::Range(DateTimeRangeErrorKind::Negative)).clone(),
+            DateTimeError::new(DateTimeErrorKind::Range(DateTimeRangeErrorKind::Negative))
+        );
+        assert_eq!(
+            DateTimeError::new(DateTimeErrorKind::Precision(
+                TimePrecisionErrorKind::OddSecond
+            ))
+            .clone(),
+            DateTimeError::new(DateTimeErrorKind::Precision(
+                TimePrecisionErrorKind::OddSecond
+            ))
+        );
+    }
+
+    #[test]
+    fn copy_date_time_error() {
+        let a = DateTimeError::new(DateTimeErrorKind::Precision(
+            TimePrecisionErrorKind::Subsecond,
+        ));
+        let b = a;
+        assert_eq!(a, b);
+    }
+
+    #[test]
+    fn debug_date_time_error() {
+        assert_eq!(
+            format!(
+                "{:?}",
+                DateTimeError::new(DateTimeErrorKind::Range(DateTimeRangeErrorKind::Overflow))
+            ),
+            "DateTimeError(Range(Overflow))"
+        );
+        assert_eq!(
+            format!(
+                "{:?}",
+                DateTimeError::new(DateTimeErrorKind::Precision(
+                    TimePrecisionErrorKind::OddSecond
+                ))
+            ),
+            "DateTimeError(Precision(OddSecond))"
+        );
+    }
+
+    #[test]
+    fn date_time_error_equality() {
+        assert_eq!(
+            DateTimeError::new(DateTimeErrorKind::Range(DateTimeRangeErrorKind::Negative)),
+            DateTimeError::new(DateTimeErrorKind::Range(DateTimeRangeErrorKind::Negative))
+        );
+        assert_ne!(
+            DateTimeError::new(DateTimeErrorKind::Range(DateTimeRangeErrorKind::Negative)),
+            DateTimeError::new(DateTimeErrorKind::Precision(
+                TimePrecisionErrorKind::OddSecond
+            ))
+        );
+    }
+
+    #[test]
+    fn kind_date_time_error() {
+        assert_eq!(
+            DateTimeError::new(DateTimeErrorKind::Precision(
+                TimePrecisionErrorKind::Subsecond
+            ))
+            .kind(),
+            DateTimeErrorKind::Precision(TimePrecisionErrorKind::Subsecond)
+        );
+    }
+
+    #[test]
+    const fn kind_date_time_error_is_const_fn() {
+        const _: DateTimeErrorKind =
+            DateTimeError::new(DateTimeErrorKind::Range(DateTimeRangeErrorKind::Negative)).kind();
+    }
+
+    #[test]
+    fn display_date_time_error() {
+        assert_eq!(
+            format!(
+                "{}",
+                DateTimeError::new(DateTimeErrorKind::Range(DateTimeRangeErrorKind::Negative))
+            ),
+            "MS-DOS date and time are before `1980-01-01 00:00:00`"
+        );
+        assert_eq!(
+            format!(
+                "{}",
+                DateTimeError::new(DateTimeErrorKind::Precision(
+                    TimePrecisionErrorKind::OddSecond
+                ))
+            ),
+            "second is odd"
+        );
+    }
+
+    #[test]
+    fn source_date_time_error() {
+        assert!(
+            DateTimeError::new(DateTimeErrorKind::Range(DateTimeRangeErrorKind::Negative))
+                .source()
+                .is_none()
+        );
+    }
+
+    #[test]
+    fn from_date_time_error_kind_to_date_time_error() {
+        assert_eq!(
+            DateTimeError::from(DateTimeErrorKind::Range(DateTimeRangeErrorKind::Overflow)),
+            DateTimeError::new(DateTimeErrorKind::Range(DateTimeRangeErrorKind::Overflow))
+        );
+    }
+
+    #[test]
+    fn from_date_time_range_error_to_date_time_error() {
+        assert_eq!(
+            DateTimeError::from(DateTimeRangeError::new(DateTimeRangeErrorKind::Negative)),
+            DateTimeError::new(DateTimeErrorKind::Range(DateTimeRangeErrorKind::Negative))
+        );
+        assert_eq!(
+            DateTimeError::from(DateTimeRangeError::new(DateTimeRangeErrorKind::Overflow)),
+            DateTimeError::new(DateTimeErrorKind::Range(DateTimeRangeErrorKind::Overflow))
+        );
+    }
+
+    #[test]
+    fn from_time_precision_error_to_date_time_error() {
+        assert_eq!(
+            DateTimeError::from(TimePrecisionError::new(TimePrecisionErrorKind::OddSecond)),
+            DateTimeError::new(DateTimeErrorKind::Precision(
+                TimePrecisionErrorKind::OddSecond
+            ))
+        );
+        assert_eq!(
+            DateTimeError::from(TimePrecisionError::new(TimePrecisionErrorKind::Subsecond)),
+            DateTimeError::new(DateTimeErrorKind::Precision(
+                TimePrecisionErrorKind::Subsecond
+            ))
+        );
+    }
+
+    #[test]
+    fn question_mark_into_date_time_error() {
+        fn check(
+            range: Result<(), DateTimeRangeError>,
+            precision: Result<(), TimePrecisionError>,
+        ) -> Result<(), DateTimeError> {
+            range?;
+            precision?;
+            Ok(())
+        }
+
+        assert_eq!(check(Ok(()), Ok(())), Ok(()));
+        assert_eq!(
+            check(
+                Err(DateTimeRangeErrorKind::Negative.into()),
+                Err(TimePrecisionErrorKind::OddSecond.into())
+            ),
+            Err(DateTimeErrorKind::Range(DateTimeRangeErrorKind::Negative).into())
+        );
+        assert_eq!(
+            check(Ok(()), Err(TimePrecisionErrorKind::Subsecond.into())),
+            Err(DateTimeErrorKind::Precision(TimePrecisionErrorKind::Subsecond).into())
+        );
+    }
+
+    #[cfg(feature = "serde")]
+    #[test]
+    fn serialize_date_time_error_kind() {
+        assert_eq!(
+            serde_json::to_string(&DateTimeErrorKind::Range(DateTimeRangeErrorKind::Negative))
+                .unwrap(),
+            r#"{"Range":"Negative"}"#
+        );
+        assert_eq!(
+            serde_json::to_string(&DateTimeErrorKind::Precision(
+                TimePrecisionErrorKind::OddSecond
+            ))
+            .unwrap(),
+            r#"{"Precision":"OddSecond"}"#
+        );
+    }
+
+    #[test]
+    fn from_str_date_time_range_error_kind() {
+        assert_eq!(
+            DateTimeRangeErrorKind::from_str("Negative").unwrap(),
+            DateTimeRangeErrorKind::Negative
+        );
+        assert_eq!(
+            DateTimeRangeErrorKind::from_str("negative").unwrap(),
+            DateTimeRangeErrorKind::Negative
+        );
+        assert_eq!(
+            DateTimeRangeErrorKind::from_str("Overflow").unwrap(),
+            DateTimeRangeErrorKind::Overflow
+        );
+        assert_eq!(
+            DateTimeRangeErrorKind::from_str("OVERFLOW").unwrap(),
+            DateTimeRangeErrorKind::Overflow
+        );
+    }
+
+    #[test]
+    fn from_str_date_time_range_error_kind_with_unknown_kind() {
+        assert_eq!(
+            DateTimeRangeErrorKind::from_str("").unwrap_err(),
+            UnknownKindError
+        );
+        assert_eq!(
+            DateTimeRangeErrorKind::from_str("Underflow").unwrap_err(),
+            UnknownKindError
+        );
+        assert_eq!(
+            DateTimeRangeErrorKind::from_str(" Negative").unwrap_err(),
+            UnknownKindError
+        );
+    }
+
+    #[test]
+    fn from_str_date_time_range_error_kind_roundtrip() {
+        for kind in [
+            DateTimeRangeErrorKind::Negative,
+            DateTimeRangeErrorKind::Overflow,
+        ] {
+            assert_eq!(
+                format!("{kind:?}")
+                    .parse::<DateTimeRangeErrorKind>()
+                    .unwrap(),
+                kind
+            );
+        }
+    }
 }
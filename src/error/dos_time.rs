@@ -0,0 +1,303 @@
+// SPDX-FileCopyrightText: 2025 Shun Sakai
+//
+// SPDX-License-Identifier: Apache-2.0 OR MIT
+
+//! Error types for [`Time`](crate::Time).
+
+use core::{error::Error, fmt};
+
+/// The error type indicating that a [`Time`](crate::Time) could not represent
+/// the given time exactly.
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub struct TimePrecisionError(TimePrecisionErrorKind);
+
+impl TimePrecisionError {
+    pub(crate) const fn new(kind: TimePrecisionErrorKind) -> Self {
+        Self(kind)
+    }
+
+    /// Returns the corresponding [`TimePrecisionErrorKind`] for this error.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use dos_date_time::{Time, error::TimePrecisionErrorKind, time::macros::time};
+    /// #
+    /// let err = Time::from_time_exact(time!(00:00:01)).unwrap_err();
+    /// assert_eq!(err.kind(), TimePrecisionErrorKind::OddSecond);
+    ///
+    /// let err = Time::from_time_exact(time!(00:00:00.5)).unwrap_err();
+    /// assert_eq!(err.kind(), TimePrecisionErrorKind::Subsecond);
+    /// ```
+    #[must_use]
+    pub const fn kind(&self) -> TimePrecisionErrorKind {
+        self.0
+    }
+}
+
+impl fmt::Display for TimePrecisionError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        self.kind().fmt(f)
+    }
+}
+
+impl Error for TimePrecisionError {}
+
+impl From<TimePrecisionErrorKind> for TimePrecisionError {
+    fn from(kind: TimePrecisionErrorKind) -> Self {
+        Self::new(kind)
+    }
+}
+
+/// Details of the error that caused a [`TimePrecisionError`].
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize))]
+pub enum TimePrecisionErrorKind {
+    /// The second was odd.
+    ///
+    /// This means the time could not be represented exactly, since the
+    /// resolution of MS-DOS time is 2 seconds.
+    OddSecond,
+
+    /// The time had a fractional second.
+    Subsecond,
+}
+
+impl fmt::Display for TimePrecisionErrorKind {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::OddSecond => write!(f, "second is odd"),
+            Self::Subsecond => write!(f, "time has a fractional second"),
+        }
+    }
+}
+
+/// The error type indicating which field of an MS-DOS time was invalid.
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize))]
+pub enum InvalidTimeField {
+    /// The Hour field was greater than 23.
+    HourTooLarge(u8),
+
+    /// The Minute field was greater than 59.
+    MinuteTooLarge(u8),
+
+    /// The `DoubleSeconds` field was greater than 29.
+    DoubleSecondsTooLarge(u8),
+}
+
+impl fmt::Display for InvalidTimeField {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::HourTooLarge(hour) => {
+                write!(f, "the Hour field is {hour}, which is greater than 23")
+            }
+            Self::MinuteTooLarge(minute) => {
+                write!(f, "the Minute field is {minute}, which is greater than 59")
+            }
+            Self::DoubleSecondsTooLarge(double_seconds) => write!(
+                f,
+                "the DoubleSeconds field is {double_seconds}, which is greater than 29"
+            ),
+        }
+    }
+}
+
+impl Error for InvalidTimeField {}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn clone_time_precision_error() {
+        assert_eq!(
+            TimePrecisionError::new(TimePrecisionErrorKind::OddSecond).clone(),
+            TimePrecisionError::new(TimePrecisionErrorKind::OddSecond)
+        );
+        assert_eq!(
+            TimePrecisionError::new(TimePrecisionErrorKind::Subsecond).clone(),
+            TimePrecisionError::new(TimePrecisionErrorKind::Subsecond)
+        );
+    }
+
+    #[test]
+    fn copy_time_precision_error() {
+        let a = TimePrecisionError::new(TimePrecisionErrorKind::OddSecond);
+        let b = a;
+        assert_eq!(a, b);
+    }
+
+    #[test]
+    fn debug_time_precision_error() {
+        assert_eq!(
+            format!(
+                "{:?}",
+                TimePrecisionError::new(TimePrecisionErrorKind::OddSecond)
+            ),
+            "TimePrecisionError(OddSecond)"
+        );
+        assert_eq!(
+            format!(
+                "{:?}",
+                TimePrecisionError::new(TimePrecisionErrorKind::Subsecond)
+            ),
+            "TimePrecisionError(Subsecond)"
+        );
+    }
+
+    #[test]
+    fn time_precision_error_equality() {
+        assert_eq!(
+            TimePrecisionError::new(TimePrecisionErrorKind::OddSecond),
+            TimePrecisionError::new(TimePrecisionErrorKind::OddSecond)
+        );
+        assert_ne!(
+            TimePrecisionError::new(TimePrecisionErrorKind::OddSecond),
+            TimePrecisionError::new(TimePrecisionErrorKind::Subsecond)
+        );
+    }
+
+    #[test]
+    fn kind_time_precision_error() {
+        assert_eq!(
+            TimePrecisionError::new(TimePrecisionErrorKind::OddSecond).kind(),
+            TimePrecisionErrorKind::OddSecond
+        );
+        assert_eq!(
+            TimePrecisionError::new(TimePrecisionErrorKind::Subsecond).kind(),
+            TimePrecisionErrorKind::Subsecond
+        );
+    }
+
+    #[test]
+    const fn kind_time_precision_error_is_const_fn() {
+        const _: TimePrecisionErrorKind =
+            TimePrecisionError::new(TimePrecisionErrorKind::OddSecond).kind();
+    }
+
+    #[test]
+    fn display_time_precision_error() {
+        assert_eq!(
+            format!(
+                "{}",
+                TimePrecisionError::new(TimePrecisionErrorKind::OddSecond)
+            ),
+            "second is odd"
+        );
+        assert_eq!(
+            format!(
+                "{}",
+                TimePrecisionError::new(TimePrecisionErrorKind::Subsecond)
+            ),
+            "time has a fractional second"
+        );
+    }
+
+    #[test]
+    fn source_time_precision_error() {
+        assert!(
+            TimePrecisionError::new(TimePrecisionErrorKind::OddSecond)
+                .source()
+                .is_none()
+        );
+    }
+
+    #[test]
+    fn from_time_precision_error_kind_to_time_precision_error() {
+        assert_eq!(
+            TimePrecisionError::from(TimePrecisionErrorKind::OddSecond),
+            TimePrecisionError::new(TimePrecisionErrorKind::OddSecond)
+        );
+    }
+
+    #[cfg(feature = "serde")]
+    #[test]
+    fn serialize_time_precision_error_kind() {
+        assert_eq!(
+            serde_json::to_string(&TimePrecisionErrorKind::OddSecond).unwrap(),
+            r#""OddSecond""#
+        );
+        assert_eq!(
+            serde_json::to_string(&TimePrecisionErrorKind::Subsecond).unwrap(),
+            r#""Subsecond""#
+        );
+    }
+
+    #[test]
+    fn clone_invalid_time_field() {
+        assert_eq!(
+            InvalidTimeField::HourTooLarge(24).clone(),
+            InvalidTimeField::HourTooLarge(24)
+        );
+    }
+
+    #[test]
+    fn copy_invalid_time_field() {
+        let a = InvalidTimeField::MinuteTooLarge(60);
+        let b = a;
+        assert_eq!(a, b);
+    }
+
+    #[test]
+    fn debug_invalid_time_field() {
+        assert_eq!(
+            format!("{:?}", InvalidTimeField::HourTooLarge(24)),
+            "HourTooLarge(24)"
+        );
+        assert_eq!(
+            format!("{:?}", InvalidTimeField::MinuteTooLarge(60)),
+            "MinuteTooLarge(60)"
+        );
+        assert_eq!(
+            format!("{:?}", InvalidTimeField::DoubleSecondsTooLarge(30)),
+            "DoubleSecondsTooLarge(30)"
+        );
+    }
+
+    #[test]
+    fn invalid_time_field_equality() {
+        assert_eq!(
+            InvalidTimeField::HourTooLarge(24),
+            InvalidTimeField::HourTooLarge(24)
+        );
+        assert_ne!(
+            InvalidTimeField::HourTooLarge(24),
+            InvalidTimeField::HourTooLarge(25)
+        );
+        assert_ne!(
+            InvalidTimeField::MinuteTooLarge(60),
+            InvalidTimeField::DoubleSecondsTooLarge(60)
+        );
+    }
+
+    #[test]
+    fn display_invalid_time_field() {
+        assert_eq!(
+            format!("{}", InvalidTimeField::HourTooLarge(24)),
+            "the Hour field is 24, which is greater than 23"
+        );
+        assert_eq!(
+            format!("{}", InvalidTimeField::MinuteTooLarge(60)),
+            "the Minute field is 60, which is greater than 59"
+        );
+        assert_eq!(
+            format!("{}", InvalidTimeField::DoubleSecondsTooLarge(30)),
+            "the DoubleSeconds field is 30, which is greater than 29"
+        );
+    }
+
+    #[test]
+    fn source_invalid_time_field() {
+        assert!(InvalidTimeField::HourTooLarge(24).source().is_none());
+    }
+
+    #[cfg(feature = "serde")]
+    #[test]
+    fn serialize_invalid_time_field() {
+        assert_eq!(
+            serde_json::to_string(&InvalidTimeField::DoubleSecondsTooLarge(30)).unwrap(),
+            r#"{"DoubleSecondsTooLarge":30}"#
+        );
+    }
+}
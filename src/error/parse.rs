@@ -0,0 +1,244 @@
+// SPDX-FileCopyrightText: 2025 Shun Sakai
+//
+// SPDX-License-Identifier: Apache-2.0 OR MIT
+
+//! Error types for parsing [`DateTime`](crate::DateTime).
+
+use core::{error::Error, fmt};
+
+use crate::error::{DateTimeRangeError, DateTimeRangeErrorKind};
+
+/// The error type indicating that a [`DateTime`](crate::DateTime) could not be
+/// parsed.
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub struct ParseError(ParseErrorKind);
+
+impl ParseError {
+    pub(crate) const fn new(kind: ParseErrorKind) -> Self {
+        Self(kind)
+    }
+
+    /// Returns the corresponding [`ParseErrorKind`] for this error.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use dos_date_time::{
+    /// #     DateTime,
+    /// #     error::{DateTimeRangeErrorKind, ParseErrorKind},
+    /// # };
+    /// #
+    /// let err = DateTime::from_ascii(b"1980-01-01").unwrap_err();
+    /// assert_eq!(err.kind(), ParseErrorKind::InvalidLength);
+    ///
+    /// let err = DateTime::from_ascii(b"1979-12-31 23:59:59").unwrap_err();
+    /// assert_eq!(
+    ///     err.kind(),
+    ///     ParseErrorKind::Range(DateTimeRangeErrorKind::Negative)
+    /// );
+    /// ```
+    #[must_use]
+    pub const fn kind(&self) -> ParseErrorKind {
+        self.0
+    }
+}
+
+impl fmt::Display for ParseError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        self.kind().fmt(f)
+    }
+}
+
+impl Error for ParseError {}
+
+impl From<ParseErrorKind> for ParseError {
+    fn from(kind: ParseErrorKind) -> Self {
+        Self::new(kind)
+    }
+}
+
+impl From<DateTimeRangeError> for ParseError {
+    fn from(err: DateTimeRangeError) -> Self {
+        Self::new(ParseErrorKind::Range(err.kind()))
+    }
+}
+
+/// Details of the error that caused a [`ParseError`].
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize))]
+pub enum ParseErrorKind {
+    /// The input had an invalid length.
+    InvalidLength,
+
+    /// The input contained an invalid separator.
+    InvalidSeparator,
+
+    /// The input contained an invalid number.
+    InvalidNumber,
+
+    /// A component of the input was invalid.
+    ///
+    /// This means, for example, the month was 13 or the day was after the
+    /// last day of the month.
+    InvalidComponent,
+
+    /// The parsed value was out of range for MS-DOS date and time.
+    Range(DateTimeRangeErrorKind),
+}
+
+impl fmt::Display for ParseErrorKind {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::InvalidLength => write!(f, "input has an invalid length"),
+            Self::InvalidSeparator => write!(f, "input contains an invalid separator"),
+            Self::InvalidNumber => write!(f, "input contains an invalid number"),
+            Self::InvalidComponent => write!(f, "input contains an invalid component"),
+            Self::Range(kind) => kind.fmt(f),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn clone_parse_error() {
+        assert_eq!(
+            ParseError::new(ParseErrorKind::InvalidLength).clone(),
+            ParseError::new(ParseErrorKind::InvalidLength)
+        );
+        assert_eq!(
+            ParseError::new(ParseErrorKind::Range(DateTimeRangeErrorKind::Overflow)).clone(),
+            ParseError::new(ParseErrorKind::Range(DateTimeRangeErrorKind::Overflow))
+        );
+    }
+
+    #[test]
+    fn copy_parse_error() {
+        let a = ParseError::new(ParseErrorKind::InvalidSeparator);
+        let b = a;
+        assert_eq!(a, b);
+    }
+
+    #[test]
+    fn debug_parse_error() {
+        assert_eq!(
+            format!("{:?}", ParseError::new(ParseErrorKind::InvalidNumber)),
+            "ParseError(InvalidNumber)"
+        );
+        assert_eq!(
+            format!(
+                "{:?}",
+                ParseError::new(ParseErrorKind::Range(DateTimeRangeErrorKind::Negative))
+            ),
+            "ParseError(Range(Negative))"
+        );
+    }
+
+    #[test]
+    fn parse_error_equality() {
+        assert_eq!(
+            ParseError::new(ParseErrorKind::InvalidLength),
+            ParseError::new(ParseErrorKind::InvalidLength)
+        );
+        assert_ne!(
+            ParseError::new(ParseErrorKind::InvalidLength),
+            ParseError::new(ParseErrorKind::InvalidComponent)
+        );
+        assert_ne!(
+            ParseError::new(ParseErrorKind::Range(DateTimeRangeErrorKind::Negative)),
+            ParseError::new(ParseErrorKind::Range(DateTimeRangeErrorKind::Overflow))
+        );
+    }
+
+    #[test]
+    fn kind_parse_error() {
+        assert_eq!(
+            ParseError::new(ParseErrorKind::InvalidComponent).kind(),
+            ParseErrorKind::InvalidComponent
+        );
+    }
+
+    #[test]
+    const fn kind_parse_error_is_const_fn() {
+        const _: ParseErrorKind = ParseError::new(ParseErrorKind::InvalidLength).kind();
+    }
+
+    #[test]
+    fn display_parse_error() {
+        assert_eq!(
+            format!("{}", ParseError::new(ParseErrorKind::InvalidLength)),
+            "input has an invalid length"
+        );
+        assert_eq!(
+            format!("{}", ParseError::new(ParseErrorKind::InvalidSeparator)),
+            "input contains an invalid separator"
+        );
+        assert_eq!(
+            format!("{}", ParseError::new(ParseErrorKind::InvalidNumber)),
+            "input contains an invalid number"
+        );
+        assert_eq!(
+            format!("{}", ParseError::new(ParseErrorKind::InvalidComponent)),
+            "input contains an invalid component"
+        );
+        assert_eq!(
+            format!(
+                "{}",
+                ParseError::new(ParseErrorKind::Range(DateTimeRangeErrorKind::Negative))
+            ),
+            "MS-DOS date and time are before `1980-01-01 00:00:00`"
+        );
+        assert_eq!(
+            format!(
+                "{}",
+                ParseError::new(ParseErrorKind::Range(DateTimeRangeErrorKind::Overflow))
+            ),
+            "MS-DOS date and time are after `2107-12-31 23:59:58`"
+        );
+    }
+
+    #[test]
+    fn source_parse_error() {
+        assert!(
+            ParseError::new(ParseErrorKind::InvalidLength)
+                .source()
+                .is_none()
+        );
+    }
+
+    #[cfg(feature = "serde")]
+    #[test]
+    fn serialize_parse_error_kind() {
+        assert_eq!(
+            serde_json::to_string(&ParseErrorKind::InvalidLength).unwrap(),
+            r#""InvalidLength""#
+        );
+        assert_eq!(
+            serde_json::to_string(&ParseErrorKind::Range(DateTimeRangeErrorKind::Overflow))
+                .unwrap(),
+            r#"{"Range":"Overflow"}"#
+        );
+    }
+
+    #[test]
+    fn from_parse_error_kind_to_parse_error() {
+        assert_eq!(
+            ParseError::from(ParseErrorKind::InvalidNumber),
+            ParseError::new(ParseErrorKind::InvalidNumber)
+        );
+    }
+
+    #[test]
+    fn from_date_time_range_error_to_parse_error() {
+        assert_eq!(
+            ParseError::from(DateTimeRangeError::new(DateTimeRangeErrorKind::Negative)),
+            ParseError::new(ParseErrorKind::Range(DateTimeRangeErrorKind::Negative))
+        );
+        assert_eq!(
+            ParseError::from(DateTimeRangeError::new(DateTimeRangeErrorKind::Overflow)),
+            ParseError::new(ParseErrorKind::Range(DateTimeRangeErrorKind::Overflow))
+        );
+    }
+}
@@ -0,0 +1,177 @@
+// SPDX-FileCopyrightText: 2025 Shun Sakai
+//
+// SPDX-License-Identifier: Apache-2.0 OR MIT
+
+//! Error types for reading [`DateTime`](crate::DateTime) from bytes.
+
+use core::{error::Error, fmt};
+
+/// The error type indicating that a [`DateTime`](crate::DateTime) could not be
+/// read from bytes.
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub struct ReadError(ReadErrorKind);
+
+impl ReadError {
+    pub(crate) const fn new(kind: ReadErrorKind) -> Self {
+        Self(kind)
+    }
+
+    /// Returns the corresponding [`ReadErrorKind`] for this error.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use dos_date_time::{DateTime, error::ReadErrorKind};
+    /// #
+    /// let err = DateTime::read_le(&mut [0x00, 0x00, 0x21].as_slice()).unwrap_err();
+    /// assert_eq!(err.kind(), ReadErrorKind::UnexpectedEof);
+    ///
+    /// let err = DateTime::read_le(&mut [0x00, 0x00, 0x20, 0x00].as_slice()).unwrap_err();
+    /// assert_eq!(err.kind(), ReadErrorKind::InvalidBits);
+    /// ```
+    #[must_use]
+    pub const fn kind(&self) -> ReadErrorKind {
+        self.0
+    }
+}
+
+impl fmt::Display for ReadError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        self.kind().fmt(f)
+    }
+}
+
+impl Error for ReadError {}
+
+impl From<ReadErrorKind> for ReadError {
+    fn from(kind: ReadErrorKind) -> Self {
+        Self::new(kind)
+    }
+}
+
+/// Details of the error that caused a [`ReadError`].
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize))]
+pub enum ReadErrorKind {
+    /// The input ended before enough bytes were read.
+    UnexpectedEof,
+
+    /// The bytes read were not valid MS-DOS date and time.
+    InvalidBits,
+}
+
+impl fmt::Display for ReadErrorKind {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::UnexpectedEof => write!(f, "unexpected end of input"),
+            Self::InvalidBits => write!(f, "bytes are not valid MS-DOS date and time"),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn clone_read_error() {
+        assert_eq!(
+            ReadError::new(ReadErrorKind::UnexpectedEof).clone(),
+            ReadError::new(ReadErrorKind::UnexpectedEof)
+        );
+        assert_eq!(
+            ReadError::new(ReadErrorKind::InvalidBits).clone(),
+            ReadError::new(ReadErrorKind::InvalidBits)
+        );
+    }
+
+    #[test]
+    fn copy_read_error() {
+        let a = ReadError::new(ReadErrorKind::UnexpectedEof);
+        let b = a;
+        assert_eq!(a, b);
+    }
+
+    #[test]
+    fn debug_read_error() {
+        assert_eq!(
+            format!("{:?}", ReadError::new(ReadErrorKind::UnexpectedEof)),
+            "ReadError(UnexpectedEof)"
+        );
+        assert_eq!(
+            format!("{:?}", ReadError::new(ReadErrorKind::InvalidBits)),
+            "ReadError(InvalidBits)"
+        );
+    }
+
+    #[test]
+    fn read_error_equality() {
+        assert_eq!(
+            ReadError::new(ReadErrorKind::UnexpectedEof),
+            ReadError::new(ReadErrorKind::UnexpectedEof)
+        );
+        assert_ne!(
+            ReadError::new(ReadErrorKind::UnexpectedEof),
+            ReadError::new(ReadErrorKind::InvalidBits)
+        );
+    }
+
+    #[test]
+    fn kind_read_error() {
+        assert_eq!(
+            ReadError::new(ReadErrorKind::UnexpectedEof).kind(),
+            ReadErrorKind::UnexpectedEof
+        );
+        assert_eq!(
+            ReadError::new(ReadErrorKind::InvalidBits).kind(),
+            ReadErrorKind::InvalidBits
+        );
+    }
+
+    #[test]
+    const fn kind_read_error_is_const_fn() {
+        const _: ReadErrorKind = ReadError::new(ReadErrorKind::UnexpectedEof).kind();
+    }
+
+    #[test]
+    fn display_read_error() {
+        assert_eq!(
+            format!("{}", ReadError::new(ReadErrorKind::UnexpectedEof)),
+            "unexpected end of input"
+        );
+        assert_eq!(
+            format!("{}", ReadError::new(ReadErrorKind::InvalidBits)),
+            "bytes are not valid MS-DOS date and time"
+        );
+    }
+
+    #[test]
+    fn source_read_error() {
+        assert!(
+            ReadError::new(ReadErrorKind::UnexpectedEof)
+                .source()
+                .is_none()
+        );
+    }
+
+    #[test]
+    fn from_read_error_kind_to_read_error() {
+        assert_eq!(
+            ReadError::from(ReadErrorKind::InvalidBits),
+            ReadError::new(ReadErrorKind::InvalidBits)
+        );
+    }
+
+    #[cfg(feature = "serde")]
+    #[test]
+    fn serialize_read_error_kind() {
+        assert_eq!(
+            serde_json::to_string(&ReadErrorKind::UnexpectedEof).unwrap(),
+            r#""UnexpectedEof""#
+        );
+        assert_eq!(
+            serde_json::to_string(&ReadErrorKind::InvalidBits).unwrap(),
+            r#""InvalidBits""#
+        );
+    }
+}
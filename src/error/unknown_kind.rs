@@ -0,0 +1,59 @@
+// SPDX-FileCopyrightText: 2025 Shun Sakai
+//
+// SPDX-License-Identifier: Apache-2.0 OR MIT
+
+//! Error types for parsing error kinds.
+
+use core::{error::Error, fmt};
+
+/// The error type indicating that a string was not the name of a known error
+/// kind.
+///
+/// This is returned when parsing [`DateRangeErrorKind`] or
+/// [`DateTimeRangeErrorKind`] from a string.
+///
+/// [`DateRangeErrorKind`]: crate::error::DateRangeErrorKind
+/// [`DateTimeRangeErrorKind`]: crate::error::DateTimeRangeErrorKind
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[non_exhaustive]
+pub struct UnknownKindError;
+
+impl fmt::Display for UnknownKindError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "unknown error kind")
+    }
+}
+
+impl Error for UnknownKindError {}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn clone_unknown_kind_error() {
+        assert_eq!(UnknownKindError.clone(), UnknownKindError);
+    }
+
+    #[test]
+    fn copy_unknown_kind_error() {
+        let a = UnknownKindError;
+        let b = a;
+        assert_eq!(a, b);
+    }
+
+    #[test]
+    fn debug_unknown_kind_error() {
+        assert_eq!(format!("{UnknownKindError:?}"), "UnknownKindError");
+    }
+
+    #[test]
+    fn display_unknown_kind_error() {
+        assert_eq!(format!("{UnknownKindError}"), "unknown error kind");
+    }
+
+    #[test]
+    fn source_unknown_kind_error() {
+        assert!(UnknownKindError.source().is_none());
+    }
+}
@@ -0,0 +1,137 @@
+// SPDX-FileCopyrightText: 2025 Shun Sakai
+//
+// SPDX-License-Identifier: Apache-2.0 OR MIT
+
+//! Error types for writing [`DateTime`](crate::DateTime) to bytes.
+
+use core::{error::Error, fmt};
+
+/// The error type indicating that a buffer was too small to write a
+/// [`DateTime`](crate::DateTime) to.
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub struct BufferTooSmallError {
+    required: usize,
+    actual: usize,
+}
+
+impl BufferTooSmallError {
+    pub(crate) const fn new(required: usize, actual: usize) -> Self {
+        Self { required, actual }
+    }
+
+    /// Returns the number of bytes required.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use dos_date_time::DateTime;
+    /// #
+    /// let err = DateTime::MIN.write_le(&mut [u8::MIN; 3]).unwrap_err();
+    /// assert_eq!(err.required(), 4);
+    /// ```
+    #[must_use]
+    pub const fn required(&self) -> usize {
+        self.required
+    }
+
+    /// Returns the actual size of the buffer.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use dos_date_time::DateTime;
+    /// #
+    /// let err = DateTime::MIN.write_le(&mut [u8::MIN; 3]).unwrap_err();
+    /// assert_eq!(err.actual(), 3);
+    /// ```
+    #[must_use]
+    pub const fn actual(&self) -> usize {
+        self.actual
+    }
+}
+
+impl fmt::Display for BufferTooSmallError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "buffer is too small: {} bytes are required, but the buffer has {} bytes",
+            self.required(),
+            self.actual()
+        )
+    }
+}
+
+impl Error for BufferTooSmallError {}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn clone_buffer_too_small_error() {
+        assert_eq!(
+            BufferTooSmallError::new(4, 3).clone(),
+            BufferTooSmallError::new(4, 3)
+        );
+    }
+
+    #[test]
+    fn copy_buffer_too_small_error() {
+        let a = BufferTooSmallError::new(4, 3);
+        let b = a;
+        assert_eq!(a, b);
+    }
+
+    #[test]
+    fn debug_buffer_too_small_error() {
+        assert_eq!(
+            format!("{:?}", BufferTooSmallError::new(4, 3)),
+            "BufferTooSmallError { required: 4, actual: 3 }"
+        );
+    }
+
+    #[test]
+    fn buffer_too_small_error_equality() {
+        assert_eq!(
+            BufferTooSmallError::new(4, 3),
+            BufferTooSmallError::new(4, 3)
+        );
+        assert_ne!(
+            BufferTooSmallError::new(4, 3),
+            BufferTooSmallError::new(4, 0)
+        );
+    }
+
+    #[test]
+    fn required_buffer_too_small_error() {
+        assert_eq!(BufferTooSmallError::new(4, 3).required(), 4);
+    }
+
+    #[test]
+    const fn required_buffer_too_small_error_is_const_fn() {
+        const _: usize = BufferTooSmallError::new(4, 3).required();
+    }
+
+    #[test]
+    fn actual_buffer_too_small_error() {
+        assert_eq!(BufferTooSmallError::new(4, 3).actual(), 3);
+    }
+
+    #[test]
+    const fn actual_buffer_too_small_error_is_const_fn() {
+        const _: usize = BufferTooSmallError::new(4, 3).actual();
+    }
+
+    #[test]
+    fn display_buffer_too_small_error() {
+        assert_eq!(
+            format!("{}", BufferTooSmallError::new(4, 3)),
+            "buffer is too small: 4 bytes are required, but the buffer has 3 bytes"
+        );
+    }
+
+    #[test]
+    fn source_buffer_too_small_error() {
+        assert!(BufferTooSmallError::new(4, 3).source().is_none());
+    }
+}
@@ -70,6 +70,9 @@
 //! assert_eq!(format!("{dt}"), "Tue, 01 Jan 1980 00:00:00 +0000");
 //! ```
 //!
+//! With the `formatting` feature, `DateTime::format` formats the value with
+//! any format description of the [`time`] crate without this conversion.
+//!
 //! [MS-DOS date and time]: https://learn.microsoft.com/en-us/windows/win32/sysinfo/ms-dos-date-and-time
 //! [FAT]: https://en.wikipedia.org/wiki/File_Allocation_Table
 //! [ZIP]: https://en.wikipedia.org/wiki/ZIP_(file_format)
@@ -81,21 +84,119 @@
 // Lint levels of rustc.
 #![deny(missing_docs)]
 
-#[cfg(test)]
+#[cfg(any(feature = "alloc", test))]
 #[macro_use]
 extern crate alloc;
 #[cfg(feature = "std")]
 extern crate std;
 
+#[macro_use]
+mod macros;
+
 mod dos_date;
 mod dos_date_time;
 mod dos_time;
 pub mod error;
+mod offset;
 
+#[cfg(feature = "bytemuck")]
+pub use bytemuck;
 #[cfg(feature = "chrono")]
 pub use chrono;
 #[cfg(feature = "jiff")]
 pub use jiff;
+#[cfg(feature = "num-traits")]
+pub use num_traits;
 pub use time;
 
-pub use crate::{dos_date::Date, dos_date_time::DateTime, dos_time::Time};
+#[doc(hidden)]
+pub use crate::macros::__parse_components;
+pub use crate::{
+    dos_date::{Date, DateRange, is_date_representable},
+    dos_date_time::{
+        Breakdown, DateTime, DisplayUtc, DosWords, ExfatDateTime, ValidationPolicy, between,
+        is_representable,
+    },
+    dos_time::Time,
+    offset::{Days, Months},
+};
+
+/// Doctests which ensure that discarding the result of a pure method is
+/// rejected when the `unused_must_use` lint is denied.
+///
+/// Trait methods in impl blocks such as [`From::from`] cannot be marked
+/// with `#[must_use]`, but [`TryFrom::try_from`] returns [`Result`], which is
+/// already `#[must_use]`.
+///
+/// ```
+/// #![deny(unused_must_use)]
+/// # use dos_date_time::{Date, DateTime, Time};
+/// #
+/// let _ = Date::MIN.month();
+/// let _ = DateTime::new(Date::MIN, Time::MIN);
+/// ```
+///
+/// ```compile_fail
+/// #![deny(unused_must_use)]
+/// # use dos_date_time::{Date, DateTime, Time};
+/// #
+/// Date::MIN.month();
+/// ```
+///
+/// ```compile_fail
+/// #![deny(unused_must_use)]
+/// # use dos_date_time::{Date, DateTime, Time};
+/// #
+/// Date::MIN.day();
+/// ```
+///
+/// ```compile_fail
+/// #![deny(unused_must_use)]
+/// # use dos_date_time::{Date, DateTime, Time};
+/// #
+/// Time::MIN.hour();
+/// ```
+///
+/// ```compile_fail
+/// #![deny(unused_must_use)]
+/// # use dos_date_time::{Date, DateTime, Time};
+/// #
+/// Time::MIN.minute();
+/// ```
+///
+/// ```compile_fail
+/// #![deny(unused_must_use)]
+/// # use dos_date_time::{Date, DateTime, Time};
+/// #
+/// Time::MIN.second();
+/// ```
+///
+/// ```compile_fail
+/// #![deny(unused_must_use)]
+/// # use dos_date_time::{Date, DateTime, Time};
+/// #
+/// DateTime::MIN.date();
+/// ```
+///
+/// ```compile_fail
+/// #![deny(unused_must_use)]
+/// # use dos_date_time::{Date, DateTime, Time};
+/// #
+/// DateTime::MIN.time();
+/// ```
+///
+/// ```compile_fail
+/// #![deny(unused_must_use)]
+/// # use dos_date_time::{Date, DateTime, Time};
+/// #
+/// DateTime::new(Date::MIN, Time::MIN);
+/// ```
+///
+/// ```compile_fail
+/// #![deny(unused_must_use)]
+/// # use dos_date_time::{Date, time::macros::date};
+/// #
+/// Date::try_from(date!(1980-01-01));
+/// ```
+#[cfg(doctest)]
+pub struct MustUseDoctests;
@@ -0,0 +1,329 @@
+// SPDX-FileCopyrightText: 2025 Shun Sakai
+//
+// SPDX-License-Identifier: Apache-2.0 OR MIT
+
+//! Macros for constructing values checked at compile time.
+
+/// Parses `N` numbers separated by `separators` from the stringified tokens
+/// passed to a macro.
+///
+/// A space in `separators` matches one or more whitespace characters. Any
+/// whitespace around other separators is ignored, since how the tokens are
+/// stringified is unspecified.
+#[doc(hidden)]
+#[must_use]
+pub const fn __parse_components<const N: usize>(s: &str, separators: &[u8]) -> [u16; N] {
+    const fn skip_whitespace(bytes: &[u8], mut i: usize) -> usize {
+        while i < bytes.len() && bytes[i].is_ascii_whitespace() {
+            i += 1;
+        }
+        i
+    }
+
+    assert!(separators.len() + 1 == N, "separators should be `N - 1`");
+    let bytes = s.as_bytes();
+    let mut components = [u16::MIN; N];
+    let (mut i, mut n) = (skip_whitespace(bytes, 0), 0);
+    while n < N {
+        let start = i;
+        let mut component = u16::MIN;
+        while i < bytes.len() && bytes[i].is_ascii_digit() {
+            let digit = (bytes[i] - b'0') as u16;
+            component = match component.checked_mul(10) {
+                Some(c) => match c.checked_add(digit) {
+                    Some(c) => c,
+                    None => panic!("component should be in the range of `u16`"),
+                },
+                None => panic!("component should be in the range of `u16`"),
+            };
+            i += 1;
+        }
+        assert!(i > start, "component should be a number");
+        components[n] = component;
+        if n < separators.len() {
+            let after_number = i;
+            i = skip_whitespace(bytes, i);
+            if separators[n] == b' ' {
+                assert!(
+                    i > after_number,
+                    "components should be separated by a space"
+                );
+            } else {
+                assert!(
+                    i < bytes.len() && bytes[i] == separators[n],
+                    "components should be separated by the expected separator"
+                );
+                i = skip_whitespace(bytes, i + 1);
+            }
+        }
+        n += 1;
+    }
+    assert!(
+        skip_whitespace(bytes, i) == bytes.len(),
+        "input should not have trailing tokens"
+    );
+    components
+}
+
+/// Creates a [`Date`](crate::Date) with the given literal, which is checked at
+/// compile time.
+///
+/// The syntax is `YYYY-MM-DD`, the same as [`time::macros::date!`], except that
+/// only the calendar date is supported.
+///
+/// # Examples
+///
+/// ```
+/// # use dos_date_time::{Date, dos_date};
+/// #
+/// assert_eq!(dos_date!(1980-01-01), Date::MIN);
+/// assert_eq!(dos_date!(2107-12-31), Date::MAX);
+/// ```
+///
+/// Out-of-range or invalid dates fail to compile:
+///
+/// ```compile_fail
+/// # use dos_date_time::dos_date;
+/// #
+/// let _ = dos_date!(1979-12-31);
+/// ```
+///
+/// ```compile_fail
+/// # use dos_date_time::dos_date;
+/// #
+/// let _ = dos_date!(2001-02-29);
+/// ```
+///
+/// [`time::macros::date!`]: https://docs.rs/time/latest/time/macros/macro.date.html
+#[macro_export]
+macro_rules! dos_date {
+    ($($date:tt)+) => {
+        const {
+            let [year, month, day] = $crate::__parse_components(stringify!($($date)+), b"--");
+            $crate::Date::__from_calendar_date(year, month, day)
+        }
+    };
+}
+
+/// Creates a [`Time`](crate::Time) with the given literal, which is checked at
+/// compile time.
+///
+/// The syntax is `hh:mm:ss`, the same as [`time::macros::time!`], except that
+/// fractional seconds are not supported.
+///
+/// <div class="warning">
+///
+/// The resolution of MS-DOS time is 2 seconds. So, unlike [`Time::from_time`],
+/// an odd second fails to compile instead of being rounded.
+///
+/// </div>
+///
+/// # Examples
+///
+/// ```
+/// # use dos_date_time::{Time, dos_time};
+/// #
+/// assert_eq!(dos_time!(00:00:00), Time::MIN);
+/// assert_eq!(dos_time!(23:59:58), Time::MAX);
+/// ```
+///
+/// Invalid times fail to compile:
+///
+/// ```compile_fail
+/// # use dos_date_time::dos_time;
+/// #
+/// let _ = dos_time!(24:00:00);
+/// ```
+///
+/// ```compile_fail
+/// # use dos_date_time::dos_time;
+/// #
+/// let _ = dos_time!(23:59:59);
+/// ```
+///
+/// [`time::macros::time!`]: https://docs.rs/time/latest/time/macros/macro.time.html
+/// [`Time::from_time`]: crate::Time::from_time
+#[macro_export]
+macro_rules! dos_time {
+    ($($time:tt)+) => {
+        const {
+            let [hour, minute, second] = $crate::__parse_components(stringify!($($time)+), b"::");
+            $crate::Time::__from_hms(hour, minute, second)
+        }
+    };
+}
+
+/// Creates a [`DateTime`](crate::DateTime) with the given literal, which is
+/// checked at compile time.
+///
+/// The syntax is `YYYY-MM-DD hh:mm:ss`, the same as
+/// [`time::macros::datetime!`], except that only the calendar date is supported
+/// and fractional seconds are not supported.
+///
+/// <div class="warning">
+///
+/// The resolution of MS-DOS date and time is 2 seconds. So, unlike
+/// [`DateTime::from_date_time`], an odd second fails to compile instead of
+/// being rounded.
+///
+/// </div>
+///
+/// # Examples
+///
+/// ```
+/// # use dos_date_time::{DateTime, dos_datetime};
+/// #
+/// assert_eq!(dos_datetime!(1980-01-01 00:00:00), DateTime::MIN);
+/// assert_eq!(dos_datetime!(2107-12-31 23:59:58), DateTime::MAX);
+/// ```
+///
+/// Out-of-range or invalid date and time fail to compile:
+///
+/// ```compile_fail
+/// # use dos_date_time::dos_datetime;
+/// #
+/// let _ = dos_datetime!(2108-01-01 00:00:00);
+/// ```
+///
+/// [`time::macros::datetime!`]: https://docs.rs/time/latest/time/macros/macro.datetime.html
+/// [`DateTime::from_date_time`]: crate::DateTime::from_date_time
+#[macro_export]
+macro_rules! dos_datetime {
+    ($($dt:tt)+) => {
+        const {
+            let [year, month, day, hour, minute, second] =
+                $crate::__parse_components(stringify!($($dt)+), b"-- ::");
+            $crate::DateTime::new(
+                $crate::Date::__from_calendar_date(year, month, day),
+                $crate::Time::__from_hms(hour, minute, second),
+            )
+        }
+    };
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{Date, DateTime, Time};
+
+    #[test]
+    fn parse_components() {
+        assert_eq!(__parse_components::<3>("2018-11-17", b"--"), [2018, 11, 17]);
+        assert_eq!(
+            __parse_components::<3>("2018 - 11 - 17", b"--"),
+            [2018, 11, 17]
+        );
+        assert_eq!(__parse_components::<3>("00:00:00", b"::"), [0, 0, 0]);
+        assert_eq!(
+            __parse_components::<6>("2018-11-17 10:38:30", b"-- ::"),
+            [2018, 11, 17, 10, 38, 30]
+        );
+        assert_eq!(
+            __parse_components::<6>("2018 - 11 - 17 10 : 38 : 30", b"-- ::"),
+            [2018, 11, 17, 10, 38, 30]
+        );
+    }
+
+    #[test]
+    #[should_panic(expected = "components should be separated by the expected separator")]
+    fn parse_components_with_invalid_separator() {
+        let _ = __parse_components::<3>("2018/11/17", b"--");
+    }
+
+    #[test]
+    #[should_panic(expected = "component should be a number")]
+    fn parse_components_with_invalid_number() {
+        let _ = __parse_components::<3>("2018-xx-17", b"--");
+    }
+
+    #[test]
+    #[should_panic(expected = "input should not have trailing tokens")]
+    fn parse_components_with_trailing_tokens() {
+        let _ = __parse_components::<3>("2018-11-17 10", b"--");
+    }
+
+    #[test]
+    fn dos_date() {
+        assert_eq!(dos_date!(1980-01-01), Date::MIN);
+        // <https://devblogs.microsoft.com/oldnewthing/20030905-02/?p=42653>.
+        assert_eq!(
+            dos_date!(2002-11-26),
+            Date::new(0b0010_1101_0111_1010).unwrap()
+        );
+        // <https://github.com/zip-rs/zip/blob/v0.6.4/src/types.rs#L553-L569>.
+        assert_eq!(
+            dos_date!(2018-11-17),
+            Date::new(0b0100_1101_0111_0001).unwrap()
+        );
+        assert_eq!(dos_date!(2000-02-29).day(), 29);
+        assert_eq!(dos_date!(2107-12-31), Date::MAX);
+    }
+
+    #[test]
+    const fn dos_date_is_const() {
+        const _: Date = dos_date!(1980-01-01);
+    }
+
+    #[test]
+    fn dos_time() {
+        assert_eq!(dos_time!(00:00:00), Time::MIN);
+        // <https://devblogs.microsoft.com/oldnewthing/20030905-02/?p=42653>.
+        assert_eq!(
+            dos_time!(19:25:00),
+            Time::new(0b1001_1011_0010_0000).unwrap()
+        );
+        // <https://github.com/zip-rs/zip/blob/v0.6.4/src/types.rs#L553-L569>.
+        assert_eq!(
+            dos_time!(10:38:30),
+            Time::new(0b0101_0100_1100_1111).unwrap()
+        );
+        assert_eq!(dos_time!(23:59:58), Time::MAX);
+    }
+
+    #[test]
+    const fn dos_time_is_const() {
+        const _: Time = dos_time!(00:00:00);
+    }
+
+    #[test]
+    fn dos_datetime() {
+        assert_eq!(dos_datetime!(1980-01-01 00:00:00), DateTime::MIN);
+        // <https://devblogs.microsoft.com/oldnewthing/20030905-02/?p=42653>.
+        assert_eq!(
+            dos_datetime!(2002-11-26 19:25:00),
+            DateTime::new(
+                Date::new(0b0010_1101_0111_1010).unwrap(),
+                Time::new(0b1001_1011_0010_0000).unwrap()
+            )
+        );
+        // <https://github.com/zip-rs/zip/blob/v0.6.4/src/types.rs#L553-L569>.
+        assert_eq!(
+            dos_datetime!(2018-11-17 10:38:30),
+            DateTime::new(
+                Date::new(0b0100_1101_0111_0001).unwrap(),
+                Time::new(0b0101_0100_1100_1111).unwrap()
+            )
+        );
+        assert_eq!(dos_datetime!(2107-12-31 23:59:58), DateTime::MAX);
+    }
+
+    #[test]
+    const fn dos_datetime_is_const() {
+        const _: DateTime = dos_datetime!(1980-01-01 00:00:00);
+    }
+
+    #[test]
+    fn macros_agree_with_time_macros() {
+        use time::macros::{date, datetime, time};
+
+        assert_eq!(
+            dos_date!(2018-11-17),
+            Date::from_date(date!(2018-11-17)).unwrap()
+        );
+        assert_eq!(dos_time!(10:38:30), Time::from_time(time!(10:38:30)));
+        assert_eq!(
+            dos_datetime!(2018-11-17 10:38:30),
+            DateTime::try_from(datetime!(2018-11-17 10:38:30)).unwrap()
+        );
+    }
+}
@@ -0,0 +1,73 @@
+// SPDX-FileCopyrightText: 2025 Shun Sakai
+//
+// SPDX-License-Identifier: Apache-2.0 OR MIT
+
+//! Typed calendar offsets for [`Date`](crate::Date) and
+//! [`DateTime`](crate::DateTime).
+
+/// A number of days, used as an offset for [`Date`](crate::Date) and
+/// [`DateTime`](crate::DateTime).
+///
+/// The value may be negative, in which case the offset moves backward.
+///
+/// # Examples
+///
+/// ```
+/// # use dos_date_time::{Date, Days, time::macros::date};
+/// #
+/// assert_eq!(
+///     Date::MIN + Days(7),
+///     Date::from_date(date!(1980-01-08)).unwrap()
+/// );
+/// ```
+#[derive(Clone, Copy, Debug, Default, Eq, Hash, Ord, PartialEq, PartialOrd)]
+pub struct Days(pub i32);
+
+/// A number of months, used as an offset for [`Date`](crate::Date) and
+/// [`DateTime`](crate::DateTime).
+///
+/// The value may be negative, in which case the offset moves backward. The day
+/// of the month is kept, but is clamped to the last day of the month if the
+/// resulting month is shorter.
+///
+/// # Examples
+///
+/// ```
+/// # use dos_date_time::{Date, Months, time::macros::date};
+/// #
+/// assert_eq!(
+///     Date::MIN + Months(2),
+///     Date::from_date(date!(1980-03-01)).unwrap()
+/// );
+/// ```
+#[derive(Clone, Copy, Debug, Default, Eq, Hash, Ord, PartialEq, PartialOrd)]
+pub struct Months(pub i32);
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn clone() {
+        assert_eq!(Days(1).clone(), Days(1));
+        assert_eq!(Months(1).clone(), Months(1));
+    }
+
+    #[test]
+    fn debug() {
+        assert_eq!(format!("{:?}", Days(-7)), "Days(-7)");
+        assert_eq!(format!("{:?}", Months(2)), "Months(2)");
+    }
+
+    #[test]
+    fn default() {
+        assert_eq!(Days::default(), Days(0));
+        assert_eq!(Months::default(), Months(0));
+    }
+
+    #[test]
+    fn order() {
+        assert!(Days(-1) < Days(0));
+        assert!(Months(1) > Months(0));
+    }
+}